@@ -1,4 +1,7 @@
-use crate::{CoreStage, Plugin, PluginGroup, PluginGroupBuilder, StartupSchedule, StartupStage};
+use crate::{
+    plugin::PluginRegistry, sub_app::SubApp, CoreStage, OnShutdown, Plugin, PluginGroup,
+    PluginGroupBuilder, StartupSchedule, StartupStage, SubAppExecution,
+};
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
     event::{Event, Events},
@@ -7,11 +10,12 @@ use bevy_ecs::{
         IntoSystemDescriptor, Schedule, ShouldRun, Stage, StageLabel, State, StateData, SystemSet,
         SystemStage,
     },
-    system::Resource,
+    system::{Res, Resource},
     world::World,
 };
+use bevy_tasks::MainThreadExecutor;
 use bevy_utils::{tracing::debug, HashMap};
-use std::fmt::Debug;
+use std::{any::TypeId, fmt::Debug};
 
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
@@ -57,12 +61,7 @@ pub struct App {
     /// A container of [`Stage`]s set to be run in a linear order.
     pub schedule: Schedule,
     sub_apps: HashMap<Box<dyn AppLabel>, SubApp>,
-}
-
-/// Each `SubApp` has its own [`Schedule`] and [`World`], enabling a separation of concerns.
-struct SubApp {
-    app: App,
-    runner: Box<dyn Fn(&mut World, &mut App)>,
+    plugin_registry: PluginRegistry,
 }
 
 impl Default for App {
@@ -100,36 +99,123 @@ impl App {
             schedule: Default::default(),
             runner: Box::new(run_once),
             sub_apps: HashMap::default(),
+            plugin_registry: PluginRegistry::default(),
         }
     }
 
     /// Advances the execution of the [`Schedule`] by one cycle.
     ///
-    /// This method also updates sub apps.
+    /// This method also updates sub apps, and first runs the tasks of the [`MainThreadExecutor`]
+    /// resource, if present, which are ready to make progress.
     ///
     /// See [`add_sub_app`](Self::add_sub_app) and [`run_once`](Schedule::run_once) for more details.
     pub fn update(&mut self) {
         #[cfg(feature = "trace")]
         let _bevy_frame_update_span = info_span!("frame").entered();
+        if let Some(executor) = self.world.get_resource::<MainThreadExecutor>() {
+            executor.tick();
+        }
         self.schedule.run(&mut self.world);
         for sub_app in self.sub_apps.values_mut() {
-            (sub_app.runner)(&mut self.world, &mut sub_app.app);
+            sub_app.run(&mut self.world);
+        }
+    }
+
+    /// Advances the execution of the [`Schedule`] by `frames` cycles, as if [`update`](Self::update)
+    /// was called `frames` times.
+    ///
+    /// This is mostly useful in tests and headless apps. Combine it with a manual time update
+    /// strategy (such as `bevy::time::TimeUpdateStrategy::from_framerate`) to simulate
+    /// a given number of frames at a chosen framerate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// #[derive(Default)]
+    /// struct Counter(usize);
+    ///
+    /// fn count_system(mut counter: ResMut<Counter>) {
+    ///     counter.0 += 1;
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.init_resource::<Counter>().add_system(count_system);
+    /// app.update_frames(10);
+    /// assert_eq!(app.world.resource::<Counter>().0, 10);
+    /// ```
+    pub fn update_frames(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.update();
         }
     }
 
     /// Starts the application by calling the app's [runner function](Self::set_runner).
     ///
-    /// Finalizes the [`App`] configuration. For general usage, see the example on the item
-    /// level documentation.
+    /// Finalizes the [`App`] configuration by calling [`finish`](Self::finish) and
+    /// [`cleanup`](Self::cleanup). For general usage, see the example on the item level
+    /// documentation.
     pub fn run(&mut self) {
         #[cfg(feature = "trace")]
         let _bevy_app_run_span = info_span!("bevy_app").entered();
 
         let mut app = std::mem::replace(self, App::empty());
+        app.finish();
+        app.cleanup();
         let runner = std::mem::replace(&mut app.runner, Box::new(run_once));
         (runner)(app);
     }
 
+    /// Runs [`Plugin::finish`] for each added [`Plugin`], in the order they were added.
+    ///
+    /// This is called by [`run`](Self::run). It only needs to be called manually when updating
+    /// the [`App`] without running it, such as in tests.
+    pub fn finish(&mut self) {
+        let plugins = std::mem::take(&mut self.plugin_registry.plugins);
+        for plugin in &plugins {
+            plugin.finish(self);
+        }
+        self.restore_plugins(plugins);
+    }
+
+    /// Runs [`Plugin::cleanup`] for each added [`Plugin`], in the order they were added.
+    ///
+    /// This is called by [`run`](Self::run), after [`finish`](Self::finish). It only needs to be
+    /// called manually when updating the [`App`] without running it, such as in tests.
+    pub fn cleanup(&mut self) {
+        let plugins = std::mem::take(&mut self.plugin_registry.plugins);
+        for plugin in &plugins {
+            plugin.cleanup(self);
+        }
+        self.restore_plugins(plugins);
+    }
+
+    /// Puts back the `plugins` taken out of the registry, before the ones added in the meantime.
+    fn restore_plugins(&mut self, mut plugins: Vec<Box<dyn Plugin>>) {
+        plugins.append(&mut self.plugin_registry.plugins);
+        self.plugin_registry.plugins = plugins;
+    }
+
+    /// Runs the [`OnShutdown`] stage, and waits for the sub-apps to complete their current run.
+    ///
+    /// This is called by the app runners right before exiting, once an [`AppExit`] event was
+    /// sent. Custom runners should call it as well.
+    pub fn shutdown(&mut self) {
+        #[cfg(feature = "trace")]
+        let _bevy_shutdown_span = info_span!("shutdown").entered();
+
+        if let Some(stage) = self.schedule.get_stage_mut::<SystemStage>(&OnShutdown) {
+            self.world.insert_resource(ShuttingDown);
+            stage.run(&mut self.world);
+            self.world.remove_resource::<ShuttingDown>();
+        }
+        for sub_app in self.sub_apps.values_mut() {
+            sub_app.finish();
+        }
+    }
+
     /// Adds a [`Stage`] with the given `label` to the last position of the app's
     /// [`Schedule`].
     ///
@@ -579,6 +665,9 @@ impl App {
     ///
     /// The labels for those stages are defined in the [`CoreStage`] and [`StartupStage`] `enum`s.
     ///
+    /// An additional [`OnShutdown`] stage is added at the end of the schedule. It is skipped by
+    /// [`update`](Self::update), and only runs once when the app [shuts down](Self::shutdown).
+    ///
     /// # Examples
     ///
     /// ```
@@ -600,6 +689,33 @@ impl App {
             .add_stage(CoreStage::Update, SystemStage::parallel())
             .add_stage(CoreStage::PostUpdate, SystemStage::parallel())
             .add_stage(CoreStage::Last, SystemStage::parallel())
+            .add_stage(
+                OnShutdown,
+                SystemStage::parallel().with_run_criteria(run_on_shutdown),
+            )
+    }
+
+    /// Adds a system to the [`OnShutdown`] stage of the app's [`Schedule`], which runs once
+    /// right before the app exits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// fn save_game(_commands: Commands) {
+    ///     println!("Saving the game before exiting");
+    /// }
+    ///
+    /// App::new()
+    ///     .add_shutdown_system(save_game);
+    /// ```
+    pub fn add_shutdown_system<Params>(
+        &mut self,
+        system: impl IntoSystemDescriptor<Params>,
+    ) -> &mut Self {
+        self.add_system_to_stage(OnShutdown, system)
     }
 
     /// Setup the application to manage events of type `T`.
@@ -763,15 +879,48 @@ impl App {
     /// #
     /// App::new().add_plugin(bevy_log::LogPlugin::default());
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the plugin's [dependencies](Plugin::dependencies) was not added yet, if
+    /// the plugin is an [optional dependency](Plugin::optional_dependencies) of a plugin that was
+    /// already added, or if the plugin is [unique](Plugin::is_unique) and was already added.
     pub fn add_plugin<T>(&mut self, plugin: T) -> &mut Self
     where
         T: Plugin,
     {
+        self.add_boxed_plugin(Box::new(plugin))
+    }
+
+    /// Adds a single type-erased [`Plugin`], such as one that was loaded dynamically.
+    ///
+    /// See [`add_plugin`](Self::add_plugin).
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`add_plugin`](Self::add_plugin).
+    pub fn add_boxed_plugin(&mut self, plugin: Box<dyn Plugin>) -> &mut Self {
+        self.plugin_registry.register(&*plugin);
         debug!("added plugin: {}", plugin.name());
         plugin.build(self);
+        self.plugin_registry.plugins.push(plugin);
         self
     }
 
+    /// Returns `true` if a [`Plugin`] of type `T` was added to the [`App`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # let mut app = App::new();
+    /// app.add_plugin(bevy_log::LogPlugin::default());
+    /// assert!(app.is_plugin_added::<bevy_log::LogPlugin>());
+    /// ```
+    pub fn is_plugin_added<T: Plugin>(&self) -> bool {
+        self.plugin_registry.contains(TypeId::of::<T>())
+    }
+
     /// Adds a group of [`Plugin`]s.
     ///
     /// [`Plugin`]s can be grouped into a set by using a [`PluginGroup`].
@@ -856,22 +1005,75 @@ impl App {
 
     /// Adds an [`App`] as a child of the current one.
     ///
+    /// Each `SubApp` has its own [`Schedule`] and [`World`], enabling a separation of concerns.
+    ///
     /// The provided function `f` is called by the [`update`](Self::update) method. The [`World`]
     /// parameter represents the main app world, while the [`App`] parameter is just a mutable
     /// reference to the `SubApp` itself.
+    ///
+    /// See [`add_extract_sub_app`](Self::add_extract_sub_app) for a `SubApp` that does not need a
+    /// custom runner.
     pub fn add_sub_app(
         &mut self,
         label: impl AppLabel,
         app: App,
         sub_app_runner: impl Fn(&mut World, &mut App) + 'static,
     ) -> &mut Self {
-        self.sub_apps.insert(
-            Box::new(label),
-            SubApp {
-                app,
-                runner: Box::new(sub_app_runner),
-            },
-        );
+        self.sub_apps
+            .insert(Box::new(label), SubApp::new(app, sub_app_runner));
+        self
+    }
+
+    /// Adds an [`App`] as a child of the current one, which extracts data from the main app
+    /// [`World`] on each [`update`](Self::update) before running its own [`Schedule`].
+    ///
+    /// The systems of the sub-app's [`ExtractStage`](crate::ExtractStage), which is added if
+    /// missing, run on the main app [`World`] and use [`Commands`](bevy_ecs::system::Commands)
+    /// to write to the sub-app's [`World`]. The other stages of the sub-app's [`Schedule`] then run
+    /// on its own [`World`], either right away or on a dedicated thread depending on `execution`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_app::{AppLabel, ExtractStage, SubAppExecution};
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AppLabel)]
+    /// struct PlannerApp;
+    ///
+    /// #[derive(Clone, Default)]
+    /// struct Goal(u32);
+    ///
+    /// fn extract_goal(mut commands: Commands, goal: Res<Goal>) {
+    ///     commands.insert_resource(goal.clone());
+    /// }
+    ///
+    /// fn plan(goal: Res<Goal>) {
+    ///     // expensive planning, isolated from the main world
+    /// }
+    ///
+    /// let mut planner_app = App::empty();
+    /// planner_app
+    ///     .init_resource::<Goal>()
+    ///     .add_stage(ExtractStage, SystemStage::parallel())
+    ///     .add_stage("plan", SystemStage::parallel())
+    ///     .add_system_to_stage(ExtractStage, extract_goal)
+    ///     .add_system_to_stage("plan", plan);
+    ///
+    /// App::new()
+    ///     .init_resource::<Goal>()
+    ///     .add_extract_sub_app(PlannerApp, planner_app, SubAppExecution::Pipelined)
+    ///     .update();
+    /// ```
+    pub fn add_extract_sub_app(
+        &mut self,
+        label: impl AppLabel,
+        app: App,
+        execution: SubAppExecution,
+    ) -> &mut Self {
+        let sub_app = SubApp::with_extract(&label, app, execution);
+        self.sub_apps.insert(Box::new(label), sub_app);
         self
     }
 
@@ -889,10 +1091,16 @@ impl App {
 
     /// Retrieves a `SubApp` inside this [`App`] with the given label, if it exists. Otherwise returns
     /// an [`Err`] containing the given label.
+    ///
+    /// If the `SubApp` is [pipelined](SubAppExecution::Pipelined), this waits for its current run
+    /// to complete.
     pub fn get_sub_app_mut(&mut self, label: impl AppLabel) -> Result<&mut App, impl AppLabel> {
         self.sub_apps
             .get_mut((&label) as &dyn AppLabel)
-            .map(|sub_app| &mut sub_app.app)
+            .map(|sub_app| {
+                sub_app.finish();
+                &mut sub_app.app
+            })
             .ok_or(label)
     }
 
@@ -900,26 +1108,50 @@ impl App {
     ///
     /// # Panics
     ///
-    /// Panics if the `SubApp` doesn't exist.
+    /// Panics if the `SubApp` doesn't exist, or if it is [pipelined](SubAppExecution::Pipelined)
+    /// and running on its own thread.
     pub fn sub_app(&self, label: impl AppLabel) -> &App {
         match self.get_sub_app(label) {
             Ok(app) => app,
-            Err(label) => panic!("Sub-App with label '{:?}' does not exist", label),
+            Err(label) => match self.sub_apps.get((&label) as &dyn AppLabel) {
+                Some(_) => panic!(
+                    "Sub-App with label '{:?}' is running, use `sub_app_mut` to wait for it",
+                    label
+                ),
+                None => panic!("Sub-App with label '{:?}' does not exist", label),
+            },
         }
     }
 
     /// Retrieves a `SubApp` inside this [`App`] with the given label, if it exists. Otherwise returns
     /// an [`Err`] containing the given label.
+    ///
+    /// While a [pipelined](SubAppExecution::Pipelined) `SubApp` is running on its own thread, its
+    /// [`World`] and [`Schedule`] are not available and an [`Err`] is returned as well. Use
+    /// [`get_sub_app_mut`](Self::get_sub_app_mut) to wait for it to complete instead.
     pub fn get_sub_app(&self, label: impl AppLabel) -> Result<&App, impl AppLabel> {
-        self.sub_apps
-            .get((&label) as &dyn AppLabel)
-            .map(|sub_app| &sub_app.app)
-            .ok_or(label)
+        match self.sub_apps.get((&label) as &dyn AppLabel) {
+            Some(sub_app) if !sub_app.is_running() => Ok(&sub_app.app),
+            _ => Err(label),
+        }
     }
 }
 
 fn run_once(mut app: App) {
     app.update();
+    app.shutdown();
+}
+
+/// Marks that the [`OnShutdown`] stage is being run by [`App::shutdown`].
+struct ShuttingDown;
+
+/// Skips the [`OnShutdown`] stage during regular updates.
+fn run_on_shutdown(shutting_down: Option<Res<ShuttingDown>>) -> ShouldRun {
+    if shutting_down.is_some() {
+        ShouldRun::Yes
+    } else {
+        ShouldRun::No
+    }
 }
 
 /// An event that indicates the [`App`] should exit. This will fully exit the app process at the
@@ -930,3 +1162,88 @@ fn run_once(mut app: App) {
 /// frame is over.
 #[derive(Debug, Clone, Default)]
 pub struct AppExit;
+
+#[cfg(test)]
+mod tests {
+    use crate::{App, Plugin};
+    use bevy_ecs::prelude::*;
+
+    #[derive(Default)]
+    struct Calls(Vec<&'static str>);
+
+    struct PluginA;
+    impl Plugin for PluginA {
+        fn build(&self, app: &mut App) {
+            app.init_resource::<Calls>();
+            app.world.resource_mut::<Calls>().0.push("build A");
+        }
+        fn finish(&self, app: &mut App) {
+            app.world.resource_mut::<Calls>().0.push("finish A");
+        }
+        fn cleanup(&self, app: &mut App) {
+            app.world.resource_mut::<Calls>().0.push("cleanup A");
+        }
+    }
+
+    struct PluginB;
+    impl Plugin for PluginB {
+        fn build(&self, app: &mut App) {
+            app.world.resource_mut::<Calls>().0.push("build B");
+        }
+        fn finish(&self, app: &mut App) {
+            app.world.resource_mut::<Calls>().0.push("finish B");
+        }
+    }
+
+    #[test]
+    fn plugin_lifecycle() {
+        let mut app = App::new();
+        app.add_plugin(PluginA).add_plugin(PluginB);
+        app.finish();
+        app.cleanup();
+
+        assert_eq!(
+            app.world.resource::<Calls>().0,
+            vec!["build A", "build B", "finish A", "finish B", "cleanup A"]
+        );
+    }
+
+    #[test]
+    fn shutdown_systems_only_run_on_shutdown() {
+        #[derive(Default)]
+        struct ShutdownCount(u32);
+
+        fn count_shutdown(mut count: ResMut<ShutdownCount>) {
+            count.0 += 1;
+        }
+
+        let mut app = App::new();
+        app.init_resource::<ShutdownCount>()
+            .add_shutdown_system(count_shutdown);
+
+        app.update_frames(2);
+        assert_eq!(app.world.resource::<ShutdownCount>().0, 0);
+
+        app.shutdown();
+        assert_eq!(app.world.resource::<ShutdownCount>().0, 1);
+
+        app.update();
+        assert_eq!(app.world.resource::<ShutdownCount>().0, 1);
+    }
+
+    #[test]
+    fn update_ticks_main_thread_executor() {
+        let executor = bevy_tasks::MainThreadExecutor::new();
+        let mut app = App::new();
+        app.insert_resource(executor.clone());
+
+        let spawner = executor.clone();
+        let mut task = std::thread::spawn(move || spawner.spawn_on_main(|| async { 42 }))
+            .join()
+            .unwrap();
+        assert!(!task.is_finished());
+
+        app.update();
+        assert_eq!(task.poll_once(), Some(42));
+    }
+}
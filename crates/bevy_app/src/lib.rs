@@ -6,6 +6,7 @@ mod app;
 mod plugin;
 mod plugin_group;
 mod schedule_runner;
+mod sub_app;
 
 #[cfg(feature = "bevy_ci_testing")]
 mod ci_testing;
@@ -15,12 +16,14 @@ pub use bevy_derive::DynamicPlugin;
 pub use plugin::*;
 pub use plugin_group::*;
 pub use schedule_runner::*;
+pub use sub_app::{ExtractStage, SubAppExecution, SubAppWorld};
 
 #[allow(missing_docs)]
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        app::App, CoreStage, DynamicPlugin, Plugin, PluginGroup, StartupSchedule, StartupStage,
+        app::App, CoreStage, DynamicPlugin, OnShutdown, Plugin, PluginGroup, StartupSchedule,
+        StartupStage,
     };
 }
 
@@ -43,6 +46,15 @@ pub enum CoreStage {
     Last,
 }
 
+/// The label for the shutdown [`Stage`](bevy_ecs::schedule::Stage), which runs once right before
+/// the [`App`] exits.
+///
+/// Unlike the other default stages, it does not run as part of [`App::update`]. Instead, it is run
+/// by [`App::shutdown`], which the app runners call once an [`AppExit`] event was sent, before the
+/// process exits. This makes it a good place to flush save files or close connections.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, StageLabel)]
+pub struct OnShutdown;
+
 /// The label for the startup [`Schedule`](bevy_ecs::schedule::Schedule),
 /// which runs once at the beginning of the [`App`].
 ///
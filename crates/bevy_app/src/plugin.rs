@@ -1,17 +1,174 @@
 use crate::App;
-use std::any::Any;
+use bevy_utils::HashMap;
+use std::any::{Any, TypeId};
 
 /// A collection of Bevy app logic and configuration.
 ///
 /// Plugins configure an [`App`]. When an [`App`] registers a plugin,
 /// the plugin's [`Plugin::build`] function is run.
+///
+/// Once all the plugins are built, right before the [`App`] starts [running](App::run), the
+/// [`Plugin::finish`] function of every plugin is run, followed by their [`Plugin::cleanup`]
+/// function.
 pub trait Plugin: Any + Send + Sync {
     /// Configures the [`App`] to which this plugin is added.
     fn build(&self, app: &mut App);
+    /// Finishes adding this plugin to the [`App`], once all the plugins are built.
+    ///
+    /// This can be used to configure things that depend on the work of plugins added after
+    /// this one. See [`App::finish`].
+    fn finish(&self, _app: &mut App) {
+        // do nothing
+    }
+    /// Runs after all the plugins are built and [finished](Plugin::finish), but before the
+    /// [`App`] starts running. See [`App::cleanup`].
+    fn cleanup(&self, _app: &mut App) {
+        // do nothing
+    }
     /// Configures a name for the [`Plugin`] which is primarily used for debugging.
     fn name(&self) -> &str {
         std::any::type_name::<Self>()
     }
+    /// The [`Plugin`]s that must be added to the [`App`] before this one.
+    ///
+    /// [`App::add_plugin`] panics if one of them is missing. Inside of a
+    /// [`PluginGroup`](crate::PluginGroup), the dependencies are built first.
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        Vec::new()
+    }
+    /// The [`Plugin`]s that must be added to the [`App`] before this one if they are added at all.
+    ///
+    /// [`App::add_plugin`] panics if one of them is added after this one. Inside of a
+    /// [`PluginGroup`](crate::PluginGroup), the ones present in the group are built first.
+    fn optional_dependencies(&self) -> Vec<PluginDependency> {
+        Vec::new()
+    }
+    /// Whether the [`Plugin`] can only be added once to an [`App`].
+    ///
+    /// [`App::add_plugin`] panics when adding a unique plugin that was already added.
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+/// A reference to a [`Plugin`] type, used to declare [`Plugin::dependencies`] and
+/// [`Plugin::optional_dependencies`].
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_app::PluginDependency;
+/// # struct AssetPlugin;
+/// # impl Plugin for AssetPlugin {
+/// #     fn build(&self, _app: &mut App) {}
+/// # }
+/// struct MyAssetsPlugin;
+///
+/// impl Plugin for MyAssetsPlugin {
+///     fn build(&self, app: &mut App) {
+///         // register assets and loaders
+///     }
+///
+///     fn dependencies(&self) -> Vec<PluginDependency> {
+///         vec![PluginDependency::of::<AssetPlugin>()]
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PluginDependency {
+    type_id: TypeId,
+    name: &'static str,
+}
+
+impl PluginDependency {
+    /// Creates a reference to the [`Plugin`] of type `T`.
+    pub fn of<T: Plugin>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// The [`TypeId`] of the referenced [`Plugin`].
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// The type name of the referenced [`Plugin`].
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// Keeps track of the [`Plugin`]s added to an [`App`] to validate their dependencies.
+#[derive(Default)]
+pub(crate) struct PluginRegistry {
+    /// The added plugins, in the order they were built.
+    pub(crate) plugins: Vec<Box<dyn Plugin>>,
+    /// The names of the added plugins.
+    added: HashMap<TypeId, String>,
+    /// For each plugin that was not added yet, the names of the added plugins that must be added
+    /// after it if it is added.
+    dependents: HashMap<TypeId, Vec<String>>,
+}
+
+impl PluginRegistry {
+    pub(crate) fn contains(&self, type_id: TypeId) -> bool {
+        self.added.contains_key(&type_id)
+    }
+
+    /// Registers a [`Plugin`] being added to the [`App`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the plugin is unique and already registered, if one of its dependencies is
+    /// missing, or if it is the optional dependency of an already registered plugin.
+    pub(crate) fn register(&mut self, plugin: &dyn Plugin) {
+        let type_id = Any::type_id(plugin);
+
+        if plugin.is_unique() {
+            if let Some(name) = self.added.get(&type_id) {
+                panic!(
+                    "Error adding plugin {}: the plugin {} was already added to the app and is unique.",
+                    plugin.name(),
+                    name
+                );
+            }
+        }
+
+        let missing = plugin
+            .dependencies()
+            .into_iter()
+            .filter(|dependency| !self.contains(dependency.type_id()))
+            .map(|dependency| dependency.name())
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            panic!(
+                "Error adding plugin {}: it depends on plugins that must be added first: {}.",
+                plugin.name(),
+                missing.join(", ")
+            );
+        }
+
+        if let Some(dependents) = self.dependents.get(&type_id) {
+            panic!(
+                "Error adding plugin {}: it must be added before the plugins that depend on it: {}.",
+                plugin.name(),
+                dependents.join(", ")
+            );
+        }
+
+        for dependency in plugin.optional_dependencies() {
+            if !self.contains(dependency.type_id()) {
+                self.dependents
+                    .entry(dependency.type_id())
+                    .or_default()
+                    .push(plugin.name().to_string());
+            }
+        }
+        self.added.insert(type_id, plugin.name().to_string());
+    }
 }
 
 /// A type representing an unsafe function that returns a mutable pointer to a [`Plugin`].
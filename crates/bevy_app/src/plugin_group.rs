@@ -1,5 +1,5 @@
 use crate::{App, Plugin};
-use bevy_utils::{tracing::debug, tracing::warn, HashMap};
+use bevy_utils::{tracing::warn, HashMap};
 use std::any::TypeId;
 
 /// Combines multiple [`Plugin`]s into a single unit.
@@ -17,6 +17,10 @@ struct PluginEntry {
 /// Provides a build ordering to ensure that [`Plugin`]s which produce/require a [`Resource`](bevy_ecs::system::Resource)
 /// are built before/after dependent/depending [`Plugin`]s. [`Plugin`]s inside the group
 /// can be disabled, enabled or reordered.
+///
+/// When the group is [finished](Self::finish), the order is adjusted so that the
+/// [dependencies](Plugin::dependencies) and [optional dependencies](Plugin::optional_dependencies)
+/// of each [`Plugin`] that are part of the group are built before it.
 #[derive(Default)]
 pub struct PluginGroupBuilder {
     plugins: HashMap<TypeId, PluginEntry>,
@@ -126,23 +130,73 @@ impl PluginGroupBuilder {
     }
 
     /// Consumes the [`PluginGroupBuilder`] and [builds](Plugin::build) the contained [`Plugin`]s
-    /// in the order specified.
-    pub fn finish(self, app: &mut App) {
-        for ty in self.order.iter() {
-            if let Some(entry) = self.plugins.get(ty) {
-                if entry.enabled {
-                    debug!("added plugin: {}", entry.plugin.name());
-                    entry.plugin.build(app);
+    /// in the order specified, moving [`Plugin`]s after their dependencies when needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dependencies of the enabled [`Plugin`]s form a cycle, or if a [`Plugin`]
+    /// fails to be added to the [`App`]. See [`App::add_plugin`].
+    pub fn finish(mut self, app: &mut App) {
+        for ty in self.sorted_order() {
+            let entry = self.plugins.remove(&ty).unwrap();
+            app.add_boxed_plugin(entry.plugin);
+        }
+    }
+
+    /// Returns the enabled [`Plugin`]s, sorted so that each one comes after its dependencies that
+    /// are part of the group. Otherwise, the specified order is kept.
+    fn sorted_order(&self) -> Vec<TypeId> {
+        let enabled = self
+            .order
+            .iter()
+            .copied()
+            .filter(|ty| self.plugins.get(ty).map_or(false, |entry| entry.enabled))
+            .collect::<Vec<_>>();
+        let dependencies = enabled
+            .iter()
+            .map(|ty| {
+                let plugin = &self.plugins[ty].plugin;
+                plugin
+                    .dependencies()
+                    .into_iter()
+                    .chain(plugin.optional_dependencies())
+                    .map(|dependency| dependency.type_id())
+                    .filter(|dependency| enabled.contains(dependency))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut sorted = Vec::with_capacity(enabled.len());
+        while sorted.len() < enabled.len() {
+            let next = enabled.iter().enumerate().position(|(i, ty)| {
+                !sorted.contains(ty)
+                    && dependencies[i]
+                        .iter()
+                        .all(|dependency| sorted.contains(dependency))
+            });
+            match next {
+                Some(i) => sorted.push(enabled[i]),
+                None => {
+                    let cycle = enabled
+                        .iter()
+                        .filter(|ty| !sorted.contains(ty))
+                        .map(|ty| self.plugins[ty].plugin.name())
+                        .collect::<Vec<_>>();
+                    panic!(
+                        "Plugins in group have cyclic dependencies: {}.",
+                        cycle.join(", ")
+                    );
                 }
             }
         }
+        sorted
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::PluginGroupBuilder;
-    use crate::{App, Plugin};
+    use crate::{App, Plugin, PluginDependency};
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -263,4 +317,97 @@ mod tests {
             ]
         )
     }
+
+    struct DependsOnA;
+    impl Plugin for DependsOnA {
+        fn build(&self, app: &mut App) {
+            assert!(app.is_plugin_added::<PluginA>());
+        }
+        fn dependencies(&self) -> Vec<PluginDependency> {
+            vec![PluginDependency::of::<PluginA>()]
+        }
+    }
+
+    struct AfterB;
+    impl Plugin for AfterB {
+        fn build(&self, _: &mut App) {}
+        fn optional_dependencies(&self) -> Vec<PluginDependency> {
+            vec![PluginDependency::of::<PluginB>()]
+        }
+    }
+
+    struct UniquePlugin;
+    impl Plugin for UniquePlugin {
+        fn build(&self, _: &mut App) {}
+        fn is_unique(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn dependencies_are_sorted_first() {
+        let mut group = PluginGroupBuilder::default();
+        group.add(DependsOnA);
+        group.add(AfterB);
+        group.add(PluginC);
+        group.add(PluginB);
+        group.add(PluginA);
+
+        assert_eq!(
+            group.sorted_order(),
+            vec![
+                std::any::TypeId::of::<PluginC>(),
+                std::any::TypeId::of::<PluginB>(),
+                std::any::TypeId::of::<AfterB>(),
+                std::any::TypeId::of::<PluginA>(),
+                std::any::TypeId::of::<DependsOnA>(),
+            ]
+        );
+
+        let mut app = App::new();
+        group.finish(&mut app);
+        assert!(app.is_plugin_added::<DependsOnA>());
+    }
+
+    #[test]
+    fn disabled_optional_dependency_is_ignored() {
+        let mut group = PluginGroupBuilder::default();
+        group.add(AfterB);
+        group.add(PluginB);
+        group.disable::<PluginB>();
+
+        assert_eq!(group.sorted_order(), vec![std::any::TypeId::of::<AfterB>()]);
+    }
+
+    #[test]
+    #[should_panic(expected = "depends on plugins that must be added first")]
+    fn missing_dependency() {
+        App::new().add_plugin(DependsOnA);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be added before the plugins that depend on it")]
+    fn optional_dependency_added_late() {
+        App::new().add_plugin(AfterB).add_plugin(PluginB);
+    }
+
+    #[test]
+    fn optional_dependency_not_added() {
+        let mut app = App::new();
+        app.add_plugin(AfterB)
+            .add_plugin(PluginA)
+            .add_plugin(DependsOnA);
+        assert!(!app.is_plugin_added::<PluginB>());
+    }
+
+    #[test]
+    #[should_panic(expected = "was already added to the app and is unique")]
+    fn unique_plugin_added_twice() {
+        App::new().add_plugin(UniquePlugin).add_plugin(UniquePlugin);
+    }
+
+    #[test]
+    fn non_unique_plugin_added_twice() {
+        App::new().add_plugin(PluginA).add_plugin(PluginA);
+    }
 }
@@ -74,6 +74,7 @@ impl Plugin for ScheduleRunnerPlugin {
             match settings.run_mode {
                 RunMode::Once => {
                     app.update();
+                    app.shutdown();
                 }
                 RunMode::Loop { wait } => {
                     let mut tick = move |app: &mut App,
@@ -120,6 +121,7 @@ impl Plugin for ScheduleRunnerPlugin {
                                 std::thread::sleep(delay);
                             }
                         }
+                        app.shutdown();
                     }
 
                     #[cfg(target_arch = "wasm32")]
@@ -146,7 +148,7 @@ impl Plugin for ScheduleRunnerPlugin {
                                 Ok(delay) => {
                                     set_timeout(f.borrow().as_ref().unwrap(), delay.unwrap_or(asap))
                                 }
-                                Err(_) => {}
+                                Err(_) => app.shutdown(),
                             }
                         };
                         *g.borrow_mut() = Some(Closure::wrap(Box::new(c) as Box<dyn FnMut()>));
@@ -0,0 +1,368 @@
+use crate::App;
+use bevy_ecs::{
+    schedule::{Schedule, Stage, StageLabel, SystemStage},
+    world::World,
+};
+use std::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    sync::mpsc::{channel, Receiver, Sender},
+    thread::JoinHandle,
+};
+
+/// The label of the [`Stage`] of a sub-app in which data is extracted
+/// from the main app's [`World`].
+///
+/// This stage is added to the sub-apps created with [`App::add_extract_sub_app`]. Its systems run
+/// on the main app's [`World`], while the sub-app's [`World`] is temporarily available through the
+/// [`SubAppWorld`] resource. [`Commands`](bevy_ecs::system::Commands) issued by these systems are
+/// applied to the sub-app's [`World`].
+///
+/// This stage is skipped when running the rest of the sub-app's [`Schedule`].
+#[derive(Debug, Hash, PartialEq, Eq, Clone, StageLabel)]
+pub struct ExtractStage;
+
+/// The [`World`] of a sub-app, inserted as a resource into the main app's [`World`] while the
+/// sub-app's [`ExtractStage`] runs.
+pub struct SubAppWorld(World);
+
+impl Deref for SubAppWorld {
+    type Target = World;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for SubAppWorld {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Determines how the [`Schedule`] of a sub-app added with [`App::add_extract_sub_app`] runs
+/// relative to the main app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubAppExecution {
+    /// The sub-app's schedule runs on the calling thread right after the extraction, and
+    /// [`App::update`] returns once it is done.
+    Blocking,
+    /// The sub-app's schedule runs on a dedicated thread after the extraction, concurrently with
+    /// the next update of the main app. The main app only waits for it to complete before
+    /// extracting again.
+    ///
+    /// On `wasm32`, where threads are not available, this behaves like
+    /// [`SubAppExecution::Blocking`].
+    Pipelined,
+}
+
+/// A sub-app stored inside of an [`App`], with the logic used to run it.
+pub(crate) struct SubApp {
+    pub(crate) app: App,
+    runner: SubAppRunner,
+}
+
+/// Runs a sub-app given the main app's [`World`].
+type SubAppRunFn = dyn Fn(&mut World, &mut App);
+
+enum SubAppRunner {
+    Custom(Box<SubAppRunFn>),
+    Extract(Box<ExtractRunner>),
+}
+
+impl SubApp {
+    pub(crate) fn new(app: App, runner: impl Fn(&mut World, &mut App) + 'static) -> Self {
+        Self {
+            app,
+            runner: SubAppRunner::Custom(Box::new(runner)),
+        }
+    }
+
+    pub(crate) fn with_extract(
+        label: &dyn Debug,
+        mut app: App,
+        execution: SubAppExecution,
+    ) -> Self {
+        let has_extract_stage = app
+            .schedule
+            .iter_stages()
+            .any(|(stage_label, _)| stage_label == &ExtractStage as &dyn StageLabel);
+        if !has_extract_stage {
+            app.add_stage(ExtractStage, SystemStage::parallel());
+        }
+        // the extract stage runs on the main world, but the buffers are applied to the sub-app world
+        app.schedule
+            .get_stage_mut::<SystemStage>(&ExtractStage)
+            .expect("The ExtractStage of a sub-app must be a SystemStage")
+            .set_apply_buffers(false);
+
+        Self {
+            app,
+            runner: SubAppRunner::Extract(Box::new(ExtractRunner::new(label, execution))),
+        }
+    }
+
+    /// Runs the sub-app, giving it access to the main app's [`World`].
+    pub(crate) fn run(&mut self, main_world: &mut World) {
+        match &mut self.runner {
+            SubAppRunner::Custom(runner) => runner(main_world, &mut self.app),
+            SubAppRunner::Extract(runner) => runner.run(main_world, &mut self.app),
+        }
+    }
+
+    /// Whether the sub-app is running on another thread, its [`World`] and [`Schedule`] being moved
+    /// out of it.
+    pub(crate) fn is_running(&self) -> bool {
+        match &self.runner {
+            SubAppRunner::Custom(_) => false,
+            SubAppRunner::Extract(runner) => runner.is_running(),
+        }
+    }
+
+    /// Waits for the sub-app to complete its current run if it is running on another thread, so
+    /// that its [`World`] and [`Schedule`] are back in place.
+    pub(crate) fn finish(&mut self) {
+        if let SubAppRunner::Extract(runner) = &mut self.runner {
+            runner.finish(&mut self.app);
+        }
+    }
+}
+
+/// Runs the extraction of a sub-app, then the rest of its schedule, either inline or pipelined on
+/// a dedicated thread.
+struct ExtractRunner {
+    /// A "scratch" world used to avoid allocating new worlds every frame when moving out the
+    /// sub-app world.
+    scratch_world: Option<World>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pipeline: Option<Pipeline>,
+}
+
+impl ExtractRunner {
+    #[allow(unused_variables)]
+    fn new(label: &dyn Debug, execution: SubAppExecution) -> Self {
+        Self {
+            scratch_world: Some(World::new()),
+            #[cfg(not(target_arch = "wasm32"))]
+            pipeline: match execution {
+                SubAppExecution::Blocking => None,
+                SubAppExecution::Pipelined => Some(Pipeline::new(label)),
+            },
+        }
+    }
+
+    fn run(&mut self, main_world: &mut World, app: &mut App) {
+        self.finish(app);
+        self.extract(main_world, app);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(pipeline) = &mut self.pipeline {
+            let scratch_world = self.scratch_world.take().unwrap();
+            let world = std::mem::replace(&mut app.world, scratch_world);
+            let schedule = std::mem::take(&mut app.schedule);
+            pipeline.send(world, schedule);
+            return;
+        }
+
+        run_sub_app_schedule(&mut app.schedule, &mut app.world);
+    }
+
+    /// Executes the [`ExtractStage`] of the sub-app.
+    /// This updates the sub-app world with the extracted data of the current frame.
+    fn extract(&mut self, main_world: &mut World, app: &mut App) {
+        let extract = app
+            .schedule
+            .get_stage_mut::<SystemStage>(&ExtractStage)
+            .unwrap();
+
+        // temporarily add the sub-app world to the main world as a resource
+        let scratch_world = self.scratch_world.take().unwrap();
+        let sub_world = std::mem::replace(&mut app.world, scratch_world);
+        main_world.insert_resource(SubAppWorld(sub_world));
+
+        extract.run(main_world);
+
+        // add the sub-app world back to the sub-app
+        let sub_world = main_world.remove_resource::<SubAppWorld>().unwrap();
+        self.scratch_world = Some(std::mem::replace(&mut app.world, sub_world.0));
+
+        extract.apply_buffers(&mut app.world);
+    }
+
+    fn is_running(&self) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(pipeline) = &self.pipeline {
+            return pipeline.in_flight;
+        }
+        false
+    }
+
+    #[allow(unused_variables)]
+    fn finish(&mut self, app: &mut App) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some((world, schedule)) = self.pipeline.as_mut().and_then(Pipeline::receive) {
+            self.scratch_world = Some(std::mem::replace(&mut app.world, world));
+            app.schedule = schedule;
+        }
+    }
+}
+
+fn run_sub_app_schedule(schedule: &mut Schedule, world: &mut World) {
+    schedule.run_once_filtered(world, |label| label != &ExtractStage as &dyn StageLabel);
+}
+
+/// A dedicated thread running the schedule of a pipelined sub-app.
+#[cfg(not(target_arch = "wasm32"))]
+struct Pipeline {
+    sender: Option<Sender<(World, Schedule)>>,
+    receiver: Receiver<(World, Schedule)>,
+    in_flight: bool,
+    thread: Option<JoinHandle<()>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Pipeline {
+    fn new(label: &dyn Debug) -> Self {
+        let (sender, thread_receiver) = channel::<(World, Schedule)>();
+        let (thread_sender, receiver) = channel();
+        let thread = std::thread::Builder::new()
+            .name(format!("sub-app {:?}", label))
+            .spawn(move || {
+                while let Ok((mut world, mut schedule)) = thread_receiver.recv() {
+                    run_sub_app_schedule(&mut schedule, &mut world);
+                    if thread_sender.send((world, schedule)).is_err() {
+                        break;
+                    }
+                }
+            })
+            .expect("Failed to spawn the sub-app thread.");
+
+        Self {
+            sender: Some(sender),
+            receiver,
+            in_flight: false,
+            thread: Some(thread),
+        }
+    }
+
+    fn send(&mut self, world: World, schedule: Schedule) {
+        self.sender
+            .as_ref()
+            .unwrap()
+            .send((world, schedule))
+            .expect("The sub-app thread has panicked.");
+        self.in_flight = true;
+    }
+
+    fn receive(&mut self) -> Option<(World, Schedule)> {
+        if !self.in_flight {
+            return None;
+        }
+        self.in_flight = false;
+        Some(
+            self.receiver
+                .recv()
+                .expect("The sub-app thread has panicked."),
+        )
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for Pipeline {
+    fn drop(&mut self) {
+        // closing the channel stops the thread once its current run completes
+        self.sender = None;
+        if let Some(thread) = self.thread.take() {
+            // a panic on the sub-app thread was already reported there
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{App, ExtractStage, SubAppExecution, SubAppWorld};
+    use bevy_ecs::prelude::*;
+
+    const TEST_SUB_APP: &str = "test_sub_app";
+
+    #[derive(Default)]
+    struct Counter(u32);
+
+    #[derive(Default)]
+    struct Extracted(u32);
+
+    #[derive(Default)]
+    struct Processed(Vec<u32>);
+
+    fn increment(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    fn extract_counter(mut commands: Commands, counter: Res<Counter>, sub_world: Res<SubAppWorld>) {
+        assert!(sub_world.contains_resource::<Processed>());
+        commands.insert_resource(Extracted(counter.0));
+    }
+
+    fn process(extracted: Res<Extracted>, mut processed: ResMut<Processed>) {
+        processed.0.push(extracted.0);
+    }
+
+    fn test_app(execution: SubAppExecution) -> App {
+        let mut sub_app = App::empty();
+        sub_app
+            .init_resource::<Extracted>()
+            .init_resource::<Processed>()
+            .add_stage(ExtractStage, SystemStage::parallel())
+            .add_stage("process", SystemStage::single(process))
+            .add_system_to_stage(ExtractStage, extract_counter);
+
+        let mut app = App::new();
+        app.init_resource::<Counter>()
+            .add_system(increment)
+            .add_extract_sub_app(TEST_SUB_APP, sub_app, execution);
+        app
+    }
+
+    #[test]
+    fn blocking_sub_app() {
+        let mut app = test_app(SubAppExecution::Blocking);
+        app.update_frames(3);
+
+        // the extract stage does not leave the sub-app world in the main world
+        assert!(!app.world.contains_resource::<SubAppWorld>());
+        assert!(!app.world.contains_resource::<Extracted>());
+        let sub_app = app.sub_app(TEST_SUB_APP);
+        assert_eq!(sub_app.world.resource::<Processed>().0, vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The ExtractStage of a sub-app must be a SystemStage")]
+    fn extract_stage_of_another_type() {
+        let mut sub_app = App::empty();
+        sub_app.add_stage(ExtractStage, Schedule::default());
+        App::new().add_extract_sub_app(TEST_SUB_APP, sub_app, SubAppExecution::Blocking);
+    }
+
+    #[test]
+    fn pipelined_sub_app() {
+        let mut app = test_app(SubAppExecution::Pipelined);
+        app.update_frames(3);
+
+        // the sub-app is not available while it runs on its own thread
+        assert!(app.get_sub_app(TEST_SUB_APP).is_err());
+        let sub_app = app.sub_app_mut(TEST_SUB_APP);
+        assert_eq!(sub_app.world.resource::<Processed>().0, vec![1, 2, 3]);
+
+        // the sub-app can keep being configured between updates
+        sub_app.world.resource_mut::<Processed>().0.clear();
+        assert!(app.get_sub_app(TEST_SUB_APP).is_ok());
+        app.update();
+        let sub_app = app.sub_app_mut(TEST_SUB_APP);
+        assert_eq!(sub_app.world.resource::<Processed>().0, vec![4]);
+    }
+}
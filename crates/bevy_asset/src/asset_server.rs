@@ -1,17 +1,36 @@
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+use crate::ProcessedAssetIo;
 use crate::{
-    path::{AssetPath, AssetPathId, SourcePathId},
+    get_meta_path,
+    meta::AssetLoaderMeta,
+    path::{get_hasher, AssetPath, AssetPathId, SourcePathId},
+    saver::ErasedAssetSaver,
     Asset, AssetIo, AssetIoError, AssetLifecycle, AssetLifecycleChannel, AssetLifecycleEvent,
-    AssetLoader, Assets, Handle, HandleId, HandleUntyped, LabelId, LoadContext, LoadState,
-    RefChange, RefChangeChannel, SourceInfo, SourceMeta,
+    AssetLoaderSettings, AssetSaver, Assets, ErasedAssetLoader, Handle, HandleId, HandleUntyped,
+    LabelId, LoadContext, LoadState, ReaderAssetLoader, RefChange, RefChangeChannel, SourceInfo,
+    SourceMeta,
 };
 use anyhow::Result;
-use bevy_ecs::system::{Res, ResMut};
+use bevy_ecs::{
+    event::EventWriter,
+    system::{Res, ResMut},
+};
 use bevy_log::warn;
+use bevy_reflect::Reflect;
 use bevy_tasks::TaskPool;
-use bevy_utils::{Entry, HashMap, Uuid};
+use bevy_utils::{Entry, HashMap, HashSet, Uuid};
 use crossbeam_channel::TryRecvError;
 use parking_lot::{Mutex, RwLock};
-use std::{path::Path, sync::Arc};
+use std::{
+    any::Any,
+    borrow::Cow,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Weak,
+    },
+};
 use thiserror::Error;
 
 /// Errors that occur while loading assets with an `AssetServer`
@@ -19,14 +38,32 @@ use thiserror::Error;
 pub enum AssetServerError {
     #[error("asset folder path is not a directory: {0}")]
     AssetFolderNotADirectory(String),
+    #[error("no asset source found with the name: {0}")]
+    MissingAssetSource(String),
     #[error("no `AssetLoader` found{}", format_missing_asset_ext(.extensions))]
     MissingAssetLoader { extensions: Vec<String> },
+    #[error("no `AssetLoader` found with the name: {0}")]
+    MissingAssetLoaderForTypeName(String),
+    #[error("failed to read the meta file {path:?}: {error}")]
+    InvalidMetaFile { path: PathBuf, error: ron::Error },
     #[error("the given type does not match the type of the loaded asset")]
     IncorrectHandleType,
     #[error("encountered an error while loading an asset: {0}")]
     AssetLoaderError(anyhow::Error),
     #[error("encountered an error while reading an asset: {0}")]
     AssetIoError(#[from] AssetIoError),
+    #[error("no asset found for the handle: {0:?}")]
+    MissingAsset(HandleId),
+    #[error("no `AssetSaver` found for the asset type and the extension of: {0:?}")]
+    MissingAssetSaver(PathBuf),
+    #[error("the asset source of {0:?} is not writable")]
+    AssetSourceNotWritable(AssetPath<'static>),
+    #[error("encountered an error while saving an asset: {0}")]
+    AssetSaverError(anyhow::Error),
+    #[error("the asset {0:?} depends on itself")]
+    CyclicLoad(AssetPath<'static>),
+    #[error("the loader of {0:?} did not load an asset with its label")]
+    MissingLoadedAsset(AssetPath<'static>),
 }
 
 fn format_missing_asset_ext(exts: &[String]) -> String {
@@ -49,16 +86,74 @@ pub(crate) struct AssetRefCounter {
 }
 
 pub struct AssetServerInternal {
-    pub(crate) asset_io: Box<dyn AssetIo>,
+    pub(crate) asset_io: Arc<dyn AssetIo>,
+    pub(crate) named_sources: RwLock<HashMap<String, Arc<dyn AssetIo>>>,
+    pub(crate) watching_for_changes: AtomicBool,
     pub(crate) asset_ref_counter: AssetRefCounter,
     pub(crate) asset_sources: Arc<RwLock<HashMap<SourcePathId, SourceInfo>>>,
     pub(crate) asset_lifecycles: Arc<RwLock<HashMap<Uuid, Box<dyn AssetLifecycle>>>>,
-    loaders: RwLock<Vec<Arc<dyn AssetLoader>>>,
+    loaders: RwLock<Vec<Arc<dyn ErasedAssetLoader>>>,
     extension_to_loader_index: RwLock<HashMap<String, usize>>,
+    settings_overrides: RwLock<HashMap<SourcePathId, Arc<SettingsOverride>>>,
+    savers: RwLock<Vec<Arc<dyn ErasedAssetSaver>>>,
+    /// The assets saved to watched files, until the change to their file is seen.
+    saving_assets: Mutex<HashMap<SourcePathId, SavedAsset>>,
     handle_to_path: Arc<RwLock<HashMap<HandleId, AssetPath<'static>>>>,
-    task_pool: TaskPool,
+    failed_loads: Mutex<Vec<AssetLoadFailedEvent>>,
+    /// The values returned by [`LoadContext::load_direct`], reused while they are alive.
+    direct_loads: Mutex<HashMap<AssetPathId, Weak<dyn Any + Send + Sync>>>,
+    /// The values being loaded by [`LoadContext::load_direct`], closed once they are loaded.
+    direct_loading: Mutex<HashMap<AssetPathId, async_channel::Receiver<()>>>,
+    /// The assets whose loaders loaded the source asset directly, reloaded when it changes.
+    direct_dependents: RwLock<HashMap<SourcePathId, HashMap<SourcePathId, AssetPath<'static>>>>,
+    pub(crate) task_pool: TaskPool,
+}
+
+/// An event sent when an asset fails to load.
+///
+/// The error is also returned by [`AssetServer::get_load_error`] until the asset is loaded again.
+#[derive(Debug, Clone)]
+pub struct AssetLoadFailedEvent {
+    pub path: AssetPath<'static>,
+    /// A weak handle to the asset.
+    pub handle: HandleUntyped,
+    pub error: Arc<AssetServerError>,
+}
+
+/// A value being loaded by [`AssetServer::load_direct`], which wakes up the loads waiting for
+/// it when dropped.
+struct DirectLoading<'a> {
+    server: &'a AssetServerInternal,
+    asset_path_id: AssetPathId,
+    _sender: async_channel::Sender<()>,
+}
+
+impl Drop for DirectLoading<'_> {
+    fn drop(&mut self) {
+        self.server
+            .direct_loading
+            .lock()
+            .remove(&self.asset_path_id);
+    }
+}
+
+/// An asset written by [`AssetServer::save`], whose file change is not reloaded.
+struct SavedAsset {
+    /// The hash of the bytes written to the file.
+    hash: u64,
+    /// Whether the write completed.
+    written: bool,
 }
 
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = get_hasher();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Changes the settings of a loader, for [`AssetServer::load_with_settings`].
+type SettingsOverride = dyn Fn(&mut dyn Reflect) + Send + Sync;
+
 /// Loads assets from the filesystem on background threads
 #[derive(Clone)]
 pub struct AssetServer {
@@ -75,20 +170,67 @@ impl AssetServer {
             server: Arc::new(AssetServerInternal {
                 loaders: Default::default(),
                 extension_to_loader_index: Default::default(),
+                settings_overrides: Default::default(),
+                savers: Default::default(),
+                saving_assets: Default::default(),
                 asset_sources: Default::default(),
                 asset_ref_counter: Default::default(),
                 handle_to_path: Default::default(),
+                failed_loads: Default::default(),
+                direct_loads: Default::default(),
+                direct_loading: Default::default(),
+                direct_dependents: Default::default(),
                 asset_lifecycles: Default::default(),
                 task_pool,
-                asset_io,
+                asset_io: asset_io.into(),
+                named_sources: Default::default(),
+                watching_for_changes: AtomicBool::new(false),
             }),
         }
     }
 
+    /// The [`AssetIo`] of the default asset source.
     pub fn asset_io(&self) -> &dyn AssetIo {
         &*self.server.asset_io
     }
 
+    /// Adds a named asset source, whose assets are loaded with paths such as
+    /// `"name://path/to/asset.png"`.
+    pub fn add_source<T: AssetIo>(&self, name: impl Into<String>, asset_io: T) {
+        self.add_boxed_source(name, Box::new(asset_io));
+    }
+
+    pub fn add_boxed_source(&self, name: impl Into<String>, asset_io: Box<dyn AssetIo>) {
+        let name = name.into();
+        if self.server.watching_for_changes.load(Ordering::Relaxed) {
+            if let Err(err) = asset_io.watch_for_changes() {
+                warn!("failed to watch asset source {} for changes: {}", name, err);
+            }
+        }
+        self.server
+            .named_sources
+            .write()
+            .insert(name, asset_io.into());
+    }
+
+    /// Returns the [`AssetIo`] of the asset source named `source`, or of the default asset
+    /// source for `None`.
+    pub fn get_source_io(
+        &self,
+        source: Option<&str>,
+    ) -> Result<Arc<dyn AssetIo>, AssetServerError> {
+        match source {
+            Some(source) => self
+                .server
+                .named_sources
+                .read()
+                .get(source)
+                .cloned()
+                .ok_or_else(|| AssetServerError::MissingAssetSource(source.to_string())),
+            None => Ok(self.server.asset_io.clone()),
+        }
+    }
+
     pub(crate) fn register_asset_type<T: Asset>(&self) -> Assets<T> {
         if self
             .server
@@ -108,11 +250,11 @@ impl AssetServer {
 
     pub fn add_loader<T>(&self, loader: T)
     where
-        T: AssetLoader,
+        T: ReaderAssetLoader,
     {
         let mut loaders = self.server.loaders.write();
         let loader_index = loaders.len();
-        for extension in loader.extensions().iter() {
+        for extension in loader.reader_extensions().iter() {
             self.server
                 .extension_to_loader_index
                 .write()
@@ -121,10 +263,165 @@ impl AssetServer {
         loaders.push(Arc::new(loader));
     }
 
+    pub fn add_saver<T>(&self, saver: T)
+    where
+        T: AssetSaver,
+    {
+        self.server.savers.write().push(Arc::new(saver));
+    }
+
+    fn get_asset_saver(
+        &self,
+        type_uuid: Uuid,
+        path: &Path,
+    ) -> Result<Arc<dyn ErasedAssetSaver>, AssetServerError> {
+        let file_name = path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .map(|file_name| file_name.to_lowercase())
+            .unwrap_or_default();
+        self.server
+            .savers
+            .read()
+            .iter()
+            .find(|saver| {
+                saver.asset_type_uuid() == type_uuid
+                    && saver.extensions().iter().any(|extension| {
+                        file_name.ends_with(&format!(".{}", extension.to_lowercase()))
+                    })
+            })
+            .cloned()
+            .ok_or_else(|| AssetServerError::MissingAssetSaver(path.to_owned()))
+    }
+
+    /// Saves the asset of `handle` to `path`, with the [`AssetSaver`] of its type and extension.
+    ///
+    /// The asset is serialized immediately and written to its asset source in the background.
+    /// The resulting file change is not reloaded when watching for changes, unless the file is
+    /// changed again before the change is seen.
+    pub fn save<'a, T: Asset, P: Into<AssetPath<'a>>>(
+        &self,
+        assets: &Assets<T>,
+        handle: &Handle<T>,
+        path: P,
+    ) -> Result<(), AssetServerError> {
+        let asset_path = path.into().to_owned();
+        let asset = assets
+            .get(handle)
+            .ok_or(AssetServerError::MissingAsset(handle.id))?;
+        let saver = self.get_asset_saver(T::TYPE_UUID, asset_path.path())?;
+        let bytes = saver
+            .save(asset)
+            .map_err(AssetServerError::AssetSaverError)?;
+        let asset_io = self.get_source_io(asset_path.source())?;
+        if asset_io.writer().is_none() {
+            return Err(AssetServerError::AssetSourceNotWritable(asset_path));
+        }
+
+        // only the files of loaded assets are watched
+        let source_path_id = asset_path.get_id().source_path_id();
+        let watched = self.server.watching_for_changes.load(Ordering::Relaxed)
+            && self.get_load_state(asset_path.get_id()) != LoadState::NotLoaded;
+        if watched {
+            self.server.saving_assets.lock().insert(
+                source_path_id,
+                SavedAsset {
+                    hash: hash_bytes(&bytes),
+                    written: false,
+                },
+            );
+        }
+        let server = self.clone();
+        self.server
+            .task_pool
+            .spawn(async move {
+                let result = asset_io
+                    .writer()
+                    .unwrap()
+                    .write_path(asset_path.path(), &bytes)
+                    .await;
+                let mut saving_assets = server.server.saving_assets.lock();
+                match result {
+                    Ok(()) => {
+                        if let Some(saved) = saving_assets.get_mut(&source_path_id) {
+                            saved.written = true;
+                        }
+                    }
+                    Err(err) => {
+                        saving_assets.remove(&source_path_id);
+                        warn!("failed to save {:?}: {}", asset_path, err);
+                    }
+                }
+            })
+            .detach();
+        Ok(())
+    }
+
+    /// Reloads the asset whose file changed, unless the change was made by
+    /// [`AssetServer::save`].
+    ///
+    /// The file of a saved asset is read on the task pool to be compared with the saved bytes.
+    #[cfg_attr(not(feature = "filesystem_watcher"), allow(dead_code))]
+    pub(crate) fn reload_unless_save_echo(&self, asset_path: AssetPath<'static>) {
+        let source_path_id = asset_path.get_id().source_path_id();
+        if !self
+            .server
+            .saving_assets
+            .lock()
+            .contains_key(&source_path_id)
+        {
+            self.reload_changed_asset(asset_path);
+            return;
+        }
+        let server = self.clone();
+        self.server
+            .task_pool
+            .spawn(async move {
+                if !server.is_save_echo(&asset_path).await {
+                    server.reload_changed_asset(asset_path);
+                }
+            })
+            .detach();
+    }
+
+    /// Returns whether a change to the file of the asset was made by [`AssetServer::save`],
+    /// comparing the content of the file with the saved bytes.
+    ///
+    /// The saved asset is forgotten once the content of its file is seen.
+    #[cfg_attr(not(feature = "filesystem_watcher"), allow(dead_code))]
+    async fn is_save_echo(&self, asset_path: &AssetPath<'_>) -> bool {
+        let saved = {
+            let source_path_id = asset_path.get_id().source_path_id();
+            let mut saving_assets = self.server.saving_assets.lock();
+            let saved = match saving_assets.get(&source_path_id) {
+                None => return false,
+                // the file is still being written
+                Some(saved) if !saved.written => return true,
+                Some(saved) => saved.hash,
+            };
+            saving_assets.remove(&source_path_id);
+            saved
+        };
+        let asset_io = match self.get_source_io(asset_path.source()) {
+            Ok(asset_io) => asset_io,
+            Err(_) => return false,
+        };
+        asset_io
+            .load_path(asset_path.path())
+            .await
+            .map_or(false, |bytes| hash_bytes(&bytes) == saved)
+    }
+
     /// Enable watching of the filesystem for changes, if support is available, starting from after
     /// the point of calling this function.
     pub fn watch_for_changes(&self) -> Result<(), AssetServerError> {
+        self.server
+            .watching_for_changes
+            .store(true, Ordering::Relaxed);
         self.asset_io().watch_for_changes()?;
+        for asset_io in self.server.named_sources.read().values() {
+            asset_io.watch_for_changes()?;
+        }
         Ok(())
     }
 
@@ -138,7 +435,10 @@ impl AssetServer {
         HandleUntyped::strong(id.into(), sender)
     }
 
-    fn get_asset_loader(&self, extension: &str) -> Result<Arc<dyn AssetLoader>, AssetServerError> {
+    fn get_asset_loader(
+        &self,
+        extension: &str,
+    ) -> Result<Arc<dyn ErasedAssetLoader>, AssetServerError> {
         let index = {
             // scope map to drop lock as soon as possible
             let map = self.server.extension_to_loader_index.read();
@@ -151,10 +451,23 @@ impl AssetServer {
             })
     }
 
+    fn get_asset_loader_by_type_name(
+        &self,
+        type_name: &str,
+    ) -> Result<Arc<dyn ErasedAssetLoader>, AssetServerError> {
+        self.server
+            .loaders
+            .read()
+            .iter()
+            .find(|loader| loader.type_name() == type_name)
+            .cloned()
+            .ok_or_else(|| AssetServerError::MissingAssetLoaderForTypeName(type_name.to_string()))
+    }
+
     fn get_path_asset_loader<P: AsRef<Path>>(
         &self,
         path: P,
-    ) -> Result<Arc<dyn AssetLoader>, AssetServerError> {
+    ) -> Result<Arc<dyn ErasedAssetLoader>, AssetServerError> {
         let s = path
             .as_ref()
             .file_name()
@@ -181,6 +494,40 @@ impl AssetServer {
         })
     }
 
+    /// Returns the path used to pick the loader of the asset at `path`, which is the path of its
+    /// processed output when loading processed assets.
+    fn get_loader_path<'a>(&self, asset_io: &dyn AssetIo, path: &'a Path) -> Cow<'a, Path> {
+        #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+        if let Some(processed_path) = asset_io
+            .downcast_ref::<ProcessedAssetIo>()
+            .and_then(|asset_io| asset_io.processed_path(path))
+        {
+            return Cow::Owned(processed_path);
+        }
+        Cow::Borrowed(path)
+    }
+
+    /// Returns the loader named in the `meta` file of the asset at `path`, or the loader for the
+    /// extension of the asset otherwise.
+    fn get_meta_or_path_asset_loader(
+        &self,
+        asset_io: &dyn AssetIo,
+        meta: Option<&[u8]>,
+        path: &Path,
+    ) -> Result<Arc<dyn ErasedAssetLoader>, AssetServerError> {
+        if let Some(meta) = meta {
+            let loader_meta: AssetLoaderMeta =
+                ron::de::from_bytes(meta).map_err(|error| AssetServerError::InvalidMetaFile {
+                    path: get_meta_path(path),
+                    error,
+                })?;
+            if let Some(type_name) = loader_meta.loader {
+                return self.get_asset_loader_by_type_name(&type_name);
+            }
+        }
+        self.get_path_asset_loader(self.get_loader_path(asset_io, path))
+    }
+
     pub fn get_handle_path<H: Into<HandleId>>(&self, handle: H) -> Option<AssetPath<'_>> {
         self.server
             .handle_to_path
@@ -201,6 +548,81 @@ impl AssetServer {
         }
     }
 
+    /// Returns the error the asset failed to load with, if its load state is
+    /// [`LoadState::Failed`].
+    pub fn get_load_error<H: Into<HandleId>>(&self, handle: H) -> Option<Arc<AssetServerError>> {
+        match handle.into() {
+            HandleId::AssetPathId(id) => self
+                .server
+                .asset_sources
+                .read()
+                .get(&id.source_path_id())
+                .and_then(|info| info.load_error.clone()),
+            HandleId::Id(_, _) => None,
+        }
+    }
+
+    /// Returns the load state of the asset and of its dependencies, recursively.
+    ///
+    /// This is [`LoadState::Loaded`] once the asset and every asset it depends on are loaded,
+    /// and [`LoadState::Failed`] if any of them failed to load.
+    pub fn get_recursive_dependency_load_state<H: Into<HandleId>>(&self, handle: H) -> LoadState {
+        let id = match handle.into() {
+            HandleId::AssetPathId(id) => id,
+            HandleId::Id(_, _) => return LoadState::NotLoaded,
+        };
+        let asset_sources = self.server.asset_sources.read();
+        let root_load_state = asset_sources
+            .get(&id.source_path_id())
+            .map_or(LoadState::NotLoaded, |info| info.load_state);
+        if root_load_state != LoadState::Loaded {
+            return root_load_state;
+        }
+
+        let mut load_state = LoadState::Loaded;
+        let mut visited = HashSet::default();
+        let mut queue = vec![id.source_path_id()];
+        while let Some(source_path_id) = queue.pop() {
+            if !visited.insert(source_path_id) {
+                continue;
+            }
+            let source_info = match asset_sources.get(&source_path_id) {
+                Some(source_info) => source_info,
+                // the load of this dependency has not started yet
+                None => {
+                    load_state = LoadState::Loading;
+                    continue;
+                }
+            };
+            match source_info.load_state {
+                LoadState::Loaded => {}
+                LoadState::Failed => return LoadState::Failed,
+                LoadState::Unloaded => {
+                    load_state = LoadState::Unloaded;
+                    continue;
+                }
+                LoadState::NotLoaded | LoadState::Loading => {
+                    if load_state == LoadState::Loaded {
+                        load_state = LoadState::Loading;
+                    }
+                    continue;
+                }
+            }
+            if let Some(meta) = &source_info.meta {
+                for asset_meta in &meta.assets {
+                    queue.extend(
+                        asset_meta
+                            .dependencies
+                            .iter()
+                            .map(|dependency| dependency.get_id().source_path_id()),
+                    );
+                }
+            }
+        }
+
+        load_state
+    }
+
     pub fn get_group_load_state(&self, handles: impl IntoIterator<Item = HandleId>) -> LoadState {
         let mut load_state = LoadState::Loaded;
         for handle_id in handles {
@@ -243,7 +665,39 @@ impl AssetServer {
         self.load_untyped(path).typed()
     }
 
-    async fn load_async(
+    /// Queue an [`Asset`] at the provided relative path for asynchronous loading, changing the
+    /// settings of its [`AssetLoader`](crate::AssetLoader) with `settings`.
+    ///
+    /// The settings are read from the `.meta` file of the asset if there is one, and are then
+    /// passed to `settings`, which is also used when the asset is reloaded. An asset that is
+    /// already loaded is reloaded with the new settings.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load_with_settings<'a, T: Asset, S: AssetLoaderSettings, P: Into<AssetPath<'a>>>(
+        &self,
+        path: P,
+        settings: impl Fn(&mut S) + Send + Sync + 'static,
+    ) -> Handle<T> {
+        let asset_path = path.into();
+        let owned_path = asset_path.to_owned();
+        self.server.settings_overrides.write().insert(
+            asset_path.get_id().source_path_id(),
+            Arc::new(move |loader_settings: &mut dyn Reflect| {
+                match loader_settings.downcast_mut::<S>() {
+                    Some(loader_settings) => settings(loader_settings),
+                    None => warn!(
+                        "the loader settings of {:?} are not of type {}",
+                        owned_path,
+                        std::any::type_name::<S>()
+                    ),
+                }
+            }),
+        );
+        let force = self.get_load_state(asset_path.get_id()) != LoadState::NotLoaded;
+        let handle_id = self.load_untracked(asset_path, force);
+        self.get_handle(handle_id)
+    }
+
+    pub(crate) async fn load_async(
         &self,
         asset_path: AssetPath<'_>,
         force: bool,
@@ -263,6 +717,7 @@ impl AssetServer {
                     meta: None,
                     path: asset_path.path().to_owned(),
                     version: 0,
+                    load_error: None,
                 }),
             };
 
@@ -280,8 +735,15 @@ impl AssetServer {
             source_info.committed_assets.clear();
             source_info.version += 1;
             source_info.meta = None;
+            source_info.load_error = None;
             source_info.version
         };
+        // values loaded directly from the previous version of the asset are outdated
+        self.server
+            .direct_loads
+            .lock()
+            .retain(|id, _| id.source_path_id() != asset_path_id.source_path_id());
+        self.forget_direct_dependents(|id| *id == asset_path_id.source_path_id());
 
         let set_asset_failed = || {
             let mut asset_sources = self.server.asset_sources.write();
@@ -291,42 +753,25 @@ impl AssetServer {
             source_info.load_state = LoadState::Failed;
         };
 
-        // get the according asset loader
-        let asset_loader = match self.get_path_asset_loader(asset_path.path()) {
-            Ok(loader) => loader,
+        let asset_io = match self.get_source_io(asset_path.source()) {
+            Ok(asset_io) => asset_io,
             Err(err) => {
                 set_asset_failed();
                 return Err(err);
             }
         };
-
-        // load the asset bytes
-        let bytes = match self.asset_io().load_path(asset_path.path()).await {
-            Ok(bytes) => bytes,
+        let load_stack = vec![asset_path.to_owned()];
+        let (mut load_context, has_meta) = match self
+            .run_loader(&asset_path, &*asset_io, version, load_stack)
+            .await
+        {
+            Ok(loaded) => loaded,
             Err(err) => {
                 set_asset_failed();
-                return Err(AssetServerError::AssetIoError(err));
+                return Err(err);
             }
         };
 
-        // load the asset source using the corresponding AssetLoader
-        let mut load_context = LoadContext::new(
-            asset_path.path(),
-            &self.server.asset_ref_counter.channel,
-            self.asset_io(),
-            version,
-            &self.server.task_pool,
-        );
-
-        if let Err(err) = asset_loader
-            .load(&bytes, &mut load_context)
-            .await
-            .map_err(AssetServerError::AssetLoaderError)
-        {
-            set_asset_failed();
-            return Err(err);
-        }
-
         // if version has changed since we loaded and grabbed a lock, return. theres is a newer
         // version being loaded
         let mut asset_sources = self.server.asset_sources.write();
@@ -361,13 +806,191 @@ impl AssetServer {
             }
         }
 
-        self.asset_io()
-            .watch_path_for_changes(asset_path.path())
-            .unwrap();
+        asset_io.watch_path_for_changes(asset_path.path()).unwrap();
+        if has_meta {
+            asset_io
+                .watch_path_for_changes(&get_meta_path(asset_path.path()))
+                .unwrap();
+        }
         self.create_assets_in_load_context(&mut load_context);
         Ok(asset_path_id)
     }
 
+    /// Loads the asset at `asset_path` from `asset_io` with its loader and the settings
+    /// of its meta file. Returns the load context holding the loaded assets, and whether the
+    /// asset has a meta file.
+    async fn run_loader<'a>(
+        &'a self,
+        asset_path: &'a AssetPath<'_>,
+        asset_io: &'a dyn AssetIo,
+        version: usize,
+        load_stack: Vec<AssetPath<'static>>,
+    ) -> Result<(LoadContext<'a>, bool), AssetServerError> {
+        // load the meta file of the asset, if there is one
+        let meta_path = get_meta_path(asset_path.path());
+        let meta = match asset_io.load_path(&meta_path).await {
+            Ok(meta) => Some(meta),
+            Err(AssetIoError::NotFound(_)) => None,
+            Err(err) => return Err(AssetServerError::AssetIoError(err)),
+        };
+
+        // get the according asset loader and its settings
+        let asset_loader =
+            self.get_meta_or_path_asset_loader(asset_io, meta.as_deref(), asset_path.path())?;
+        let mut settings = match &meta {
+            Some(meta) => asset_loader.deserialize_settings(meta).map_err(|error| {
+                AssetServerError::InvalidMetaFile {
+                    path: meta_path,
+                    error,
+                }
+            })?,
+            None => asset_loader.default_settings(),
+        };
+        let settings_override = self
+            .server
+            .settings_overrides
+            .read()
+            .get(&asset_path.get_id().source_path_id())
+            .cloned();
+        if let Some(settings_override) = settings_override {
+            settings_override(&mut *settings);
+        }
+
+        // open the asset file
+        let mut reader = asset_io.read_path(asset_path.path()).await?;
+
+        // load the asset source using the corresponding AssetLoader
+        let mut load_context = LoadContext::new(
+            self,
+            asset_path.source(),
+            asset_path.path(),
+            asset_io,
+            version,
+            load_stack,
+        );
+        asset_loader
+            .load_erased(&mut *reader, &*settings, &mut load_context)
+            .await
+            .map_err(AssetServerError::AssetLoaderError)?;
+        Ok((load_context, meta.is_some()))
+    }
+
+    /// Loads the asset at `asset_path` and returns its value, for
+    /// [`LoadContext::load_direct`]. The value is reused while it is alive.
+    pub(crate) async fn load_direct<T: Asset>(
+        &self,
+        asset_path: AssetPath<'static>,
+        mut load_stack: Vec<AssetPath<'static>>,
+    ) -> Result<Arc<T>, AssetServerError> {
+        let asset_path_id = asset_path.get_id();
+        if let Some(loading) = load_stack.last() {
+            let dependent = AssetPath::new_ref(loading.path(), None);
+            let dependent = match loading.source() {
+                Some(source) => dependent.with_source(source),
+                None => dependent,
+            };
+            self.server
+                .direct_dependents
+                .write()
+                .entry(asset_path_id.source_path_id())
+                .or_default()
+                .insert(dependent.get_id().source_path_id(), dependent.to_owned());
+        }
+
+        // wait for the same value being loaded by another loader, instead of loading it twice
+        let _loading = loop {
+            let cached = self
+                .server
+                .direct_loads
+                .lock()
+                .get(&asset_path_id)
+                .and_then(Weak::upgrade);
+            if let Some(asset) = cached {
+                return asset
+                    .downcast::<T>()
+                    .map_err(|_| AssetServerError::IncorrectHandleType);
+            }
+            let loading = match self.server.direct_loading.lock().entry(asset_path_id) {
+                Entry::Occupied(entry) => entry.get().clone(),
+                Entry::Vacant(entry) => {
+                    let (sender, receiver) = async_channel::bounded(1);
+                    entry.insert(receiver);
+                    break DirectLoading {
+                        server: &self.server,
+                        asset_path_id,
+                        _sender: sender,
+                    };
+                }
+            };
+            // only returns once the other load is done, as nothing is ever sent
+            let _ = loading.recv().await;
+        };
+
+        let asset_io = self.get_source_io(asset_path.source())?;
+        self.forget_direct_dependents(|id| *id == asset_path_id.source_path_id());
+        load_stack.push(asset_path.clone());
+        let (mut load_context, has_meta) = self
+            .run_loader(&asset_path, &*asset_io, 0, load_stack)
+            .await?;
+        asset_io.watch_path_for_changes(asset_path.path())?;
+        if has_meta {
+            asset_io.watch_path_for_changes(&get_meta_path(asset_path.path()))?;
+        }
+        let label = asset_path.label().map(|label| label.to_string());
+        let asset = load_context
+            .labeled_assets
+            .remove(&label)
+            .and_then(|asset| asset.value)
+            .ok_or_else(|| AssetServerError::MissingLoadedAsset(asset_path.clone()))?;
+        let asset: Arc<T> = asset
+            .downcast::<T>()
+            .map_err(|_| AssetServerError::IncorrectHandleType)?
+            .into();
+
+        let mut direct_loads = self.server.direct_loads.lock();
+        direct_loads.retain(|_, asset| asset.strong_count() > 0);
+        let weak: Weak<T> = Arc::downgrade(&asset);
+        direct_loads.insert(asset_path_id, weak);
+        Ok(asset)
+    }
+
+    /// Forgets the values loaded directly by the loaders of the assets matching `is_dependent`,
+    /// which load them again when they run again.
+    fn forget_direct_dependents(&self, is_dependent: impl Fn(&SourcePathId) -> bool) {
+        self.server
+            .direct_dependents
+            .write()
+            .retain(|_, dependents| {
+                dependents.retain(|id, _| !is_dependent(id));
+                !dependents.is_empty()
+            });
+    }
+
+    /// Reloads the asset at `asset_path` after its file changed, along with the assets whose
+    /// loaders loaded it directly with [`LoadContext::load_direct`].
+    #[cfg_attr(not(feature = "filesystem_watcher"), allow(dead_code))]
+    pub(crate) fn reload_changed_asset(&self, asset_path: AssetPath<'static>) {
+        let mut changed = vec![asset_path];
+        let mut visited = HashSet::default();
+        while let Some(asset_path) = changed.pop() {
+            let source_path_id = asset_path.get_id().source_path_id();
+            if !visited.insert(source_path_id) {
+                continue;
+            }
+            // the values loaded directly are loaded again by the reloaded dependents
+            self.server
+                .direct_loads
+                .lock()
+                .retain(|id, _| id.source_path_id() != source_path_id);
+            if let Some(dependents) = self.server.direct_dependents.read().get(&source_path_id) {
+                changed.extend(dependents.values().cloned());
+            }
+            if self.get_load_state(asset_path.get_id()) != LoadState::NotLoaded {
+                self.load_untracked(asset_path, true);
+            }
+        }
+    }
+
     #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
     pub fn load_untyped<'a, P: Into<AssetPath<'a>>>(&self, path: P) -> HandleUntyped {
         let handle_id = self.load_untracked(path.into(), false);
@@ -380,8 +1003,9 @@ impl AssetServer {
         self.server
             .task_pool
             .spawn(async move {
-                if let Err(err) = server.load_async(owned_path, force).await {
+                if let Err(err) = server.load_async(owned_path.clone(), force).await {
                     warn!("{}", err);
+                    server.set_load_error(owned_path, err);
                 }
             })
             .detach();
@@ -396,33 +1020,72 @@ impl AssetServer {
         asset_path.into()
     }
 
+    /// Stores the error of a failed load and queues an [`AssetLoadFailedEvent`] for it.
+    fn set_load_error(&self, asset_path: AssetPath<'static>, error: AssetServerError) {
+        let asset_path_id = asset_path.get_id();
+        let error = Arc::new(error);
+        {
+            let mut asset_sources = self.server.asset_sources.write();
+            match asset_sources.get_mut(&asset_path_id.source_path_id()) {
+                // a newer load may have started since this one failed
+                Some(source_info) if source_info.load_state == LoadState::Failed => {
+                    source_info.load_error = Some(error.clone());
+                }
+                _ => return,
+            }
+        }
+        self.server.failed_loads.lock().push(AssetLoadFailedEvent {
+            path: asset_path,
+            handle: HandleUntyped::weak(asset_path_id.into()),
+            error,
+        });
+    }
+
     #[must_use = "not using the returned strong handles may result in the unexpected release of the assets"]
-    pub fn load_folder<P: AsRef<Path>>(
+    pub fn load_folder<'a, P: Into<AssetPath<'a>>>(
         &self,
         path: P,
     ) -> Result<Vec<HandleUntyped>, AssetServerError> {
-        let path = path.as_ref();
-        if !self.asset_io().is_dir(path) {
+        let path = path.into();
+        let asset_io = self.get_source_io(path.source())?;
+        let mut handles = Vec::new();
+        self.load_folder_from_source(path.source(), &*asset_io, path.path(), &mut handles)?;
+        Ok(handles)
+    }
+
+    fn load_folder_from_source(
+        &self,
+        source: Option<&str>,
+        asset_io: &dyn AssetIo,
+        path: &Path,
+        handles: &mut Vec<HandleUntyped>,
+    ) -> Result<(), AssetServerError> {
+        if !asset_io.is_dir(path) {
             return Err(AssetServerError::AssetFolderNotADirectory(
                 path.to_str().unwrap().to_string(),
             ));
         }
 
-        let mut handles = Vec::new();
-        for child_path in self.asset_io().read_directory(path.as_ref())? {
-            if self.asset_io().is_dir(&child_path) {
-                handles.extend(self.load_folder(&child_path)?);
+        for child_path in asset_io.read_directory(path)? {
+            if asset_io.is_dir(&child_path) {
+                self.load_folder_from_source(source, asset_io, &child_path, handles)?;
             } else {
-                if self.get_path_asset_loader(&child_path).is_err() {
+                if self
+                    .get_path_asset_loader(self.get_loader_path(asset_io, &child_path))
+                    .is_err()
+                {
                     continue;
                 }
-                let handle =
-                    self.load_untyped(child_path.to_str().expect("Path should be a valid string."));
+                let child_path = AssetPath::from(child_path.as_path());
+                let handle = match source {
+                    Some(source) => self.load_untyped(child_path.with_source(source)),
+                    None => self.load_untyped(child_path),
+                };
                 handles.push(handle);
             }
         }
 
-        Ok(handles)
+        Ok(())
     }
 
     pub fn free_unused_assets(&self) {
@@ -432,8 +1095,12 @@ impl AssetServer {
             let ref_counts = self.server.asset_ref_counter.ref_counts.read();
             let asset_sources = self.server.asset_sources.read();
             let asset_lifecycles = self.server.asset_lifecycles.read();
+            let mut freed_sources = HashSet::default();
             for potential_free in potential_frees.drain(..) {
                 if let Some(&0) = ref_counts.get(&potential_free) {
+                    if let HandleId::AssetPathId(id) = potential_free {
+                        freed_sources.insert(id.source_path_id());
+                    }
                     let type_uuid = match potential_free {
                         HandleId::Id(type_uuid, _) => Some(type_uuid),
                         HandleId::AssetPathId(id) => asset_sources
@@ -448,6 +1115,25 @@ impl AssetServer {
                     }
                 }
             }
+
+            // forget the settings and the direct loads of the sources whose assets are all unused
+            let has_source_state = !self.server.settings_overrides.read().is_empty()
+                || !self.server.direct_dependents.read().is_empty();
+            if has_source_state && !freed_sources.is_empty() {
+                for (handle_id, count) in ref_counts.iter() {
+                    if let HandleId::AssetPathId(id) = handle_id {
+                        if *count > 0 {
+                            freed_sources.remove(&id.source_path_id());
+                        }
+                    }
+                }
+                let mut settings_overrides = self.server.settings_overrides.write();
+                for source_path_id in &freed_sources {
+                    settings_overrides.remove(source_path_id);
+                }
+                drop(settings_overrides);
+                self.forget_direct_dependents(|id| freed_sources.contains(id));
+            }
         }
     }
 
@@ -480,14 +1166,17 @@ impl AssetServer {
 
     fn create_assets_in_load_context(&self, load_context: &mut LoadContext) {
         let asset_lifecycles = self.server.asset_lifecycles.read();
+        let base_path = load_context.asset_path();
         for (label, asset) in &mut load_context.labeled_assets {
             let asset_value = asset
                 .value
                 .take()
                 .expect("Asset should exist at this point.");
             if let Some(asset_lifecycle) = asset_lifecycles.get(&asset_value.type_uuid()) {
-                let asset_path =
-                    AssetPath::new_ref(load_context.path, label.as_ref().map(|l| l.as_str()));
+                let mut asset_path = base_path.clone();
+                if let Some(label) = label {
+                    asset_path = asset_path.with_label(label.as_str());
+                }
                 asset_lifecycle.create_asset(asset_path.into(), asset_value, load_context.version);
             } else {
                 panic!(
@@ -529,6 +1218,9 @@ impl AssetServer {
                     }
 
                     assets.set_untracked(result.id, *result.asset);
+                    if let HandleId::AssetPathId(_) = result.id {
+                        assets.loading_dependencies.insert(result.id);
+                    }
                 }
                 Ok(AssetLifecycleEvent::Free(handle_id)) => {
                     if let HandleId::AssetPathId(id) = handle_id {
@@ -547,6 +1239,19 @@ impl AssetServer {
                 Err(TryRecvError::Disconnected) => panic!("AssetChannel disconnected."),
             }
         }
+        drop(asset_sources_guard);
+
+        if !assets.loading_dependencies.is_empty() {
+            let finished: Vec<_> = assets
+                .loading_dependencies
+                .iter()
+                .map(|&id| (id, self.get_recursive_dependency_load_state(id)))
+                .filter(|(_, load_state)| *load_state != LoadState::Loading)
+                .collect();
+            for (id, load_state) in finished {
+                assets.finish_loading_dependencies(id, load_state == LoadState::Loaded);
+            }
+        }
     }
 }
 
@@ -559,14 +1264,25 @@ pub fn free_unused_assets_system(asset_server: Res<AssetServer>) {
     free_unused_assets_system_impl(&asset_server);
 }
 
+/// Sends the [`AssetLoadFailedEvent`]s of the loads that failed since the last run.
+pub fn asset_load_failed_events_system(
+    asset_server: Res<AssetServer>,
+    mut events: EventWriter<AssetLoadFailedEvent>,
+) {
+    events.send_batch(asset_server.server.failed_loads.lock().drain(..));
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{loader::LoadedAsset, update_asset_storage_system};
+    use crate::{
+        loader::LoadedAsset, update_asset_storage_system, AssetEvent, AssetLoader, Reader,
+    };
     use bevy_app::App;
     use bevy_ecs::prelude::*;
     use bevy_reflect::TypeUuid;
     use bevy_utils::BoxedFuture;
+    use std::sync::atomic::AtomicUsize;
 
     #[derive(Debug, TypeUuid)]
     #[uuid = "a5189b72-0572-4290-a2e0-96f73a491c44"]
@@ -574,9 +1290,12 @@ mod test {
 
     struct FakePngLoader;
     impl AssetLoader for FakePngLoader {
+        type Settings = ();
+
         fn load<'a>(
             &'a self,
             _: &'a [u8],
+            _settings: &'a Self::Settings,
             ctx: &'a mut LoadContext,
         ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
             ctx.set_default_asset(LoadedAsset::new(PngAsset));
@@ -590,9 +1309,12 @@ mod test {
 
     struct FailingLoader;
     impl AssetLoader for FailingLoader {
+        type Settings = ();
+
         fn load<'a>(
             &'a self,
             _: &'a [u8],
+            _settings: &'a Self::Settings,
             _: &'a mut LoadContext,
         ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
             Box::pin(async { anyhow::bail!("failed") })
@@ -605,9 +1327,12 @@ mod test {
 
     struct FakeMultipleDotLoader;
     impl AssetLoader for FakeMultipleDotLoader {
+        type Settings = ();
+
         fn load<'a>(
             &'a self,
             _: &'a [u8],
+            _settings: &'a Self::Settings,
             _: &'a mut LoadContext,
         ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
             Box::pin(async move { Ok(()) })
@@ -618,6 +1343,217 @@ mod test {
         }
     }
 
+    #[derive(Debug, TypeUuid)]
+    #[uuid = "4f0a8a63-1a4b-4f55-a3f4-3f0e3b4c8f21"]
+    struct DependentAsset;
+
+    /// Loads `.dep` files, which contain the paths of the assets they depend on, one per line.
+    struct DependentLoader;
+    impl AssetLoader for DependentLoader {
+        type Settings = ();
+
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            _settings: &'a Self::Settings,
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let dependencies = std::str::from_utf8(bytes)?
+                    .lines()
+                    .map(|path| AssetPath::from(path).to_owned())
+                    .collect();
+                ctx.set_default_asset(
+                    LoadedAsset::new(DependentAsset).with_dependencies(dependencies),
+                );
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["dep"]
+        }
+    }
+
+    #[derive(Debug, TypeUuid)]
+    #[uuid = "9d3f2c71-5a8e-4e0b-b6c4-7f1e2a3d4b5c"]
+    struct SumAsset(i64);
+
+    /// Loads `.sum` files, which contain either a number, or the paths of the `.sum` files to
+    /// add up, one per line.
+    struct SumLoader;
+    impl AssetLoader for SumLoader {
+        type Settings = ();
+
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            _settings: &'a Self::Settings,
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let text = std::str::from_utf8(bytes)?;
+                let sum = match text.trim().parse() {
+                    Ok(number) => number,
+                    Err(_) => {
+                        let mut sum = 0;
+                        for path in text.lines() {
+                            sum += ctx.load_direct::<SumAsset, _>(path).await?.0;
+                        }
+                        sum
+                    }
+                };
+                ctx.set_default_asset(LoadedAsset::new(SumAsset(sum)));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["sum"]
+        }
+    }
+
+    /// Loads `.count` files as a [`SumAsset`] of 0, counting the loads.
+    struct CountingLoader(Arc<AtomicUsize>);
+    impl AssetLoader for CountingLoader {
+        type Settings = ();
+
+        fn load<'a>(
+            &'a self,
+            _bytes: &'a [u8],
+            _settings: &'a Self::Settings,
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                self.0.fetch_add(1, Ordering::Relaxed);
+                // give the other loads a chance to run meanwhile
+                futures_lite::future::yield_now().await;
+                ctx.set_default_asset(LoadedAsset::new(SumAsset(0)));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["count"]
+        }
+    }
+
+    #[derive(Debug, TypeUuid)]
+    #[uuid = "2e7c4a19-8b3d-4f6e-9a05-c1d2e3f4a5b6"]
+    struct HeaderAsset {
+        header: [u8; 4],
+        len: u64,
+    }
+
+    /// Loads the first bytes and the length of `.bin` files, without reading the rest of them.
+    struct HeaderLoader;
+    impl ReaderAssetLoader for HeaderLoader {
+        type Settings = ();
+
+        fn load_from_reader<'a>(
+            &'a self,
+            reader: &'a mut dyn Reader,
+            _settings: &'a Self::Settings,
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                use futures_lite::{io::SeekFrom, AsyncReadExt, AsyncSeekExt};
+
+                let mut header = [0; 4];
+                reader.read_exact(&mut header).await?;
+                let len = reader.seek(SeekFrom::End(0)).await?;
+                ctx.set_default_asset(LoadedAsset::new(HeaderAsset { header, len }));
+                Ok(())
+            })
+        }
+
+        fn reader_extensions(&self) -> &[&str] {
+            &["bin"]
+        }
+    }
+
+    #[derive(Debug, TypeUuid)]
+    #[uuid = "6b5d8e0c-3c0e-4b0b-8f6a-2d9a9c1b7e52"]
+    struct TextAsset(String);
+
+    struct TextSaver;
+    impl AssetSaver for TextSaver {
+        type Asset = TextAsset;
+
+        fn save(&self, asset: &TextAsset) -> Result<Vec<u8>, anyhow::Error> {
+            Ok(asset.0.as_bytes().to_vec())
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+    }
+
+    struct TextLoader;
+    impl AssetLoader for TextLoader {
+        type Settings = ();
+
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            _settings: &'a Self::Settings,
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let text = String::from_utf8(bytes.to_vec())?;
+                ctx.set_default_asset(LoadedAsset::new(TextAsset(text)));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+    }
+
+    #[derive(Debug, TypeUuid)]
+    #[uuid = "0a1c4bf7-7e2d-4d3c-9d8c-0e3f6b0f3b4e"]
+    struct SettingsAsset;
+
+    #[derive(Default, Reflect, serde::Serialize, serde::Deserialize)]
+    struct TestSettings {
+        value: u32,
+    }
+
+    #[derive(Default)]
+    struct SettingsLoader {
+        values: Arc<Mutex<Vec<u32>>>,
+    }
+    impl AssetLoader for SettingsLoader {
+        type Settings = TestSettings;
+
+        fn load<'a>(
+            &'a self,
+            _: &'a [u8],
+            settings: &'a Self::Settings,
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            self.values.lock().push(settings.value);
+            ctx.set_default_asset(LoadedAsset::new(SettingsAsset));
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["settings"]
+        }
+    }
+
+    fn setup_settings_loader(dir: &tempfile::TempDir) -> (AssetServer, Arc<Mutex<Vec<u32>>>) {
+        let asset_server = setup(dir.path());
+        let loader = SettingsLoader::default();
+        let values = loader.values.clone();
+        asset_server.add_loader(loader);
+        asset_server.add_loader(FakePngLoader);
+        asset_server.register_asset_type::<SettingsAsset>();
+        asset_server.register_asset_type::<PngAsset>();
+        (asset_server, values)
+    }
+
     fn setup(asset_path: impl AsRef<Path>) -> AssetServer {
         use crate::FileAssetIo;
 
@@ -630,7 +1566,7 @@ mod test {
         asset_server.add_loader(FakePngLoader);
 
         let t = asset_server.get_path_asset_loader("test.png");
-        assert_eq!(t.unwrap().extensions()[0], "png");
+        assert_eq!(t.unwrap().erased_extensions()[0], "png");
     }
 
     #[test]
@@ -639,7 +1575,7 @@ mod test {
         asset_server.add_loader(FakePngLoader);
 
         let t = asset_server.get_path_asset_loader("test.PNG");
-        assert_eq!(t.unwrap().extensions()[0], "png");
+        assert_eq!(t.unwrap().erased_extensions()[0], "png");
     }
 
     #[test]
@@ -690,7 +1626,7 @@ mod test {
         asset_server.add_loader(FakePngLoader);
 
         let t = asset_server.get_path_asset_loader("test-v1.2.3.png");
-        assert_eq!(t.unwrap().extensions()[0], "png");
+        assert_eq!(t.unwrap().erased_extensions()[0], "png");
     }
 
     #[test]
@@ -699,7 +1635,7 @@ mod test {
         asset_server.add_loader(FakeMultipleDotLoader);
 
         let t = asset_server.get_path_asset_loader("test.test.png");
-        assert_eq!(t.unwrap().extensions()[0], "test.png");
+        assert_eq!(t.unwrap().erased_extensions()[0], "test.png");
     }
 
     fn create_dir_and_file(file: impl AsRef<Path>) -> tempfile::TempDir {
@@ -759,6 +1695,606 @@ mod test {
         assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
     }
 
+    #[test]
+    fn test_meta_file_settings() {
+        let dir = create_dir_and_file("a.settings");
+        std::fs::write(dir.path().join("b.settings"), b"").unwrap();
+        std::fs::write(dir.path().join("a.settings.meta"), "(settings: (value: 3))").unwrap();
+        let (asset_server, values) = setup_settings_loader(&dir);
+
+        futures_lite::future::block_on(asset_server.load_async("a.settings".into(), true)).unwrap();
+        futures_lite::future::block_on(asset_server.load_async("b.settings".into(), true)).unwrap();
+        assert_eq!(*values.lock(), vec![3, 0]);
+    }
+
+    #[test]
+    fn test_meta_file_loader() {
+        let dir = create_dir_and_file("image.png");
+        std::fs::write(
+            dir.path().join("image.png.meta"),
+            format!(
+                "(loader: Some({:?}))",
+                std::any::type_name::<SettingsLoader>()
+            ),
+        )
+        .unwrap();
+        let (asset_server, values) = setup_settings_loader(&dir);
+
+        futures_lite::future::block_on(asset_server.load_async("image.png".into(), true)).unwrap();
+        assert_eq!(*values.lock(), vec![0]);
+
+        std::fs::write(
+            dir.path().join("image.png.meta"),
+            "(loader: Some(\"NotALoader\"))",
+        )
+        .unwrap();
+        let err = futures_lite::future::block_on(asset_server.load_async("image.png".into(), true))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AssetServerError::MissingAssetLoaderForTypeName(name) if name == "NotALoader"
+        ));
+    }
+
+    #[test]
+    fn test_invalid_meta_file() {
+        let dir = create_dir_and_file("a.settings");
+        std::fs::write(
+            dir.path().join("a.settings.meta"),
+            "(settings: (value: \"3\"))",
+        )
+        .unwrap();
+        let (asset_server, _) = setup_settings_loader(&dir);
+
+        let path: AssetPath = "a.settings".into();
+        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
+            .unwrap_err();
+        assert!(matches!(err, AssetServerError::InvalidMetaFile { .. }));
+        assert_eq!(
+            asset_server.get_load_state(path.get_id()),
+            LoadState::Failed
+        );
+    }
+
+    #[test]
+    fn test_load_with_settings() {
+        let dir = create_dir_and_file("a.settings");
+        std::fs::write(dir.path().join("a.settings.meta"), "(settings: (value: 3))").unwrap();
+        let (asset_server, values) = setup_settings_loader(&dir);
+
+        let _handle: Handle<SettingsAsset> =
+            asset_server.load_with_settings("a.settings", |settings: &mut TestSettings| {
+                settings.value *= 2;
+            });
+        // the settings are also changed when reloading the asset
+        futures_lite::future::block_on(asset_server.load_async("a.settings".into(), true)).unwrap();
+        let values = values.lock();
+        assert!(!values.is_empty());
+        assert!(values.iter().all(|value| *value == 6));
+    }
+
+    #[test]
+    fn test_load_with_settings_reloads_loaded_asset() {
+        let dir = create_dir_and_file("a.settings");
+        std::fs::write(dir.path().join("a.settings.meta"), "(settings: (value: 3))").unwrap();
+        let (asset_server, values) = setup_settings_loader(&dir);
+
+        futures_lite::future::block_on(asset_server.load_async("a.settings".into(), false))
+            .unwrap();
+        assert_eq!(*values.lock(), vec![3]);
+
+        let handle: Handle<SettingsAsset> =
+            asset_server.load_with_settings("a.settings", |settings: &mut TestSettings| {
+                settings.value *= 2;
+            });
+        // the reload runs on the task pool
+        for _ in 0..200 {
+            if values.lock().len() > 1 {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert_eq!(*values.lock(), vec![3, 6]);
+
+        // the settings are forgotten once the asset is unused
+        let source_path_id = AssetPath::from("a.settings").get_id().source_path_id();
+        assert!(asset_server
+            .server
+            .settings_overrides
+            .read()
+            .contains_key(&source_path_id));
+        drop(handle);
+        asset_server.mark_unused_assets();
+        asset_server.free_unused_assets();
+        assert!(!asset_server
+            .server
+            .settings_overrides
+            .read()
+            .contains_key(&source_path_id));
+    }
+
+    #[test]
+    fn test_named_asset_source() {
+        use crate::FileAssetIo;
+
+        let dir = create_dir_and_file("a.settings");
+        let mods_dir = create_dir_and_file("b.settings");
+        std::fs::write(
+            mods_dir.path().join("b.settings.meta"),
+            "(settings: (value: 5))",
+        )
+        .unwrap();
+        let (asset_server, values) = setup_settings_loader(&dir);
+        asset_server.add_source("mods", FileAssetIo::new(mods_dir.path(), false));
+
+        let path: AssetPath = "mods://b.settings".into();
+        futures_lite::future::block_on(asset_server.load_async(path.clone(), true)).unwrap();
+        assert_eq!(*values.lock(), vec![5]);
+        assert_ne!(path.get_id(), AssetPath::from("b.settings").get_id());
+
+        // the default source does not contain the asset
+        let err =
+            futures_lite::future::block_on(asset_server.load_async("b.settings".into(), true))
+                .unwrap_err();
+        assert!(matches!(err, AssetServerError::AssetIoError(_)));
+
+        let handles = asset_server.load_folder("mods://").unwrap();
+        assert_eq!(handles.len(), 1);
+        assert_eq!(handles[0].id, HandleId::from(path.get_id()));
+    }
+
+    #[test]
+    fn test_missing_asset_source() {
+        let dir = create_dir_and_file("a.settings");
+        let (asset_server, _) = setup_settings_loader(&dir);
+
+        let path: AssetPath = "mods://a.settings".into();
+        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
+            .unwrap_err();
+        assert!(matches!(err, AssetServerError::MissingAssetSource(name) if name == "mods"));
+        assert_eq!(
+            asset_server.get_load_state(path.get_id()),
+            LoadState::Failed
+        );
+    }
+
+    #[test]
+    fn test_load_failed_event() {
+        let asset_server = setup(".");
+        asset_server.add_loader(FakePngLoader);
+
+        let handle = asset_server.load_untyped("an/invalid/path.png");
+        // wait for the load task to fail
+        for _ in 0..200 {
+            if asset_server.get_load_error(&handle).is_some() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        let error = asset_server.get_load_error(&handle).unwrap();
+        assert!(matches!(*error, AssetServerError::AssetIoError(_)));
+        assert_eq!(asset_server.get_load_state(&handle), LoadState::Failed);
+
+        let mut app = App::new();
+        app.insert_resource(asset_server)
+            .add_event::<AssetLoadFailedEvent>()
+            .add_system(asset_load_failed_events_system);
+        app.update();
+
+        let events = app
+            .world
+            .resource::<bevy_ecs::event::Events<AssetLoadFailedEvent>>();
+        let mut reader = events.get_reader();
+        let events: Vec<_> = reader.iter(events).collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].handle.id, handle.id);
+        assert_eq!(events[0].path.path(), Path::new("an/invalid/path.png"));
+        assert!(Arc::ptr_eq(&events[0].error, &error));
+    }
+
+    fn load_with_dependencies(
+        source: Option<&str>,
+        files: &[(&str, &str)],
+    ) -> (App, HandleUntyped) {
+        let dir = tempfile::tempdir().unwrap();
+        for (path, content) in files {
+            std::fs::write(dir.path().join(path), content).unwrap();
+        }
+        // the files are only in the named source, if there is one
+        let default_dir = tempfile::tempdir().unwrap();
+        let asset_server = match source {
+            Some(source) => {
+                let asset_server = setup(default_dir.path());
+                asset_server.add_source(source, crate::FileAssetIo::new(dir.path(), false));
+                asset_server
+            }
+            None => setup(dir.path()),
+        };
+        asset_server.add_loader(DependentLoader);
+        let assets = asset_server.register_asset_type::<DependentAsset>();
+
+        let mut app = App::new();
+        app.insert_resource(assets)
+            .insert_resource(asset_server.clone())
+            .add_event::<AssetEvent<DependentAsset>>()
+            .init_resource::<Vec<HandleId>>()
+            .add_system(update_asset_storage_system::<DependentAsset>)
+            .add_system(Assets::<DependentAsset>::asset_event_system)
+            .add_system_to_stage(
+                bevy_app::CoreStage::PostUpdate,
+                |mut events: EventReader<AssetEvent<DependentAsset>>,
+                 mut loaded: ResMut<Vec<HandleId>>| {
+                    for event in events.iter() {
+                        if let AssetEvent::LoadedWithDependencies { handle } = event {
+                            loaded.push(handle.id);
+                        }
+                    }
+                },
+            );
+
+        let path = AssetPath::from(files[0].0);
+        let path = match source {
+            Some(source) => path.with_source(source),
+            None => path,
+        };
+        futures_lite::future::block_on(asset_server.load_async(path.clone(), true)).unwrap();
+        let handle = asset_server.get_handle_untyped(path.get_id());
+        // wait for the dependencies, which are loaded on the task pool
+        for _ in 0..200 {
+            app.update();
+            if asset_server.get_recursive_dependency_load_state(&handle) != LoadState::Loading {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        // send the events of the last update
+        app.update();
+        // keep the directory until the assets are loaded
+        drop(dir);
+        (app, handle)
+    }
+
+    #[test]
+    fn test_recursive_dependency_load_state() {
+        let (app, handle) = load_with_dependencies(
+            None,
+            &[("a.dep", "b.dep\nc.dep"), ("b.dep", "c.dep"), ("c.dep", "")],
+        );
+        let asset_server = app.world.resource::<AssetServer>();
+        assert_eq!(
+            asset_server.get_recursive_dependency_load_state(&handle),
+            LoadState::Loaded
+        );
+        assert_eq!(
+            asset_server.get_recursive_dependency_load_state("c.dep"),
+            LoadState::Loaded
+        );
+        let loaded = app.world.resource::<Vec<HandleId>>();
+        assert_eq!(loaded.len(), 3);
+        assert!(loaded.contains(&handle.id));
+    }
+
+    #[test]
+    fn test_dependencies_inherit_source() {
+        let (app, handle) = load_with_dependencies(
+            Some("mods"),
+            &[("a.dep", "b.dep"), ("b.dep", "c.dep"), ("c.dep", "")],
+        );
+        let asset_server = app.world.resource::<AssetServer>();
+        assert_eq!(
+            asset_server.get_recursive_dependency_load_state(&handle),
+            LoadState::Loaded
+        );
+        assert_eq!(
+            asset_server.get_load_state(AssetPath::from("c.dep").with_source("mods").get_id()),
+            LoadState::Loaded
+        );
+        assert_eq!(
+            asset_server.get_load_state(AssetPath::from("c.dep").get_id()),
+            LoadState::NotLoaded
+        );
+    }
+
+    #[test]
+    fn test_failed_dependency_load_state() {
+        let (app, handle) =
+            load_with_dependencies(None, &[("a.dep", "b.dep"), ("b.dep", "missing.dep")]);
+        let asset_server = app.world.resource::<AssetServer>();
+        assert_eq!(asset_server.get_load_state(&handle), LoadState::Loaded);
+        assert_eq!(
+            asset_server.get_recursive_dependency_load_state(&handle),
+            LoadState::Failed
+        );
+    }
+
+    #[test]
+    fn test_save_asset() {
+        let dir = tempfile::tempdir().unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.add_saver(TextSaver);
+        let mut assets = asset_server.register_asset_type::<TextAsset>();
+        let handle = assets.add(TextAsset("saved".to_string()));
+
+        asset_server.save(&assets, &handle, "texts/a.txt").unwrap();
+        // the file is not watched, so its change is not tracked
+        assert!(asset_server.server.saving_assets.lock().is_empty());
+        // wait for the write, which happens on the task pool
+        let path = dir.path().join("texts/a.txt");
+        for _ in 0..200 {
+            if std::fs::read(&path).map_or(false, |bytes| bytes == b"saved") {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "saved");
+        let writer = asset_server.asset_io().writer().unwrap();
+        futures_lite::future::block_on(writer.remove_path(Path::new("texts/a.txt"))).unwrap();
+        assert!(!path.exists());
+
+        assert!(matches!(
+            asset_server.save(&assets, &handle, "texts/a.bin"),
+            Err(AssetServerError::MissingAssetSaver(_))
+        ));
+        assert!(matches!(
+            asset_server.save(
+                &assets,
+                &Handle::weak(HandleId::random::<TextAsset>()),
+                "b.txt"
+            ),
+            Err(AssetServerError::MissingAsset(_))
+        ));
+        asset_server.add_source("processed", crate::ProcessedAssetIo::new(dir.path()));
+        assert!(matches!(
+            asset_server.save(&assets, &handle, "processed://b.txt"),
+            Err(AssetServerError::AssetSourceNotWritable(_))
+        ));
+    }
+
+    #[test]
+    fn test_save_echo() {
+        let dir = create_dir_and_file("a.txt");
+        let asset_server = setup(dir.path());
+        asset_server
+            .server
+            .watching_for_changes
+            .store(true, Ordering::Relaxed);
+        asset_server.add_loader(TextLoader);
+        asset_server.add_saver(TextSaver);
+        let mut assets = asset_server.register_asset_type::<TextAsset>();
+        let path: AssetPath = "a.txt".into();
+        futures_lite::future::block_on(asset_server.load_async(path.clone(), false)).unwrap();
+
+        let save = |assets: &mut Assets<TextAsset>, text: &str| {
+            let handle = assets.add(TextAsset(text.to_string()));
+            asset_server.save(assets, &handle, path.clone()).unwrap();
+            // wait for the write, which happens on the task pool
+            let source_path_id = path.get_id().source_path_id();
+            for _ in 0..200 {
+                let saving_assets = asset_server.server.saving_assets.lock();
+                if saving_assets
+                    .get(&source_path_id)
+                    .map_or(false, |saved| saved.written)
+                {
+                    break;
+                }
+                drop(saving_assets);
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+        };
+
+        // only the first change after the save is skipped
+        save(&mut assets, "saved");
+        let is_save_echo = || futures_lite::future::block_on(asset_server.is_save_echo(&path));
+        assert!(is_save_echo());
+        assert!(!is_save_echo());
+
+        // a change made after the save is not skipped
+        save(&mut assets, "saved again");
+        std::fs::write(dir.path().join("a.txt"), "edited").unwrap();
+        assert!(!is_save_echo());
+        assert!(asset_server.server.saving_assets.lock().is_empty());
+    }
+
+    fn setup_sum_loader(files: &[(&str, &str)]) -> (tempfile::TempDir, App) {
+        let dir = tempfile::tempdir().unwrap();
+        for (path, content) in files {
+            std::fs::write(dir.path().join(path), content).unwrap();
+        }
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(SumLoader);
+        let assets = asset_server.register_asset_type::<SumAsset>();
+        let mut app = App::new();
+        app.insert_resource(assets)
+            .insert_resource(asset_server)
+            .add_system(update_asset_storage_system::<SumAsset>);
+        (dir, app)
+    }
+
+    #[test]
+    fn test_load_direct() {
+        let (_dir, mut app) = setup_sum_loader(&[
+            ("a.sum", "b.sum\nc.sum"),
+            ("b.sum", "c.sum\nc.sum"),
+            ("c.sum", "2"),
+        ]);
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        let path: AssetPath = "a.sum".into();
+        futures_lite::future::block_on(asset_server.load_async(path.clone(), true)).unwrap();
+        app.update();
+        let assets = app.world.resource::<Assets<SumAsset>>();
+        assert_eq!(assets.get(path.get_id()).unwrap().0, 6);
+        // the values loaded directly are not added to the assets
+        assert!(assets.get(AssetPath::from("c.sum").get_id()).is_none());
+
+        // a value is reused while it is alive
+        let load_direct = || {
+            futures_lite::future::block_on(
+                asset_server.load_direct::<SumAsset>("c.sum".into(), Vec::new()),
+            )
+            .unwrap()
+        };
+        let c = load_direct();
+        assert_eq!(c.0, 2);
+        assert!(Arc::ptr_eq(&c, &load_direct()));
+        assert!(matches!(
+            futures_lite::future::block_on(
+                asset_server.load_direct::<TextAsset>("c.sum".into(), Vec::new())
+            ),
+            Err(AssetServerError::IncorrectHandleType)
+        ));
+    }
+
+    #[test]
+    fn test_load_direct_reloads_dependents() {
+        let (dir, mut app) =
+            setup_sum_loader(&[("a.sum", "b.sum"), ("b.sum", "c.sum"), ("c.sum", "2")]);
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        let path: AssetPath = "a.sum".into();
+        futures_lite::future::block_on(asset_server.load_async(path.clone(), true)).unwrap();
+        app.update();
+        let get_sum = |app: &App| {
+            app.world
+                .resource::<Assets<SumAsset>>()
+                .get(path.get_id())
+                .unwrap()
+                .0
+        };
+        assert_eq!(get_sum(&app), 2);
+
+        std::fs::write(dir.path().join("c.sum"), "5").unwrap();
+        asset_server.reload_changed_asset("c.sum".into());
+        // the dependents are reloaded on the task pool
+        for _ in 0..200 {
+            app.update();
+            if get_sum(&app) == 5 {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert_eq!(get_sum(&app), 5);
+        // the values loaded directly are not loaded as assets
+        assert_eq!(
+            asset_server.get_load_state(AssetPath::from("c.sum").get_id()),
+            LoadState::NotLoaded
+        );
+    }
+
+    #[test]
+    fn test_load_direct_once_at_a_time() {
+        let (_dir, app) = setup_sum_loader(&[("a.count", "")]);
+        let asset_server = app.world.resource::<AssetServer>();
+        let loads = Arc::new(AtomicUsize::new(0));
+        asset_server.add_loader(CountingLoader(loads.clone()));
+
+        let load_direct = || asset_server.load_direct::<SumAsset>("a.count".into(), Vec::new());
+        let (a, b) =
+            futures_lite::future::block_on(futures_lite::future::zip(load_direct(), load_direct()));
+        assert!(Arc::ptr_eq(&a.unwrap(), &b.unwrap()));
+        assert_eq!(loads.load(Ordering::Relaxed), 1);
+        assert!(asset_server.server.direct_loading.lock().is_empty());
+    }
+
+    #[test]
+    fn test_forget_direct_dependents() {
+        let (dir, app) =
+            setup_sum_loader(&[("a.sum", "b.sum"), ("b.sum", "c.sum"), ("c.sum", "2")]);
+        let asset_server = app.world.resource::<AssetServer>();
+        let a = AssetPath::from("a.sum").get_id().source_path_id();
+        let b = AssetPath::from("b.sum").get_id().source_path_id();
+        let c = AssetPath::from("c.sum").get_id().source_path_id();
+        let dependents = |id| {
+            asset_server
+                .server
+                .direct_dependents
+                .read()
+                .get(&id)
+                .map(|dependents| dependents.keys().copied().collect::<HashSet<_>>())
+        };
+        let path: AssetPath = "a.sum".into();
+        let handle = asset_server.get_handle::<SumAsset, _>(path.get_id());
+        futures_lite::future::block_on(asset_server.load_async(path.clone(), true)).unwrap();
+        assert_eq!(dependents(b), Some(HashSet::from_iter([a])));
+        assert_eq!(dependents(c), Some(HashSet::from_iter([b])));
+
+        // a reloaded asset only depends on what it loads this time
+        std::fs::write(dir.path().join("a.sum"), "c.sum").unwrap();
+        futures_lite::future::block_on(asset_server.load_async(path, true)).unwrap();
+        assert_eq!(dependents(b), None);
+        assert_eq!(dependents(c), Some(HashSet::from_iter([a, b])));
+
+        // a freed asset depends on nothing
+        drop(handle);
+        asset_server.mark_unused_assets();
+        asset_server.free_unused_assets();
+        assert_eq!(dependents(c), Some(HashSet::from_iter([b])));
+    }
+
+    #[test]
+    fn test_load_direct_from_source() {
+        let (_dir, mut app) = setup_sum_loader(&[]);
+        let mods_dir = tempfile::tempdir().unwrap();
+        std::fs::write(mods_dir.path().join("a.sum"), "b.sum").unwrap();
+        std::fs::write(mods_dir.path().join("b.sum"), "3").unwrap();
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        asset_server.add_source("mods", crate::FileAssetIo::new(mods_dir.path(), false));
+
+        // `b.sum` is only in the source of `a.sum`
+        let path = AssetPath::from("a.sum").with_source("mods");
+        futures_lite::future::block_on(asset_server.load_async(path.clone(), true)).unwrap();
+        app.update();
+        let assets = app.world.resource::<Assets<SumAsset>>();
+        assert_eq!(assets.get(path.get_id()).unwrap().0, 3);
+    }
+
+    #[test]
+    fn test_cyclic_load_direct() {
+        let (_dir, app) =
+            setup_sum_loader(&[("a.sum", "b.sum"), ("b.sum", "c.sum"), ("c.sum", "a.sum")]);
+        let asset_server = app.world.resource::<AssetServer>();
+        let mut err = futures_lite::future::block_on(asset_server.load_async("a.sum".into(), true))
+            .unwrap_err();
+        // the error is wrapped by the loader of each asset of the cycle
+        while let AssetServerError::AssetLoaderError(loader_err) = err {
+            err = loader_err.downcast().unwrap();
+        }
+        assert!(matches!(
+            err,
+            AssetServerError::CyclicLoad(path) if path.path() == Path::new("a.sum")
+        ));
+    }
+
+    #[test]
+    fn test_reader_loader() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut bytes = b"HEAD".to_vec();
+        bytes.resize(1 << 16, 0);
+        std::fs::write(dir.path().join("a.bin"), &bytes).unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(HeaderLoader);
+        let assets = asset_server.register_asset_type::<HeaderAsset>();
+        let mut app = App::new();
+        app.insert_resource(assets)
+            .insert_resource(asset_server.clone())
+            .add_system(update_asset_storage_system::<HeaderAsset>);
+
+        let path: AssetPath = "a.bin".into();
+        futures_lite::future::block_on(asset_server.load_async(path.clone(), true)).unwrap();
+        app.update();
+        let asset = app
+            .world
+            .resource::<Assets<HeaderAsset>>()
+            .get(path.get_id())
+            .unwrap();
+        assert_eq!(&asset.header, b"HEAD");
+        assert_eq!(asset.len, 1 << 16);
+
+        // the methods of the loader traits can be called on a loader implementing all of them
+        assert_eq!(SumLoader.extensions(), ["sum"]);
+        assert_eq!(SumLoader.reader_extensions(), ["sum"]);
+        assert_eq!(SumLoader.erased_extensions(), ["sum"]);
+        assert_eq!(HeaderLoader.reader_extensions(), ["bin"]);
+    }
+
     #[test]
     fn test_asset_lifecycle() {
         let dir = create_dir_and_file("fake.png");
@@ -1,6 +1,6 @@
 use crate::{
-    update_asset_storage_system, Asset, AssetLoader, AssetServer, AssetStage, Handle, HandleId,
-    RefChange,
+    update_asset_storage_system, Asset, AssetIo, AssetSaver, AssetServer, AssetStage, Handle,
+    HandleId, ReaderAssetLoader, RefChange,
 };
 use bevy_app::App;
 use bevy_ecs::{
@@ -8,7 +8,7 @@ use bevy_ecs::{
     system::ResMut,
     world::FromWorld,
 };
-use bevy_utils::HashMap;
+use bevy_utils::{HashMap, HashSet};
 use crossbeam_channel::Sender;
 use std::fmt::Debug;
 
@@ -16,9 +16,20 @@ use std::fmt::Debug;
 ///
 /// Events sent via the [Assets] struct will always be sent with a _Weak_ handle
 pub enum AssetEvent<T: Asset> {
-    Created { handle: Handle<T> },
-    Modified { handle: Handle<T> },
-    Removed { handle: Handle<T> },
+    Created {
+        handle: Handle<T>,
+    },
+    Modified {
+        handle: Handle<T>,
+    },
+    Removed {
+        handle: Handle<T>,
+    },
+    /// Sent when an asset loaded by the [`AssetServer`] and all of its dependencies, recursively,
+    /// are loaded. See [`AssetServer::get_recursive_dependency_load_state`].
+    LoadedWithDependencies {
+        handle: Handle<T>,
+    },
 }
 
 impl<T: Asset> Debug for AssetEvent<T> {
@@ -45,6 +56,13 @@ impl<T: Asset> Debug for AssetEvent<T> {
                 ))
                 .field("handle", &handle.id)
                 .finish(),
+            AssetEvent::LoadedWithDependencies { handle } => f
+                .debug_struct(&format!(
+                    "AssetEvent<{}>::LoadedWithDependencies",
+                    std::any::type_name::<T>()
+                ))
+                .field("handle", &handle.id)
+                .finish(),
         }
     }
 }
@@ -66,6 +84,8 @@ impl<T: Asset> Debug for AssetEvent<T> {
 pub struct Assets<T: Asset> {
     assets: HashMap<HandleId, T>,
     events: Events<AssetEvent<T>>,
+    /// The loaded assets whose dependencies are still loading.
+    pub(crate) loading_dependencies: HashSet<HandleId>,
     pub(crate) ref_change_sender: Sender<RefChange>,
 }
 
@@ -74,6 +94,7 @@ impl<T: Asset> Assets<T> {
         Assets {
             assets: HashMap::default(),
             events: Events::default(),
+            loading_dependencies: HashSet::default(),
             ref_change_sender,
         }
     }
@@ -208,6 +229,7 @@ impl<T: Asset> Assets<T> {
     pub fn remove<H: Into<HandleId>>(&mut self, handle: H) -> Option<T> {
         let id: HandleId = handle.into();
         let asset = self.assets.remove(&id);
+        self.loading_dependencies.remove(&id);
         if asset.is_some() {
             self.events.send(AssetEvent::Removed {
                 handle: Handle::weak(id),
@@ -216,6 +238,18 @@ impl<T: Asset> Assets<T> {
         asset
     }
 
+    /// Stops waiting for the dependencies of the asset to load.
+    ///
+    /// # Events
+    /// * [`AssetEvent::LoadedWithDependencies`]: Sent if the dependencies are `loaded`
+    pub(crate) fn finish_loading_dependencies(&mut self, id: HandleId, loaded: bool) {
+        if self.loading_dependencies.remove(&id) && loaded {
+            self.events.send(AssetEvent::LoadedWithDependencies {
+                handle: Handle::weak(id),
+            });
+        }
+    }
+
     /// Clears the inner asset map, removing all key-value pairs.
     ///
     /// Keeps the allocated memory for reuse.
@@ -270,13 +304,26 @@ pub trait AddAsset {
         T: Asset;
     fn init_asset_loader<T>(&mut self) -> &mut Self
     where
-        T: AssetLoader + FromWorld;
+        T: ReaderAssetLoader + FromWorld;
     fn init_debug_asset_loader<T>(&mut self) -> &mut Self
     where
-        T: AssetLoader + FromWorld;
+        T: ReaderAssetLoader + FromWorld;
     fn add_asset_loader<T>(&mut self, loader: T) -> &mut Self
     where
-        T: AssetLoader;
+        T: ReaderAssetLoader;
+    fn add_asset_saver<T>(&mut self, saver: T) -> &mut Self
+    where
+        T: AssetSaver;
+    fn add_asset_source<T>(&mut self, name: impl Into<String>, asset_io: T) -> &mut Self
+    where
+        T: AssetIo;
+    #[cfg(all(
+        feature = "asset_processor",
+        all(not(target_arch = "wasm32"), not(target_os = "android"))
+    ))]
+    fn add_asset_processor<T>(&mut self, processor: T) -> &mut Self
+    where
+        T: crate::AssetProcessor;
 }
 
 impl AddAsset for App {
@@ -320,7 +367,7 @@ impl AddAsset for App {
 
     fn init_asset_loader<T>(&mut self) -> &mut Self
     where
-        T: AssetLoader + FromWorld,
+        T: ReaderAssetLoader + FromWorld,
     {
         let result = T::from_world(&mut self.world);
         self.add_asset_loader(result)
@@ -328,7 +375,7 @@ impl AddAsset for App {
 
     fn init_debug_asset_loader<T>(&mut self) -> &mut Self
     where
-        T: AssetLoader + FromWorld,
+        T: ReaderAssetLoader + FromWorld,
     {
         #[cfg(feature = "debug_asset_server")]
         {
@@ -342,11 +389,51 @@ impl AddAsset for App {
 
     fn add_asset_loader<T>(&mut self, loader: T) -> &mut Self
     where
-        T: AssetLoader,
+        T: ReaderAssetLoader,
     {
         self.world.resource_mut::<AssetServer>().add_loader(loader);
         self
     }
+
+    /// Adds an [`AssetSaver`], used by [`AssetServer::save`] to write the assets of its type to
+    /// files with its extensions.
+    fn add_asset_saver<T>(&mut self, saver: T) -> &mut Self
+    where
+        T: AssetSaver,
+    {
+        self.world.resource::<AssetServer>().add_saver(saver);
+        self
+    }
+
+    /// Adds a named asset source, whose assets are loaded with paths such as
+    /// `"name://path/to/asset.png"`.
+    fn add_asset_source<T>(&mut self, name: impl Into<String>, asset_io: T) -> &mut Self
+    where
+        T: AssetIo,
+    {
+        self.world
+            .resource::<AssetServer>()
+            .add_source(name, asset_io);
+        self
+    }
+
+    /// Adds an [`AssetProcessor`](crate::AssetProcessor) to the
+    /// [`AssetProcessorServer`](crate::AssetProcessorServer).
+    ///
+    /// Does nothing unless the [`AssetMode`](crate::AssetMode) is `Processor`.
+    #[cfg(all(
+        feature = "asset_processor",
+        all(not(target_arch = "wasm32"), not(target_os = "android"))
+    ))]
+    fn add_asset_processor<T>(&mut self, processor: T) -> &mut Self
+    where
+        T: crate::AssetProcessor,
+    {
+        if let Some(asset_processor) = self.world.get_resource::<crate::AssetProcessorServer>() {
+            asset_processor.add_processor(processor);
+        }
+        self
+    }
 }
 
 #[cfg(feature = "debug_asset_server")]
@@ -0,0 +1,398 @@
+#[cfg(all(
+    feature = "filesystem_watcher",
+    all(not(target_arch = "wasm32"), not(target_os = "android"))
+))]
+use crate::filesystem_watcher::FilesystemWatcher;
+use crate::{AssetIo, AssetIoError, FileType, Metadata, Reader};
+use anyhow::Result;
+use bevy_utils::BoxedFuture;
+#[cfg(all(
+    feature = "filesystem_watcher",
+    all(not(target_arch = "wasm32"), not(target_os = "android"))
+))]
+use bevy_utils::HashSet;
+#[cfg(all(
+    feature = "filesystem_watcher",
+    all(not(target_arch = "wasm32"), not(target_os = "android"))
+))]
+use crossbeam_channel::TryRecvError;
+use futures_lite::io::Cursor;
+use parking_lot::RwLock;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+/// The name of the asset source of the assets embedded with
+/// [`embedded_asset!`](crate::embedded_asset), such as `"embedded://bevy_pbr/render/icon.png"`.
+pub const EMBEDDED_ASSET_SOURCE: &str = "embedded";
+
+struct EmbeddedAsset {
+    bytes: &'static [u8],
+    /// The file the bytes were included from, which is loaded instead when it exists.
+    source_path: Option<PathBuf>,
+}
+
+/// Loads the assets compiled into the binary with [`embedded_asset!`](crate::embedded_asset).
+///
+/// Assets with a source path are loaded from that file when it can be read, and reloaded when
+/// it changes while watching for changes.
+#[derive(Default)]
+pub struct EmbeddedAssetIo {
+    assets: RwLock<BTreeMap<PathBuf, EmbeddedAsset>>,
+    #[cfg(all(
+        feature = "filesystem_watcher",
+        all(not(target_arch = "wasm32"), not(target_os = "android"))
+    ))]
+    filesystem_watcher: RwLock<Option<FilesystemWatcher>>,
+}
+
+impl EmbeddedAssetIo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the embedded asset at `path`, whose `bytes` were included from `source_path`.
+    pub fn insert_asset(
+        &self,
+        path: impl Into<PathBuf>,
+        bytes: &'static [u8],
+        source_path: Option<PathBuf>,
+    ) {
+        let path = path.into();
+        #[cfg(all(
+            feature = "filesystem_watcher",
+            all(not(target_arch = "wasm32"), not(target_os = "android"))
+        ))]
+        if let Some(source_path) = &source_path {
+            self.watch_source_path(source_path);
+        }
+        self.assets
+            .write()
+            .insert(path, EmbeddedAsset { bytes, source_path });
+    }
+
+    #[cfg(all(
+        feature = "filesystem_watcher",
+        all(not(target_arch = "wasm32"), not(target_os = "android"))
+    ))]
+    fn watch_source_path(&self, source_path: &Path) {
+        // the folder is watched so that a file replaced by a rename is still watched
+        let folder = source_path.parent().unwrap_or(source_path);
+        if let Some(watcher) = self.filesystem_watcher.write().as_mut() {
+            if let Err(err) = watcher.watch(folder) {
+                bevy_log::warn!("failed to watch {:?} for changes: {}", source_path, err);
+            }
+        }
+    }
+
+    /// Returns the embedded bytes of the asset at `path`, and the file they were included from.
+    fn get_asset(&self, path: &Path) -> Result<(&'static [u8], Option<PathBuf>), AssetIoError> {
+        self.assets
+            .read()
+            .get(path)
+            .map(|asset| (asset.bytes, asset.source_path.clone()))
+            .ok_or_else(|| AssetIoError::NotFound(path.to_owned()))
+    }
+
+    /// Returns the paths of the embedded assets whose source file was created, modified or
+    /// removed since the last call.
+    #[cfg(all(
+        feature = "filesystem_watcher",
+        all(not(target_arch = "wasm32"), not(target_os = "android"))
+    ))]
+    pub(crate) fn take_modified_paths(&self) -> HashSet<PathBuf> {
+        let mut changed = HashSet::default();
+        let watcher = self.filesystem_watcher.read();
+        if let Some(ref watcher) = *watcher {
+            loop {
+                let event = match watcher.receiver.try_recv() {
+                    Ok(result) => result.unwrap(),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => panic!("FilesystemWatcher disconnected."),
+                };
+                if let notify::event::Event {
+                    kind:
+                        notify::event::EventKind::Create(_)
+                        | notify::event::EventKind::Modify(_)
+                        | notify::event::EventKind::Remove(_),
+                    paths,
+                    ..
+                } = event
+                {
+                    let assets = self.assets.read();
+                    changed.extend(
+                        assets
+                            .iter()
+                            .filter(|(_, asset)| {
+                                asset
+                                    .source_path
+                                    .as_ref()
+                                    .map_or(false, |source_path| paths.contains(source_path))
+                            })
+                            .map(|(path, _)| path.clone()),
+                    );
+                }
+            }
+        }
+        changed
+    }
+}
+
+impl AssetIo for EmbeddedAssetIo {
+    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        Box::pin(async move {
+            let (bytes, _source_path) = self.get_asset(path)?;
+            #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+            if let Some(source_path) = _source_path {
+                if let Ok(bytes) = crate::unblock(move || std::fs::read(source_path)).await {
+                    return Ok(bytes);
+                }
+            }
+            Ok(bytes.to_vec())
+        })
+    }
+
+    fn read_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<dyn Reader>, AssetIoError>> {
+        Box::pin(async move {
+            let (bytes, _source_path) = self.get_asset(path)?;
+            #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+            if let Some(source_path) = _source_path {
+                if let Ok(file) = crate::unblock(move || std::fs::File::open(source_path)).await {
+                    return Ok(Box::new(crate::Unblock::new(file)) as Box<dyn Reader>);
+                }
+            }
+            // the embedded bytes are read in place, without copying them
+            Ok(Box::new(Cursor::new(bytes)) as Box<dyn Reader>)
+        })
+    }
+
+    fn read_directory(
+        &self,
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+        if !self.is_dir(path) {
+            return Err(AssetIoError::NotFound(path.to_owned()));
+        }
+        let mut children = Vec::new();
+        for asset_path in self.assets.read().keys() {
+            if let Ok(relative) = asset_path.strip_prefix(path) {
+                if let Some(child) = relative.components().next() {
+                    let child = path.join(child);
+                    if children.last() != Some(&child) {
+                        children.push(child);
+                    }
+                }
+            }
+        }
+        Ok(Box::new(children.into_iter()))
+    }
+
+    fn get_metadata(&self, path: &Path) -> Result<Metadata, AssetIoError> {
+        let assets = self.assets.read();
+        if assets.contains_key(path) {
+            Ok(Metadata::new(FileType::File))
+        } else if assets.keys().any(|asset_path| asset_path.starts_with(path)) {
+            Ok(Metadata::new(FileType::Directory))
+        } else {
+            Err(AssetIoError::NotFound(path.to_owned()))
+        }
+    }
+
+    fn watch_path_for_changes(&self, _path: &Path) -> Result<(), AssetIoError> {
+        // the source paths are watched as soon as the assets are inserted
+        Ok(())
+    }
+
+    fn watch_for_changes(&self) -> Result<(), AssetIoError> {
+        #[cfg(all(
+            feature = "filesystem_watcher",
+            all(not(target_arch = "wasm32"), not(target_os = "android"))
+        ))]
+        {
+            *self.filesystem_watcher.write() = Some(FilesystemWatcher::default());
+            for asset in self.assets.read().values() {
+                if let Some(source_path) = &asset.source_path {
+                    self.watch_source_path(source_path);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the path of an asset embedded from `asset_path`, relative to the file at
+/// `file_path` of the module at `module_path`.
+///
+/// The path starts with the crate name, followed by the path of the asset relative to the `src`
+/// folder of the crate: `bevy_pbr/render/icon.png` for `icon.png` in `src/render/mod.rs` of
+/// `bevy_pbr`.
+pub fn embedded_asset_path(module_path: &str, file_path: &Path, asset_path: &Path) -> PathBuf {
+    let crate_name = module_path.split("::").next().unwrap();
+    let folder = file_path.parent().unwrap();
+    let relative_folder: PathBuf = match folder.iter().position(|component| component == "src") {
+        Some(src) => folder.iter().skip(src + 1).collect(),
+        None => folder.to_owned(),
+    };
+    Path::new(crate_name).join(relative_folder).join(asset_path)
+}
+
+/// Returns the absolute path of the file an asset was embedded from, if it can be found.
+///
+/// `file_path` is relative to the workspace of the crate, which is an ancestor of the crate
+/// manifest directory.
+pub fn embedded_source_path(
+    manifest_dir: &str,
+    file_path: &Path,
+    asset_path: &Path,
+) -> Option<PathBuf> {
+    let path = file_path.parent()?.join(asset_path);
+    Path::new(manifest_dir)
+        .ancestors()
+        .map(|root| root.join(&path))
+        .find(|path| path.is_file())
+        .and_then(|path| path.canonicalize().ok())
+}
+
+/// Embeds the asset at `path`, relative to the current file, into the binary. The asset is
+/// loaded from the [`EMBEDDED_ASSET_SOURCE`] with the path returned by [`embedded_asset_path`].
+///
+/// In builds with debug assertions, the asset is loaded from its file when it exists, and
+/// reloaded when it changes while watching for changes.
+///
+/// ```ignore
+/// // in src/render/mod.rs of my_crate
+/// embedded_asset!(app, "icon.png");
+/// let icon: Handle<Image> = asset_server.load("embedded://my_crate/render/icon.png");
+/// ```
+#[macro_export]
+macro_rules! embedded_asset {
+    ($app: ident, $path: expr) => {{
+        let file_path = ::std::path::Path::new(file!());
+        let asset_path = ::std::path::Path::new($path);
+        let source_path = if cfg!(debug_assertions) {
+            $crate::embedded_source_path(env!("CARGO_MANIFEST_DIR"), file_path, asset_path)
+        } else {
+            None
+        };
+        let asset_io = $app
+            .world
+            .resource::<$crate::AssetServer>()
+            .get_source_io(Some($crate::EMBEDDED_ASSET_SOURCE))
+            .unwrap();
+        asset_io
+            .downcast_ref::<$crate::EmbeddedAssetIo>()
+            .expect("the embedded asset source should be an EmbeddedAssetIo")
+            .insert_asset(
+                $crate::embedded_asset_path(module_path!(), file_path, asset_path),
+                include_bytes!($path),
+                source_path,
+            );
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AssetPlugin, AssetServer};
+    use bevy_app::App;
+
+    #[test]
+    fn embedded_asset_paths() {
+        assert_eq!(
+            embedded_asset_path(
+                "bevy_pbr::render",
+                Path::new("crates/bevy_pbr/src/render/mod.rs"),
+                Path::new("icon.png")
+            ),
+            PathBuf::from("bevy_pbr/render/icon.png")
+        );
+        assert_eq!(
+            embedded_asset_path(
+                "my_game",
+                Path::new("src/main.rs"),
+                Path::new("shaders/water.wgsl")
+            ),
+            PathBuf::from("my_game/shaders/water.wgsl")
+        );
+    }
+
+    #[test]
+    fn load_embedded_asset() {
+        let mut app = App::new();
+        app.add_plugin(bevy_core::CorePlugin)
+            .add_plugin(AssetPlugin);
+        embedded_asset!(app, "embedded_asset_io.rs");
+
+        let asset_io = app
+            .world
+            .resource::<AssetServer>()
+            .get_source_io(Some(EMBEDDED_ASSET_SOURCE))
+            .unwrap();
+        let path = Path::new("bevy_asset/io/embedded_asset_io.rs");
+        assert!(asset_io.is_file(path));
+        assert!(asset_io.is_dir(Path::new("bevy_asset")));
+        let bytes = futures_lite::future::block_on(asset_io.load_path(path)).unwrap();
+        assert_eq!(bytes, include_bytes!("embedded_asset_io.rs"));
+        assert!(matches!(
+            futures_lite::future::block_on(asset_io.load_path(Path::new("bevy_asset/missing.rs"))),
+            Err(AssetIoError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn load_from_source_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let source_path = dir.path().join("a.txt");
+        std::fs::write(&source_path, "modified").unwrap();
+        let asset_io = EmbeddedAssetIo::new();
+        asset_io.insert_asset("my_crate/a.txt", b"embedded", Some(source_path.clone()));
+
+        let load =
+            || futures_lite::future::block_on(asset_io.load_path(Path::new("my_crate/a.txt")));
+        assert_eq!(load().unwrap(), b"modified");
+        std::fs::remove_file(&source_path).unwrap();
+        assert_eq!(load().unwrap(), b"embedded");
+    }
+
+    #[cfg(feature = "filesystem_watcher")]
+    #[test]
+    fn source_replaced_by_rename() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir_path = dir.path().canonicalize().unwrap();
+        let source_path = dir_path.join("a.txt");
+        std::fs::write(&source_path, "first").unwrap();
+        let asset_io = EmbeddedAssetIo::new();
+        asset_io.insert_asset("my_crate/a.txt", b"embedded", Some(source_path.clone()));
+        asset_io.watch_for_changes().unwrap();
+
+        // editors often save to a temporary file and rename it over the original
+        let saved_path = dir_path.join("a.txt.tmp");
+        std::fs::write(&saved_path, "second").unwrap();
+        std::fs::rename(&saved_path, &source_path).unwrap();
+
+        let mut modified = HashSet::default();
+        for _ in 0..500 {
+            modified.extend(asset_io.take_modified_paths());
+            if !modified.is_empty() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert!(modified.contains(Path::new("my_crate/a.txt")));
+
+        let mut reader =
+            futures_lite::future::block_on(asset_io.read_path(Path::new("my_crate/a.txt")))
+                .unwrap();
+        let mut bytes = Vec::new();
+        futures_lite::future::block_on(futures_lite::AsyncReadExt::read_to_end(
+            &mut reader,
+            &mut bytes,
+        ))
+        .unwrap();
+        assert_eq!(bytes, b"second");
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg(feature = "filesystem_watcher")]
-use crate::{filesystem_watcher::FilesystemWatcher, AssetServer};
-use crate::{AssetIo, AssetIoError, Metadata};
+use crate::{
+    filesystem_watcher::FilesystemWatcher, AssetPath, AssetServer, EmbeddedAssetIo,
+    META_FILE_EXTENSION,
+};
+use crate::{AssetIo, AssetIoError, AssetWriter, Metadata, Reader, Unblock};
 use anyhow::Result;
 #[cfg(feature = "filesystem_watcher")]
 use bevy_ecs::system::Res;
@@ -67,29 +70,73 @@ impl FileAssetIo {
     pub fn root_path(&self) -> &PathBuf {
         &self.root_path
     }
+
+    fn open_file(&self, path: &Path) -> Result<File, AssetIoError> {
+        let full_path = self.root_path.join(path);
+        File::open(&full_path).map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                AssetIoError::NotFound(full_path)
+            } else {
+                e.into()
+            }
+        })
+    }
+
+    /// Returns the paths, relative to the root path, of the files created, modified or removed
+    /// since the last call.
+    #[cfg(feature = "filesystem_watcher")]
+    pub(crate) fn take_modified_paths(&self) -> HashSet<PathBuf> {
+        let mut changed = HashSet::default();
+        let watcher = self.filesystem_watcher.read();
+        if let Some(ref watcher) = *watcher {
+            loop {
+                let event = match watcher.receiver.try_recv() {
+                    Ok(result) => result.unwrap(),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => panic!("FilesystemWatcher disconnected."),
+                };
+                if let notify::event::Event {
+                    kind:
+                        notify::event::EventKind::Create(_)
+                        | notify::event::EventKind::Modify(_)
+                        | notify::event::EventKind::Remove(_),
+                    paths,
+                    ..
+                } = event
+                {
+                    for path in paths {
+                        if let Ok(relative_path) = path.strip_prefix(&self.root_path) {
+                            changed.insert(relative_path.to_owned());
+                        }
+                    }
+                }
+            }
+        }
+        changed
+    }
 }
 
 impl AssetIo for FileAssetIo {
     fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
         Box::pin(async move {
             let mut bytes = Vec::new();
-            let full_path = self.root_path.join(path);
-            match File::open(&full_path) {
-                Ok(mut file) => {
-                    file.read_to_end(&mut bytes)?;
-                }
-                Err(e) => {
-                    return if e.kind() == std::io::ErrorKind::NotFound {
-                        Err(AssetIoError::NotFound(full_path))
-                    } else {
-                        Err(e.into())
-                    }
-                }
-            }
+            self.open_file(path)?.read_to_end(&mut bytes)?;
             Ok(bytes)
         })
     }
 
+    /// Reads the file on a thread of its own, so that the loader streaming it doesn't stall the
+    /// task pool.
+    fn read_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<dyn Reader>, AssetIoError>> {
+        Box::pin(async move {
+            let file = self.open_file(path)?;
+            Ok(Box::new(Unblock::new(file)) as Box<dyn Reader>)
+        })
+    }
+
     fn read_directory(
         &self,
         path: &Path,
@@ -142,6 +189,40 @@ impl AssetIo for FileAssetIo {
                 }
             })
     }
+
+    fn writer(&self) -> Option<&dyn AssetWriter> {
+        Some(self)
+    }
+}
+
+impl AssetWriter for FileAssetIo {
+    fn write_path<'a>(
+        &'a self,
+        path: &'a Path,
+        bytes: &'a [u8],
+    ) -> BoxedFuture<'a, Result<(), AssetIoError>> {
+        Box::pin(async move {
+            let full_path = self.root_path.join(path);
+            if let Some(parent) = full_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(full_path, bytes)?;
+            Ok(())
+        })
+    }
+
+    fn remove_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<(), AssetIoError>> {
+        Box::pin(async move {
+            let full_path = self.root_path.join(path);
+            fs::remove_file(&full_path).map_err(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    AssetIoError::NotFound(full_path)
+                } else {
+                    e.into()
+                }
+            })
+        })
+    }
 }
 
 #[cfg(all(
@@ -149,35 +230,51 @@ impl AssetIo for FileAssetIo {
     all(not(target_arch = "wasm32"), not(target_os = "android"))
 ))]
 pub fn filesystem_watcher_system(asset_server: Res<AssetServer>) {
-    let mut changed = HashSet::default();
-    let asset_io =
-        if let Some(asset_io) = asset_server.server.asset_io.downcast_ref::<FileAssetIo>() {
-            asset_io
+    reload_modified_assets(&asset_server, None, &*asset_server.server.asset_io);
+    let named_sources = asset_server.server.named_sources.read();
+    for (source, asset_io) in named_sources.iter() {
+        reload_modified_assets(&asset_server, Some(source), &**asset_io);
+    }
+}
+
+#[cfg(all(
+    feature = "filesystem_watcher",
+    all(not(target_arch = "wasm32"), not(target_os = "android"))
+))]
+fn reload_modified_assets(
+    asset_server: &AssetServer,
+    source: Option<&str>,
+    asset_io: &dyn AssetIo,
+) {
+    let modified_paths = if let Some(asset_io) = asset_io.downcast_ref::<FileAssetIo>() {
+        asset_io.take_modified_paths()
+    } else if let Some(asset_io) = asset_io.downcast_ref::<EmbeddedAssetIo>() {
+        asset_io.take_modified_paths()
+    } else {
+        return;
+    };
+    for path in modified_paths {
+        // a modified meta file reloads the asset it configures
+        let is_meta = path.extension() == Some(META_FILE_EXTENSION.as_ref());
+        let path = if is_meta {
+            path.with_extension("")
         } else {
-            return;
+            path
         };
-    let watcher = asset_io.filesystem_watcher.read();
-    if let Some(ref watcher) = *watcher {
-        loop {
-            let event = match watcher.receiver.try_recv() {
-                Ok(result) => result.unwrap(),
-                Err(TryRecvError::Empty) => break,
-                Err(TryRecvError::Disconnected) => panic!("FilesystemWatcher disconnected."),
-            };
-            if let notify::event::Event {
-                kind: notify::event::EventKind::Modify(_),
-                paths,
-                ..
-            } = event
-            {
-                for path in &paths {
-                    if !changed.contains(path) {
-                        let relative_path = path.strip_prefix(&asset_io.root_path).unwrap();
-                        let _ = asset_server.load_untracked(relative_path.into(), true);
-                    }
-                }
-                changed.extend(paths);
-            }
+        // removed assets keep their loaded value
+        if !asset_io.is_file(&path) {
+            continue;
+        }
+        let asset_path = AssetPath::from(path);
+        let asset_path = match source {
+            Some(source) => asset_path.with_source(source),
+            None => asset_path,
+        };
+        // the changes made by `AssetServer::save` are skipped, which never writes meta files
+        if is_meta {
+            asset_server.reload_changed_asset(asset_path.to_owned());
+        } else {
+            asset_server.reload_unless_save_echo(asset_path.to_owned());
         }
     }
 }
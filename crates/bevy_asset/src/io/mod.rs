@@ -2,23 +2,38 @@
 mod android_asset_io;
 #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
 mod file_asset_io;
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+mod processed_asset_io;
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+mod unblock;
 #[cfg(target_arch = "wasm32")]
 mod wasm_asset_io;
+#[cfg(feature = "zip_asset_io")]
+mod zip_asset_io;
 
+mod embedded_asset_io;
 mod metadata;
 
 #[cfg(target_os = "android")]
 pub use android_asset_io::*;
 #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
 pub use file_asset_io::*;
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+pub use processed_asset_io::*;
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+pub(crate) use unblock::*;
 #[cfg(target_arch = "wasm32")]
 pub use wasm_asset_io::*;
+#[cfg(feature = "zip_asset_io")]
+pub use zip_asset_io::*;
 
+pub use embedded_asset_io::*;
 pub use metadata::*;
 
 use anyhow::Result;
 use bevy_utils::BoxedFuture;
 use downcast_rs::{impl_downcast, Downcast};
+use futures_lite::io::{AsyncRead, AsyncSeek, Cursor};
 use std::{
     io,
     path::{Path, PathBuf},
@@ -36,9 +51,27 @@ pub enum AssetIoError {
     PathWatchError(PathBuf),
 }
 
+/// An asynchronous reader of the bytes of an asset file, returned by [`AssetIo::read_path`].
+pub trait Reader: AsyncRead + AsyncSeek + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncSeek + Unpin + Send + ?Sized> Reader for T {}
+
 /// Handles load requests from an `AssetServer`
 pub trait AssetIo: Downcast + Send + Sync + 'static {
     fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>>;
+    /// Returns a [`Reader`] over the file at `path`, which can be read in parts instead of all at
+    /// once.
+    ///
+    /// The default implementation reads the whole file with [`AssetIo::load_path`].
+    fn read_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<dyn Reader>, AssetIoError>> {
+        Box::pin(async move {
+            let bytes = self.load_path(path).await?;
+            Ok(Box::new(Cursor::new(bytes)) as Box<dyn Reader>)
+        })
+    }
     fn read_directory(
         &self,
         path: &Path,
@@ -60,6 +93,22 @@ pub trait AssetIo: Downcast + Send + Sync + 'static {
             .map(Metadata::is_file)
             .unwrap_or(false)
     }
+
+    /// Returns the [`AssetWriter`] writing to this asset source, if it is writable.
+    fn writer(&self) -> Option<&dyn AssetWriter> {
+        None
+    }
 }
 
 impl_downcast!(AssetIo);
+
+/// Handles write requests from an `AssetServer`, for the [`AssetIo`]s that are writable.
+pub trait AssetWriter: Send + Sync + 'static {
+    /// Writes `bytes` to the file at `path`, creating its parent directories if needed.
+    fn write_path<'a>(
+        &'a self,
+        path: &'a Path,
+        bytes: &'a [u8],
+    ) -> BoxedFuture<'a, Result<(), AssetIoError>>;
+    fn remove_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<(), AssetIoError>>;
+}
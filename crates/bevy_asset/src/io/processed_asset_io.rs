@@ -0,0 +1,183 @@
+use crate::{AssetIo, AssetIoError, FileAssetIo, FileType, Metadata, Reader};
+use anyhow::Result;
+use bevy_log::warn;
+use bevy_utils::BoxedFuture;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// The name of the file in the processed asset folder that stores the [`ProcessedAssetIndex`].
+pub const PROCESSED_ASSET_INDEX_FILE: &str = "index.ron";
+
+/// Where the processed output of a source asset is stored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessedAssetInfo {
+    /// The hash of the source asset content, of the processor that produced the output and of
+    /// the dependencies it read.
+    pub hash: String,
+    /// The path of the output, relative to the processed asset folder.
+    pub output: PathBuf,
+    /// The source assets read by the processor while producing the output.
+    #[serde(default)]
+    pub dependencies: Vec<PathBuf>,
+}
+
+/// Maps the paths of source assets to their processed outputs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProcessedAssetIndex {
+    pub assets: BTreeMap<PathBuf, ProcessedAssetInfo>,
+}
+
+impl ProcessedAssetIndex {
+    /// Reads the index stored in the given processed asset folder, if there is one.
+    pub fn read<P: AsRef<Path>>(folder: P) -> Result<Self, AssetIoError> {
+        let path = folder.as_ref().join(PROCESSED_ASSET_INDEX_FILE);
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e.into()),
+        };
+        ron::de::from_bytes(&bytes)
+            .map_err(|e| AssetIoError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))
+    }
+
+    /// Writes this index to the given processed asset folder.
+    pub fn write<P: AsRef<Path>>(&self, folder: P) -> Result<(), AssetIoError> {
+        let index = ron::ser::to_string_pretty(self, Default::default()).map_err(|e| {
+            AssetIoError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+        })?;
+        fs::write(folder.as_ref().join(PROCESSED_ASSET_INDEX_FILE), index)?;
+        Ok(())
+    }
+}
+
+/// Loads the outputs of the asset processor from the processed asset folder.
+///
+/// Assets are requested by the path of their source, which is resolved to the output
+/// through the [`ProcessedAssetIndex`].
+pub struct ProcessedAssetIo {
+    io: FileAssetIo,
+    index: Arc<RwLock<ProcessedAssetIndex>>,
+    processing: RwLock<Option<async_channel::Receiver<()>>>,
+}
+
+impl ProcessedAssetIo {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        let io = FileAssetIo::new(path, false);
+        let index = ProcessedAssetIndex::read(io.root_path()).unwrap_or_else(|err| {
+            warn!("failed to read the processed asset index: {}", err);
+            Default::default()
+        });
+        Self {
+            io,
+            index: Arc::new(RwLock::new(index)),
+            processing: RwLock::new(None),
+        }
+    }
+
+    pub fn root_path(&self) -> &PathBuf {
+        self.io.root_path()
+    }
+
+    /// The index shared with the asset processor writing to this folder.
+    pub fn index(&self) -> &Arc<RwLock<ProcessedAssetIndex>> {
+        &self.index
+    }
+
+    /// Returns the path of the processed output of the asset at `path`.
+    pub fn processed_path(&self, path: &Path) -> Option<PathBuf> {
+        self.index
+            .read()
+            .assets
+            .get(path)
+            .map(|info| info.output.clone())
+    }
+
+    /// Makes loads wait until the returned sender is dropped, so that they don't read outputs
+    /// the asset processor is still writing.
+    pub fn pause_loads(&self) -> async_channel::Sender<()> {
+        let (sender, receiver) = async_channel::bounded(1);
+        *self.processing.write() = Some(receiver);
+        sender
+    }
+
+    /// Waits until the loads are no longer paused by [`ProcessedAssetIo::pause_loads`].
+    pub async fn wait_for_processing(&self) {
+        let processing = self.processing.read().clone();
+        if let Some(receiver) = processing {
+            // only returns once every sender is dropped, as nothing is ever sent
+            let _ = receiver.recv().await;
+        }
+    }
+}
+
+impl AssetIo for ProcessedAssetIo {
+    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        Box::pin(async move {
+            self.wait_for_processing().await;
+            match self.processed_path(path) {
+                Some(output) => self.io.load_path(&output).await,
+                None => Err(AssetIoError::NotFound(path.to_owned())),
+            }
+        })
+    }
+
+    fn read_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<dyn Reader>, AssetIoError>> {
+        Box::pin(async move {
+            self.wait_for_processing().await;
+            match self.processed_path(path) {
+                Some(output) => self.io.read_path(&output).await,
+                None => Err(AssetIoError::NotFound(path.to_owned())),
+            }
+        })
+    }
+
+    fn read_directory(
+        &self,
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+        if !self.is_dir(path) {
+            return Err(AssetIoError::NotFound(path.to_owned()));
+        }
+        let mut children = Vec::new();
+        for source in self.index.read().assets.keys() {
+            if let Ok(relative) = source.strip_prefix(path) {
+                if let Some(child) = relative.components().next() {
+                    let child = path.join(child);
+                    if children.last() != Some(&child) {
+                        children.push(child);
+                    }
+                }
+            }
+        }
+        Ok(Box::new(children.into_iter()))
+    }
+
+    fn get_metadata(&self, path: &Path) -> Result<Metadata, AssetIoError> {
+        let index = self.index.read();
+        if index.assets.contains_key(path) {
+            Ok(Metadata::new(FileType::File))
+        } else if index.assets.keys().any(|source| source.starts_with(path)) {
+            Ok(Metadata::new(FileType::Directory))
+        } else {
+            Err(AssetIoError::NotFound(path.to_owned()))
+        }
+    }
+
+    fn watch_path_for_changes(&self, _path: &Path) -> Result<(), AssetIoError> {
+        // processed assets are reloaded by the asset processor when it updates them
+        Ok(())
+    }
+
+    fn watch_for_changes(&self) -> Result<(), AssetIoError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+use futures_lite::{
+    io::{AsyncRead, AsyncSeek},
+    ready, StreamExt,
+};
+use std::{
+    io::{self, Read, Seek, SeekFrom},
+    pin::Pin,
+    task::{Context, Poll},
+    thread,
+};
+
+/// Runs the blocking `f` on a new thread, and waits for its result without blocking the
+/// executor.
+pub(crate) async fn unblock<T, F>(f: F) -> T
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = async_channel::bounded(1);
+    thread::spawn(move || {
+        let _ = sender.try_send(f());
+    });
+    receiver
+        .recv()
+        .await
+        .expect("the blocking operation panicked")
+}
+
+enum Op {
+    Read(usize),
+    Seek(SeekFrom),
+}
+
+enum Reply {
+    Read(Vec<u8>),
+    Seek(u64),
+}
+
+/// An async reader over a blocking one, such as a [`std::fs::File`], whose reads and seeks run
+/// on a thread of its own so that they don't stall the executor.
+pub(crate) struct Unblock {
+    ops: crossbeam_channel::Sender<Op>,
+    replies: async_channel::Receiver<io::Result<Reply>>,
+    /// Whether an operation was sent and its reply is not received yet.
+    pending: bool,
+    /// The bytes read by the thread but not by the reader yet.
+    buffer: Vec<u8>,
+    consumed: usize,
+}
+
+impl Unblock {
+    pub(crate) fn new<T: Read + Seek + Send + 'static>(mut io: T) -> Self {
+        let (ops, op_receiver) = crossbeam_channel::unbounded();
+        let (reply_sender, replies) = async_channel::bounded(1);
+        // the thread stops once the `Unblock` is dropped
+        thread::spawn(move || {
+            for op in op_receiver {
+                let reply = match op {
+                    Op::Read(len) => {
+                        let mut bytes = vec![0; len];
+                        io.read(&mut bytes).map(|read| {
+                            bytes.truncate(read);
+                            Reply::Read(bytes)
+                        })
+                    }
+                    Op::Seek(pos) => io.seek(pos).map(Reply::Seek),
+                };
+                if reply_sender.try_send(reply).is_err() {
+                    break;
+                }
+            }
+        });
+        Self {
+            ops,
+            replies,
+            pending: false,
+            buffer: Vec::new(),
+            consumed: 0,
+        }
+    }
+
+    fn send(&mut self, op: Op) -> io::Result<()> {
+        self.ops.send(op).map_err(|_| Self::stopped())?;
+        self.pending = true;
+        Ok(())
+    }
+
+    fn poll_reply(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Reply>> {
+        let reply = ready!(self.replies.poll_next(cx));
+        self.pending = false;
+        Poll::Ready(reply.unwrap_or_else(|| Err(Self::stopped())))
+    }
+
+    fn stopped() -> io::Error {
+        io::Error::new(
+            io::ErrorKind::BrokenPipe,
+            "the blocking reader thread stopped",
+        )
+    }
+}
+
+impl AsyncRead for Unblock {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        loop {
+            if self.consumed < self.buffer.len() {
+                let read = buf.len().min(self.buffer.len() - self.consumed);
+                buf[..read].copy_from_slice(&self.buffer[self.consumed..self.consumed + read]);
+                self.consumed += read;
+                return Poll::Ready(Ok(read));
+            }
+            if buf.is_empty() {
+                return Poll::Ready(Ok(0));
+            }
+            if !self.pending {
+                self.send(Op::Read(buf.len()))?;
+            }
+            match ready!(self.poll_reply(cx))? {
+                Reply::Read(bytes) if bytes.is_empty() => return Poll::Ready(Ok(0)),
+                Reply::Read(bytes) => {
+                    self.buffer = bytes;
+                    self.consumed = 0;
+                }
+                // the reply of a seek that was not polled to completion
+                Reply::Seek(_) => {}
+            }
+        }
+    }
+}
+
+impl AsyncSeek for Unblock {
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<io::Result<u64>> {
+        loop {
+            if !self.pending {
+                // the buffered bytes were already read from the underlying reader
+                let pos = match pos {
+                    SeekFrom::Current(offset) => {
+                        SeekFrom::Current(offset - (self.buffer.len() - self.consumed) as i64)
+                    }
+                    pos => pos,
+                };
+                self.buffer.clear();
+                self.consumed = 0;
+                self.send(Op::Seek(pos))?;
+            }
+            match ready!(self.poll_reply(cx))? {
+                Reply::Seek(position) => return Poll::Ready(Ok(position)),
+                // the reply of a read that was not polled to completion
+                Reply::Read(bytes) => {
+                    self.buffer = bytes;
+                    self.consumed = 0;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::{future, AsyncReadExt, AsyncSeekExt};
+    use std::io::Cursor;
+
+    #[test]
+    fn read_and_seek() {
+        let mut reader = Unblock::new(Cursor::new(b"hello world".to_vec()));
+        future::block_on(async {
+            let mut hello = [0; 5];
+            reader.read_exact(&mut hello).await.unwrap();
+            assert_eq!(&hello, b"hello");
+            assert_eq!(reader.seek(SeekFrom::Current(1)).await.unwrap(), 6);
+
+            let mut world = Vec::new();
+            reader.read_to_end(&mut world).await.unwrap();
+            assert_eq!(world, b"world");
+
+            assert_eq!(reader.seek(SeekFrom::End(-5)).await.unwrap(), 6);
+            let mut w = [0; 1];
+            reader.read_exact(&mut w).await.unwrap();
+            assert_eq!(&w, b"w");
+        });
+        assert_eq!(future::block_on(unblock(|| 1 + 1)), 2);
+    }
+}
@@ -0,0 +1,304 @@
+use crate::{AssetIo, AssetIoError, FileType, Metadata, Reader};
+use anyhow::Result;
+use bevy_utils::{BoxedFuture, HashMap};
+use futures_lite::io::Cursor;
+use parking_lot::Mutex;
+use std::{
+    collections::BTreeSet,
+    fs::File,
+    io::{self, Read, Seek},
+    path::{Path, PathBuf},
+};
+use zip::{result::ZipError, ZipArchive};
+
+/// A source of zip archive bytes.
+pub trait ZipReader: Read + Seek + Send + 'static {}
+
+impl<T: Read + Seek + Send + 'static> ZipReader for T {}
+
+/// Loads assets from one or more zip archives.
+///
+/// Archives form an overlay: a file in an archive added later overrides a file with the same
+/// path in an archive added earlier. Paths missing from every archive are loaded from the base
+/// [`AssetIo`], if there is one.
+///
+/// ```no_run
+/// # use bevy_asset::{FileAssetIo, ZipAssetIo};
+/// let asset_io = ZipAssetIo::new()
+///     .with_base(FileAssetIo::new("assets", false))
+///     .with_archive("assets.zip")
+///     .unwrap()
+///     .with_archive("patch.zip")
+///     .unwrap();
+/// ```
+#[derive(Default)]
+pub struct ZipAssetIo {
+    archives: Vec<Mutex<ZipArchive<Box<dyn ZipReader>>>>,
+    /// The archive and entry index of each file.
+    files: HashMap<PathBuf, (usize, usize)>,
+    /// The children of each directory.
+    directories: HashMap<PathBuf, BTreeSet<PathBuf>>,
+    base: Option<Box<dyn AssetIo>>,
+}
+
+impl ZipAssetIo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [`AssetIo`] used for the paths that are not found in any archive.
+    #[must_use]
+    pub fn with_base<T: AssetIo>(mut self, base: T) -> Self {
+        self.base = Some(Box::new(base));
+        self
+    }
+
+    /// Adds the zip archive at `path` on top of the previously added archives.
+    pub fn with_archive<P: AsRef<Path>>(mut self, path: P) -> Result<Self, AssetIoError> {
+        self.add_archive(path)?;
+        Ok(self)
+    }
+
+    /// Adds the zip archive at `path` on top of the previously added archives.
+    pub fn add_archive<P: AsRef<Path>>(&mut self, path: P) -> Result<(), AssetIoError> {
+        let file = File::open(path.as_ref()).map_err(|e| {
+            if e.kind() == io::ErrorKind::NotFound {
+                AssetIoError::NotFound(path.as_ref().to_owned())
+            } else {
+                e.into()
+            }
+        })?;
+        self.add_archive_from_reader(file)
+    }
+
+    /// Adds a zip archive read from `reader` on top of the previously added archives.
+    pub fn add_archive_from_reader<R: ZipReader>(&mut self, reader: R) -> Result<(), AssetIoError> {
+        let mut archive =
+            ZipArchive::new(Box::new(reader) as Box<dyn ZipReader>).map_err(zip_error)?;
+        let archive_index = self.archives.len();
+        for entry_index in 0..archive.len() {
+            let entry = archive.by_index(entry_index).map_err(zip_error)?;
+            // skip entries escaping the archive root, such as `../file`
+            let path = match entry.enclosed_name() {
+                Some(path) => path.to_owned(),
+                None => continue,
+            };
+            if entry.is_dir() {
+                self.add_directory(&path);
+            } else {
+                self.add_child(&path);
+                self.files.insert(path, (archive_index, entry_index));
+            }
+        }
+        self.archives.push(Mutex::new(archive));
+        Ok(())
+    }
+
+    /// Registers `path` and its ancestors as directories.
+    fn add_directory(&mut self, path: &Path) {
+        if self.directories.contains_key(path) {
+            return;
+        }
+        self.directories.insert(path.to_owned(), BTreeSet::new());
+        self.add_child(path);
+    }
+
+    /// Registers `path` as a child of its parent directory.
+    fn add_child(&mut self, path: &Path) {
+        if let Some(parent) = path.parent() {
+            self.add_directory(parent);
+            self.directories
+                .get_mut(parent)
+                .unwrap()
+                .insert(path.to_owned());
+        }
+    }
+
+    fn read_file(&self, archive_index: usize, entry_index: usize) -> Result<Vec<u8>, AssetIoError> {
+        let mut archive = self.archives[archive_index].lock();
+        let mut entry = archive.by_index(entry_index).map_err(zip_error)?;
+        let mut bytes = Vec::with_capacity(entry.size() as usize);
+        entry.read_to_end(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+fn zip_error(err: ZipError) -> AssetIoError {
+    match err {
+        ZipError::Io(err) => AssetIoError::Io(err),
+        err => AssetIoError::Io(io::Error::new(io::ErrorKind::InvalidData, err)),
+    }
+}
+
+impl AssetIo for ZipAssetIo {
+    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        Box::pin(async move {
+            match (self.files.get(path), &self.base) {
+                (Some(&(archive_index, entry_index)), _) => {
+                    self.read_file(archive_index, entry_index)
+                }
+                (None, Some(base)) => base.load_path(path).await,
+                (None, None) => Err(AssetIoError::NotFound(path.to_owned())),
+            }
+        })
+    }
+
+    fn read_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<dyn Reader>, AssetIoError>> {
+        Box::pin(async move {
+            match (self.files.get(path), &self.base) {
+                // compressed entries can't be seeked, so they are read entirely
+                (Some(&(archive_index, entry_index)), _) => {
+                    let bytes = self.read_file(archive_index, entry_index)?;
+                    Ok(Box::new(Cursor::new(bytes)) as Box<dyn Reader>)
+                }
+                (None, Some(base)) => base.read_path(path).await,
+                (None, None) => Err(AssetIoError::NotFound(path.to_owned())),
+            }
+        })
+    }
+
+    fn read_directory(
+        &self,
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+        let mut children = self.directories.get(path).cloned();
+        if let Some(base) = &self.base {
+            if base.is_dir(path) {
+                children
+                    .get_or_insert_with(BTreeSet::new)
+                    .extend(base.read_directory(path)?);
+            }
+        }
+        match children {
+            Some(children) => Ok(Box::new(children.into_iter())),
+            None => Err(AssetIoError::NotFound(path.to_owned())),
+        }
+    }
+
+    fn get_metadata(&self, path: &Path) -> Result<Metadata, AssetIoError> {
+        if self.files.contains_key(path) {
+            Ok(Metadata::new(FileType::File))
+        } else if self.directories.contains_key(path) {
+            Ok(Metadata::new(FileType::Directory))
+        } else if let Some(base) = &self.base {
+            base.get_metadata(path)
+        } else {
+            Err(AssetIoError::NotFound(path.to_owned()))
+        }
+    }
+
+    fn watch_path_for_changes(&self, path: &Path) -> Result<(), AssetIoError> {
+        // archives are immutable, only the base can change
+        match &self.base {
+            Some(base) if !self.files.contains_key(path) => base.watch_path_for_changes(path),
+            _ => Ok(()),
+        }
+    }
+
+    fn watch_for_changes(&self) -> Result<(), AssetIoError> {
+        match &self.base {
+            Some(base) => base.watch_for_changes(),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+    use zip::{write::FileOptions, ZipWriter};
+
+    fn create_archive(files: &[(&str, &str)]) -> Cursor<Vec<u8>> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (path, content) in files {
+            writer.start_file(*path, FileOptions::default()).unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        let mut archive = writer.finish().unwrap();
+        archive.set_position(0);
+        archive
+    }
+
+    fn load(asset_io: &ZipAssetIo, path: &str) -> Result<String, AssetIoError> {
+        let bytes = futures_lite::future::block_on(asset_io.load_path(Path::new(path)))?;
+        Ok(String::from_utf8(bytes).unwrap())
+    }
+
+    #[test]
+    fn load_from_overlaid_archives() {
+        let mut asset_io = ZipAssetIo::new();
+        asset_io
+            .add_archive_from_reader(create_archive(&[("a.txt", "a"), ("textures/b.txt", "b")]))
+            .unwrap();
+        asset_io
+            .add_archive_from_reader(create_archive(&[
+                ("textures/b.txt", "patched b"),
+                ("textures/ui/c.txt", "c"),
+            ]))
+            .unwrap();
+
+        assert_eq!(load(&asset_io, "a.txt").unwrap(), "a");
+        assert_eq!(load(&asset_io, "textures/b.txt").unwrap(), "patched b");
+        assert_eq!(load(&asset_io, "textures/ui/c.txt").unwrap(), "c");
+        assert!(matches!(
+            load(&asset_io, "d.txt"),
+            Err(AssetIoError::NotFound(_))
+        ));
+
+        assert!(asset_io.is_file(Path::new("a.txt")));
+        assert!(asset_io.is_dir(Path::new("textures/ui")));
+        let children: Vec<_> = asset_io
+            .read_directory(Path::new("textures"))
+            .unwrap()
+            .collect();
+        assert_eq!(
+            children,
+            vec![
+                PathBuf::from("textures/b.txt"),
+                PathBuf::from("textures/ui")
+            ]
+        );
+    }
+
+    #[test]
+    fn load_from_base() {
+        use crate::FileAssetIo;
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.txt"), "loose a").unwrap();
+        std::fs::write(dir.path().join("b.txt"), "loose b").unwrap();
+        let mut asset_io = ZipAssetIo::new().with_base(FileAssetIo::new(dir.path(), false));
+        asset_io
+            .add_archive_from_reader(create_archive(&[("a.txt", "a"), ("c.txt", "c")]))
+            .unwrap();
+
+        assert_eq!(load(&asset_io, "a.txt").unwrap(), "a");
+        assert_eq!(load(&asset_io, "b.txt").unwrap(), "loose b");
+        let children: Vec<_> = asset_io.read_directory(Path::new("")).unwrap().collect();
+        assert_eq!(
+            children,
+            vec![
+                PathBuf::from("a.txt"),
+                PathBuf::from("b.txt"),
+                PathBuf::from("c.txt")
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_archive() {
+        let mut asset_io = ZipAssetIo::new();
+        let err = asset_io
+            .add_archive_from_reader(Cursor::new(b"not a zip".to_vec()))
+            .unwrap_err();
+        assert!(matches!(err, AssetIoError::Io(_)));
+        assert!(matches!(
+            asset_io.add_archive("not/an/archive.zip"),
+            Err(AssetIoError::NotFound(_))
+        ));
+    }
+}
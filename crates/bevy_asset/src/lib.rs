@@ -12,7 +12,14 @@ mod handle;
 mod info;
 mod io;
 mod loader;
+mod meta;
 mod path;
+#[cfg(all(
+    feature = "asset_processor",
+    all(not(target_arch = "wasm32"), not(target_os = "android"))
+))]
+mod processor;
+mod saver;
 
 pub mod prelude {
     #[doc(hidden)]
@@ -26,7 +33,14 @@ pub use handle::*;
 pub use info::*;
 pub use io::*;
 pub use loader::*;
+pub use meta::{get_meta_path, AssetMetaFile, META_FILE_EXTENSION};
 pub use path::*;
+#[cfg(all(
+    feature = "asset_processor",
+    all(not(target_arch = "wasm32"), not(target_os = "android"))
+))]
+pub use processor::*;
+pub use saver::AssetSaver;
 
 use bevy_app::{prelude::Plugin, App};
 use bevy_ecs::schedule::{StageLabel, SystemStage};
@@ -44,11 +58,31 @@ pub enum AssetStage {
 #[derive(Default)]
 pub struct AssetPlugin;
 
+/// Where the [`AssetServer`] loads assets from.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AssetMode {
+    /// Loads the assets of the asset folder as they are.
+    #[default]
+    Unprocessed,
+    /// Loads the pre-processed assets of the processed asset folder. Cannot be supported on the
+    /// wasm32 arch nor android os.
+    Processed,
+    /// Processes the assets of the asset folder into the processed asset folder when the app
+    /// starts, and loads them from there. Requires the `asset_processor` feature, and cannot be
+    /// supported on the wasm32 arch nor android os.
+    ///
+    /// Modified assets are processed again when watching for changes.
+    Processor,
+}
+
 pub struct AssetServerSettings {
     pub asset_folder: String,
     /// Whether to watch for changes in asset files. Requires the `filesystem_watcher` feature,
     /// and cannot be supported on the wasm32 arch nor android os.
     pub watch_for_changes: bool,
+    pub mode: AssetMode,
+    /// The folder the asset processor writes to, when not using [`AssetMode::Unprocessed`].
+    pub processed_asset_folder: String,
 }
 
 impl Default for AssetServerSettings {
@@ -56,6 +90,8 @@ impl Default for AssetServerSettings {
         Self {
             asset_folder: "assets".to_string(),
             watch_for_changes: false,
+            mode: AssetMode::default(),
+            processed_asset_folder: "imported_assets".to_string(),
         }
     }
 }
@@ -69,6 +105,11 @@ pub fn create_platform_default_asset_io(app: &mut App) -> Box<dyn AssetIo> {
         .world
         .get_resource_or_insert_with(AssetServerSettings::default);
 
+    #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+    if settings.mode != AssetMode::Unprocessed {
+        return Box::new(ProcessedAssetIo::new(&settings.processed_asset_folder));
+    }
+
     #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
     let source = FileAssetIo::new(&settings.asset_folder, settings.watch_for_changes);
     #[cfg(target_arch = "wasm32")]
@@ -79,6 +120,37 @@ pub fn create_platform_default_asset_io(app: &mut App) -> Box<dyn AssetIo> {
     Box::new(source)
 }
 
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+#[cfg_attr(not(feature = "asset_processor"), allow(unused_variables))]
+fn create_asset_processor(app: &mut App, asset_io: &dyn AssetIo) {
+    let settings = app.world.resource::<AssetServerSettings>();
+    if settings.mode != AssetMode::Processor {
+        return;
+    }
+    #[cfg(not(feature = "asset_processor"))]
+    panic!("AssetMode::Processor requires the asset_processor feature");
+
+    #[cfg(feature = "asset_processor")]
+    {
+        let processed_io = asset_io
+            .downcast_ref::<ProcessedAssetIo>()
+            .expect("AssetMode::Processor requires a ProcessedAssetIo-backed AssetServer");
+        let source_io = FileAssetIo::new(&settings.asset_folder, settings.watch_for_changes);
+        if settings.watch_for_changes {
+            source_io
+                .watch_path_for_changes(std::path::Path::new(""))
+                .unwrap();
+        }
+        let processor = AssetProcessorServer::new(
+            source_io,
+            processed_io.root_path(),
+            processed_io.index().clone(),
+            app.world.resource::<IoTaskPool>().0.clone(),
+        );
+        app.insert_resource(processor);
+    }
+}
+
 impl Plugin for AssetPlugin {
     fn build(&self, app: &mut App) {
         if !app.world.contains_resource::<AssetServer>() {
@@ -86,11 +158,32 @@ impl Plugin for AssetPlugin {
 
             let source = create_platform_default_asset_io(app);
 
+            #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+            create_asset_processor(app, &*source);
+
             let asset_server = AssetServer::with_boxed_io(source, task_pool);
+            if app
+                .world
+                .resource::<AssetServerSettings>()
+                .watch_for_changes
+            {
+                asset_server
+                    .server
+                    .watching_for_changes
+                    .store(true, std::sync::atomic::Ordering::Relaxed);
+            }
 
             app.insert_resource(asset_server);
         }
 
+        let asset_server = app.world.resource::<AssetServer>();
+        if asset_server
+            .get_source_io(Some(EMBEDDED_ASSET_SOURCE))
+            .is_err()
+        {
+            asset_server.add_source(EMBEDDED_ASSET_SOURCE, EmbeddedAssetIo::new());
+        }
+
         app.add_stage_before(
             bevy_app::CoreStage::PreUpdate,
             AssetStage::LoadAssets,
@@ -101,10 +194,15 @@ impl Plugin for AssetPlugin {
             AssetStage::AssetEvents,
             SystemStage::parallel(),
         )
+        .add_event::<AssetLoadFailedEvent>()
         .register_type::<HandleId>()
         .add_system_to_stage(
             bevy_app::CoreStage::PreUpdate,
             asset_server::free_unused_assets_system,
+        )
+        .add_system_to_stage(
+            AssetStage::LoadAssets,
+            asset_server::asset_load_failed_events_system,
         );
 
         #[cfg(all(
@@ -112,5 +210,44 @@ impl Plugin for AssetPlugin {
             all(not(target_arch = "wasm32"), not(target_os = "android"))
         ))]
         app.add_system_to_stage(AssetStage::LoadAssets, io::filesystem_watcher_system);
+
+        #[cfg(all(
+            feature = "asset_processor",
+            feature = "filesystem_watcher",
+            all(not(target_arch = "wasm32"), not(target_os = "android"))
+        ))]
+        if app.world.contains_resource::<AssetProcessorServer>() {
+            app.add_system_to_stage(AssetStage::LoadAssets, processor::asset_processor_system);
+        }
+    }
+
+    #[cfg(all(
+        feature = "asset_processor",
+        all(not(target_arch = "wasm32"), not(target_os = "android"))
+    ))]
+    fn finish(&self, app: &mut App) {
+        if let Some(processor) = app.world.get_resource::<AssetProcessorServer>() {
+            // process on the IO task pool without blocking startup, loads wait for it
+            let processing = app
+                .world
+                .resource::<AssetServer>()
+                .asset_io()
+                .downcast_ref::<ProcessedAssetIo>()
+                .map(|processed_io| processed_io.pause_loads());
+            let processor = processor.clone();
+            app.world
+                .resource::<IoTaskPool>()
+                .spawn(async move {
+                    if let Err(err) = processor.process_all().await {
+                        bevy_log::error!("{}", err);
+                    }
+                    drop(processing);
+                })
+                .detach();
+        }
+    }
+
+    fn is_unique(&self) -> bool {
+        true
     }
 }
@@ -1,26 +1,124 @@
 use crate::{
-    path::AssetPath, AssetIo, AssetIoError, AssetMeta, AssetServer, Assets, Handle, HandleId,
-    RefChangeChannel,
+    path::AssetPath, AssetIo, AssetIoError, AssetMeta, AssetMetaFile, AssetServer,
+    AssetServerError, Assets, Handle, HandleId, Reader, RefChangeChannel,
 };
 use anyhow::Result;
 use bevy_ecs::system::{Res, ResMut};
-use bevy_reflect::{TypeUuid, TypeUuidDynamic};
+use bevy_reflect::{Reflect, TypeUuid, TypeUuidDynamic};
 use bevy_tasks::TaskPool;
 use bevy_utils::{BoxedFuture, HashMap};
 use crossbeam_channel::{Receiver, Sender};
 use downcast_rs::{impl_downcast, Downcast};
-use std::path::Path;
+use futures_lite::AsyncReadExt;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{path::Path, sync::Arc};
 
 /// A loader for an asset source
 pub trait AssetLoader: Send + Sync + 'static {
+    /// The settings of this loader, read from the `.meta` file of the asset being loaded, or
+    /// passed to [`AssetServer::load_with_settings`].
+    type Settings: AssetLoaderSettings;
     fn load<'a>(
         &'a self,
         bytes: &'a [u8],
+        settings: &'a Self::Settings,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<(), anyhow::Error>>;
     fn extensions(&self) -> &[&str];
 }
 
+/// A loader for an asset source, reading the asset with a [`Reader`] instead of getting its
+/// bytes all at once. Useful for large assets, or for assets that are only partially read.
+///
+/// It is implemented for every [`AssetLoader`], by reading the whole asset before loading it.
+/// Its methods are named apart from those of [`AssetLoader`] so that calling them on a loader
+/// is not ambiguous.
+pub trait ReaderAssetLoader: Send + Sync + 'static {
+    /// The settings of this loader, read from the `.meta` file of the asset being loaded, or
+    /// passed to [`AssetServer::load_with_settings`].
+    type Settings: AssetLoaderSettings;
+    fn load_from_reader<'a>(
+        &'a self,
+        reader: &'a mut dyn Reader,
+        settings: &'a Self::Settings,
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>>;
+    fn reader_extensions(&self) -> &[&str];
+}
+
+impl<L: AssetLoader> ReaderAssetLoader for L {
+    type Settings = L::Settings;
+
+    fn load_from_reader<'a>(
+        &'a self,
+        reader: &'a mut dyn Reader,
+        settings: &'a Self::Settings,
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            <L as AssetLoader>::load(self, &bytes, settings, load_context).await
+        })
+    }
+
+    fn reader_extensions(&self) -> &[&str] {
+        <L as AssetLoader>::extensions(self)
+    }
+}
+
+/// The settings of an [`AssetLoader`]. Loaders without settings use `()`.
+pub trait AssetLoaderSettings: Reflect + Serialize + DeserializeOwned + Default {}
+
+impl<T> AssetLoaderSettings for T where T: Reflect + Serialize + DeserializeOwned + Default {}
+
+/// An object-safe version of [`ReaderAssetLoader`], implemented for every loader.
+pub trait ErasedAssetLoader: Send + Sync + 'static {
+    fn load_erased<'a>(
+        &'a self,
+        reader: &'a mut dyn Reader,
+        settings: &'a dyn Reflect,
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>>;
+    fn erased_extensions(&self) -> &[&str];
+    /// The type name of the loader, used to pick it in `.meta` files.
+    fn type_name(&self) -> &'static str;
+    fn default_settings(&self) -> Box<dyn Reflect>;
+    /// Reads the settings from the contents of an [`AssetMetaFile`](crate::AssetMetaFile).
+    fn deserialize_settings(&self, meta: &[u8]) -> Result<Box<dyn Reflect>, ron::Error>;
+}
+
+impl<L: ReaderAssetLoader> ErasedAssetLoader for L {
+    fn load_erased<'a>(
+        &'a self,
+        reader: &'a mut dyn Reader,
+        settings: &'a dyn Reflect,
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        let settings = settings
+            .downcast_ref::<L::Settings>()
+            .expect("the settings should have the type of the loader settings");
+        self.load_from_reader(reader, settings, load_context)
+    }
+
+    fn erased_extensions(&self) -> &[&str] {
+        self.reader_extensions()
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<L>()
+    }
+
+    fn default_settings(&self) -> Box<dyn Reflect> {
+        Box::new(L::Settings::default())
+    }
+
+    fn deserialize_settings(&self, meta: &[u8]) -> Result<Box<dyn Reflect>, ron::Error> {
+        let meta: AssetMetaFile<L::Settings> = ron::de::from_bytes(meta)?;
+        Ok(Box::new(meta.settings))
+    }
+}
+
 pub trait Asset: TypeUuid + AssetDynamic {}
 
 pub trait AssetDynamic: Downcast + TypeUuidDynamic + Send + Sync + 'static {}
@@ -79,29 +177,37 @@ impl<T: Asset> From<LoadedAsset<T>> for BoxedLoadedAsset {
 }
 
 pub struct LoadContext<'a> {
+    pub(crate) asset_server: &'a AssetServer,
+    pub(crate) source: Option<&'a str>,
     pub(crate) ref_change_channel: &'a RefChangeChannel,
     pub(crate) asset_io: &'a dyn AssetIo,
     pub(crate) labeled_assets: HashMap<Option<String>, BoxedLoadedAsset>,
     pub(crate) path: &'a Path,
     pub(crate) version: usize,
     pub(crate) task_pool: &'a TaskPool,
+    /// The assets being loaded directly by the loaders of this asset, ending with this asset.
+    pub(crate) load_stack: Vec<AssetPath<'static>>,
 }
 
 impl<'a> LoadContext<'a> {
     pub(crate) fn new(
+        asset_server: &'a AssetServer,
+        source: Option<&'a str>,
         path: &'a Path,
-        ref_change_channel: &'a RefChangeChannel,
         asset_io: &'a dyn AssetIo,
         version: usize,
-        task_pool: &'a TaskPool,
+        load_stack: Vec<AssetPath<'static>>,
     ) -> Self {
         Self {
-            ref_change_channel,
+            asset_server,
+            source,
+            ref_change_channel: &asset_server.server.asset_ref_counter.channel,
             asset_io,
             labeled_assets: Default::default(),
             version,
             path,
-            task_pool,
+            task_pool: &asset_server.server.task_pool,
+            load_stack,
         }
     }
 
@@ -109,25 +215,89 @@ impl<'a> LoadContext<'a> {
         self.path
     }
 
+    /// The name of the asset source of the asset being loaded, or `None` for the default
+    /// asset source.
+    pub fn source(&self) -> Option<&str> {
+        self.source
+    }
+
+    /// The [`AssetPath`] of the asset being loaded, including its asset source.
+    pub fn asset_path(&self) -> AssetPath<'a> {
+        let asset_path = AssetPath::new_ref(self.path, None);
+        match self.source {
+            Some(source) => asset_path.with_source(source),
+            None => asset_path,
+        }
+    }
+
     pub fn has_labeled_asset(&self, label: &str) -> bool {
         self.labeled_assets.contains_key(&Some(label.to_string()))
     }
 
+    /// Returns `path` in the asset source of the asset being loaded, unless it names a source.
+    fn resolve_source(&self, path: AssetPath<'static>) -> AssetPath<'static> {
+        match self.source {
+            Some(source) if path.source().is_none() => path.with_source(source.to_string()),
+            _ => path,
+        }
+    }
+
+    fn resolve_dependencies<T: Asset>(&self, mut asset: LoadedAsset<T>) -> BoxedLoadedAsset {
+        asset.dependencies = asset
+            .dependencies
+            .into_iter()
+            .map(|dependency| self.resolve_source(dependency))
+            .collect();
+        asset.into()
+    }
+
+    /// Sets the asset loaded from the file. Dependencies without an asset source are loaded from
+    /// the source of this asset.
     pub fn set_default_asset<T: Asset>(&mut self, asset: LoadedAsset<T>) {
-        self.labeled_assets.insert(None, asset.into());
+        let asset = self.resolve_dependencies(asset);
+        self.labeled_assets.insert(None, asset);
     }
 
+    /// Sets an asset loaded from the file under `label`. Dependencies without an asset source are
+    /// loaded from the source of this asset.
     pub fn set_labeled_asset<T: Asset>(&mut self, label: &str, asset: LoadedAsset<T>) -> Handle<T> {
         assert!(!label.is_empty());
-        self.labeled_assets
-            .insert(Some(label.to_string()), asset.into());
-        self.get_handle(AssetPath::new_ref(self.path(), Some(label)))
+        let asset = self.resolve_dependencies(asset);
+        self.labeled_assets.insert(Some(label.to_string()), asset);
+        self.get_handle(self.asset_path().with_label(label))
     }
 
     pub fn get_handle<I: Into<HandleId>, T: Asset>(&self, id: I) -> Handle<T> {
         Handle::strong(id.into(), self.ref_change_channel.sender.clone())
     }
 
+    /// Loads the asset at `path` with its loader and returns its value, for assets computed from
+    /// other assets at load time.
+    ///
+    /// The value is not added to the [`Assets`] collection, and a value loaded directly by
+    /// another loader is reused while it is alive. Loading an asset that is already being loaded
+    /// by this chain of loaders fails with [`AssetServerError::CyclicLoad`].
+    ///
+    /// Paths without an asset source are loaded from the source of this asset. This asset is
+    /// reloaded when the file of the loaded asset changes.
+    pub async fn load_direct<'b, T: Asset, P: Into<AssetPath<'b>>>(
+        &self,
+        path: P,
+    ) -> Result<Arc<T>, AssetServerError> {
+        let asset_path = self.resolve_source(path.into().to_owned());
+        let source_path_id = asset_path.get_id().source_path_id();
+        if self
+            .load_stack
+            .iter()
+            .any(|loading| loading.get_id().source_path_id() == source_path_id)
+        {
+            return Err(AssetServerError::CyclicLoad(asset_path));
+        }
+        self.asset_server
+            .load_direct(asset_path, self.load_stack.clone())
+            .await
+    }
+
     pub async fn read_asset_bytes<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>, AssetIoError> {
         self.asset_io.load_path(path.as_ref()).await
     }
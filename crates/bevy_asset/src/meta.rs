@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// The extension of the `.meta` files stored next to the assets they configure.
+pub const META_FILE_EXTENSION: &str = "meta";
+
+/// The contents of the `.meta` file of an asset, such as `image.png.meta` for `image.png`.
+///
+/// ```ron
+/// (
+///     loader: Some("bevy_render::texture::image_texture_loader::ImageTextureLoader"),
+///     settings: (
+///         is_srgb: false,
+///         sampler: Nearest,
+///     ),
+/// )
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AssetMetaFile<S> {
+    /// The type name of the [`AssetLoader`](crate::AssetLoader) loading the asset. When
+    /// `None`, the loader is picked from the extension of the asset.
+    #[serde(default)]
+    pub loader: Option<String>,
+    /// The [`AssetLoader::Settings`](crate::AssetLoader::Settings) used to load the asset.
+    #[serde(default)]
+    pub settings: S,
+}
+
+/// The part of an [`AssetMetaFile`] read before the loader of the asset is known.
+#[derive(Deserialize)]
+pub(crate) struct AssetLoaderMeta {
+    #[serde(default)]
+    pub(crate) loader: Option<String>,
+}
+
+/// Returns the path of the `.meta` file of the asset at `path`.
+pub fn get_meta_path(path: &Path) -> PathBuf {
+    let mut meta_path = path.as_os_str().to_owned();
+    meta_path.push(".");
+    meta_path.push(META_FILE_EXTENSION);
+    PathBuf::from(meta_path)
+}
@@ -7,8 +7,17 @@ use std::{
     path::{Path, PathBuf},
 };
 
+/// The path of an asset, such as `"models/tree.gltf#Mesh0"`.
+///
+/// Assets of a named asset source, registered with [`AssetServer::add_source`], are addressed
+/// with a source prefix, such as `"mods://weapons/sword.gltf"`. Paths without a prefix
+/// refer to the default asset source.
+///
+/// [`AssetServer::add_source`]: crate::AssetServer::add_source
 #[derive(Debug, Hash, Clone, Serialize, Deserialize)]
 pub struct AssetPath<'a> {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source: Option<Cow<'a, str>>,
     path: Cow<'a, Path>,
     label: Option<Cow<'a, str>>,
 }
@@ -17,6 +26,7 @@ impl<'a> AssetPath<'a> {
     #[inline]
     pub fn new_ref(path: &'a Path, label: Option<&'a str>) -> AssetPath<'a> {
         AssetPath {
+            source: None,
             path: Cow::Borrowed(path),
             label: label.map(Cow::Borrowed),
         }
@@ -25,11 +35,34 @@ impl<'a> AssetPath<'a> {
     #[inline]
     pub fn new(path: PathBuf, label: Option<String>) -> AssetPath<'a> {
         AssetPath {
+            source: None,
             path: Cow::Owned(path),
             label: label.map(Cow::Owned),
         }
     }
 
+    /// Returns this path in the named asset source `source`.
+    #[inline]
+    #[must_use]
+    pub fn with_source(mut self, source: impl Into<Cow<'a, str>>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Returns this path with the label `label`.
+    #[inline]
+    #[must_use]
+    pub fn with_label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// The name of the asset source of this path, or `None` for the default asset source.
+    #[inline]
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
     #[inline]
     pub fn get_id(&self) -> AssetPathId {
         AssetPathId::from(self)
@@ -48,6 +81,10 @@ impl<'a> AssetPath<'a> {
     #[inline]
     pub fn to_owned(&self) -> AssetPath<'static> {
         AssetPath {
+            source: self
+                .source
+                .as_ref()
+                .map(|value| Cow::Owned(value.to_string())),
             path: Cow::Owned(self.path.to_path_buf()),
             label: self
                 .label
@@ -83,6 +120,22 @@ impl<'a> From<&'a Path> for SourcePathId {
     }
 }
 
+impl SourcePathId {
+    /// The id of the asset at `path` in the asset source named `source`.
+    pub fn new(source: Option<&str>, path: &Path) -> Self {
+        match source {
+            Some(source) => {
+                let mut hasher = get_hasher();
+                source.hash(&mut hasher);
+                path.hash(&mut hasher);
+                SourcePathId(hasher.finish())
+            }
+            // ids of the default asset source only depend on the path
+            None => SourcePathId::from(path),
+        }
+    }
+}
+
 impl From<AssetPathId> for SourcePathId {
     fn from(id: AssetPathId) -> Self {
         id.source_path_id()
@@ -124,17 +177,14 @@ where
 {
     fn from(value: T) -> Self {
         let asset_path: AssetPath = value.into();
-        AssetPathId(
-            SourcePathId::from(asset_path.path()),
-            LabelId::from(asset_path.label()),
-        )
+        AssetPathId::from(&asset_path)
     }
 }
 
 impl<'a, 'b> From<&'a AssetPath<'b>> for AssetPathId {
     fn from(asset_path: &'a AssetPath<'b>) -> Self {
         AssetPathId(
-            SourcePathId::from(asset_path.path()),
+            SourcePathId::new(asset_path.source(), asset_path.path()),
             LabelId::from(asset_path.label()),
         )
     }
@@ -142,10 +192,15 @@ impl<'a, 'b> From<&'a AssetPath<'b>> for AssetPathId {
 
 impl<'a> From<&'a str> for AssetPath<'a> {
     fn from(asset_path: &'a str) -> Self {
+        let (source, asset_path) = match asset_path.split_once("://") {
+            Some((source, asset_path)) => (Some(source), asset_path),
+            None => (None, asset_path),
+        };
         let mut parts = asset_path.split('#');
         let path = Path::new(parts.next().expect("Path must be set."));
         let label = parts.next();
         AssetPath {
+            source: source.map(Cow::Borrowed),
             path: Cow::Borrowed(path),
             label: label.map(Cow::Borrowed),
         }
@@ -161,6 +216,7 @@ impl<'a> From<&'a String> for AssetPath<'a> {
 impl<'a> From<&'a Path> for AssetPath<'a> {
     fn from(path: &'a Path) -> Self {
         AssetPath {
+            source: None,
             path: Cow::Borrowed(path),
             label: None,
         }
@@ -170,8 +226,45 @@ impl<'a> From<&'a Path> for AssetPath<'a> {
 impl<'a> From<PathBuf> for AssetPath<'a> {
     fn from(path: PathBuf) -> Self {
         AssetPath {
+            source: None,
             path: Cow::Owned(path),
             label: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_asset_path() {
+        let path = AssetPath::from("models/tree.gltf#Mesh0");
+        assert_eq!(path.source(), None);
+        assert_eq!(path.path(), Path::new("models/tree.gltf"));
+        assert_eq!(path.label(), Some("Mesh0"));
+
+        let path = AssetPath::from("mods://weapons/sword.gltf#Mesh0");
+        assert_eq!(path.source(), Some("mods"));
+        assert_eq!(path.path(), Path::new("weapons/sword.gltf"));
+        assert_eq!(path.label(), Some("Mesh0"));
+    }
+
+    #[test]
+    fn source_path_ids() {
+        let default = AssetPath::from("weapons/sword.gltf");
+        let mods = AssetPath::from("mods://weapons/sword.gltf");
+        assert_eq!(
+            default.get_id().source_path_id(),
+            SourcePathId::from(Path::new("weapons/sword.gltf"))
+        );
+        assert_ne!(default.get_id(), mods.get_id());
+        assert_eq!(
+            mods.get_id(),
+            AssetPath::new_ref(Path::new("weapons/sword.gltf"), None)
+                .with_source("mods")
+                .get_id()
+        );
+        assert_eq!(mods.to_owned().get_id(), mods.get_id());
+    }
+}
@@ -0,0 +1,800 @@
+use crate::{
+    AssetIo, AssetIoError, FileAssetIo, ProcessedAssetIndex, ProcessedAssetInfo,
+    PROCESSED_ASSET_INDEX_FILE,
+};
+#[cfg(feature = "filesystem_watcher")]
+use crate::{AssetPath, AssetServer, LoadState};
+use anyhow::Result;
+#[cfg(feature = "filesystem_watcher")]
+use bevy_ecs::system::Res;
+use bevy_log::warn;
+use bevy_tasks::{Task, TaskPool};
+use bevy_utils::{BoxedFuture, Entry, HashMap, HashSet};
+use parking_lot::{Mutex, RwLock};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use thiserror::Error;
+
+/// Errors that occur while processing assets with an [`AssetProcessorServer`]
+#[derive(Error, Debug)]
+pub enum AssetProcessorError {
+    #[error("encountered an error while processing {path:?}: {error}")]
+    ProcessorError { path: PathBuf, error: anyhow::Error },
+    #[error("encountered an error while reading or writing an asset: {0}")]
+    AssetIoError(#[from] AssetIoError),
+}
+
+/// Turns the bytes of a source asset into the bytes of a pre-processed asset, such as a
+/// compressed texture, which is written to the processed asset folder.
+pub trait AssetProcessor: Send + Sync + 'static {
+    fn process<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        process_context: &'a mut ProcessContext,
+    ) -> BoxedFuture<'a, Result<Vec<u8>, anyhow::Error>>;
+    /// The extensions of the source assets this processor handles.
+    fn extensions(&self) -> &[&str];
+    /// The extension of the processed assets, used to pick the `AssetLoader` loading them.
+    fn output_extension(&self) -> &str;
+    /// Changing the version reprocesses the assets processed by a previous version.
+    fn version(&self) -> u32 {
+        0
+    }
+}
+
+pub struct ProcessContext<'a> {
+    pub(crate) path: &'a Path,
+    pub(crate) asset_io: &'a dyn AssetIo,
+    pub(crate) dependencies: Vec<(PathBuf, blake3::Hash)>,
+}
+
+impl<'a> ProcessContext<'a> {
+    pub fn path(&self) -> &Path {
+        self.path
+    }
+
+    /// Reads the bytes of another source asset. The asset becomes a dependency of the processed
+    /// asset, which is processed again when the dependency changes.
+    pub async fn read_asset_bytes<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<Vec<u8>, AssetIoError> {
+        let path = path.as_ref();
+        let bytes = self.asset_io.load_path(path).await?;
+        self.dependencies
+            .push((path.to_owned(), blake3::hash(&bytes)));
+        Ok(bytes)
+    }
+
+    pub fn asset_io(&self) -> &dyn AssetIo {
+        self.asset_io
+    }
+}
+
+/// What [`AssetProcessorServer::process_path`] did with an asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessStatus {
+    /// A new output was written to the processed asset folder.
+    Processed,
+    /// The output of a previous run is still up to date.
+    UpToDate,
+}
+
+struct RegisteredProcessor {
+    name: &'static str,
+    processor: Arc<dyn AssetProcessor>,
+}
+
+pub struct AssetProcessorServerInternal {
+    source_io: FileAssetIo,
+    destination: PathBuf,
+    processors: RwLock<Vec<Arc<RegisteredProcessor>>>,
+    extension_to_processor_index: RwLock<HashMap<String, usize>>,
+    index: Arc<RwLock<ProcessedAssetIndex>>,
+    write_index_lock: Mutex<()>,
+    /// The assets being processed, and whether they changed again since they started.
+    in_flight: Mutex<HashMap<PathBuf, bool>>,
+    task_pool: TaskPool,
+}
+
+/// Processes the assets of a source folder with the registered [`AssetProcessor`]s, writing
+/// the outputs to a processed asset folder.
+///
+/// Outputs are named after the hash of their source and processor, so unchanged assets are not
+/// processed again. Assets without a processor are copied as is.
+#[derive(Clone)]
+pub struct AssetProcessorServer {
+    pub(crate) server: Arc<AssetProcessorServerInternal>,
+}
+
+impl AssetProcessorServer {
+    pub fn new<P: AsRef<Path>>(
+        source_io: FileAssetIo,
+        destination: P,
+        index: Arc<RwLock<ProcessedAssetIndex>>,
+        task_pool: TaskPool,
+    ) -> Self {
+        AssetProcessorServer {
+            server: Arc::new(AssetProcessorServerInternal {
+                source_io,
+                destination: destination.as_ref().to_owned(),
+                processors: Default::default(),
+                extension_to_processor_index: Default::default(),
+                index,
+                write_index_lock: Default::default(),
+                in_flight: Default::default(),
+                task_pool,
+            }),
+        }
+    }
+
+    pub fn source_io(&self) -> &FileAssetIo {
+        &self.server.source_io
+    }
+
+    pub fn destination(&self) -> &Path {
+        &self.server.destination
+    }
+
+    pub fn add_processor<T>(&self, processor: T)
+    where
+        T: AssetProcessor,
+    {
+        let mut processors = self.server.processors.write();
+        let processor_index = processors.len();
+        for extension in processor.extensions().iter() {
+            self.server
+                .extension_to_processor_index
+                .write()
+                .insert(extension.to_string(), processor_index);
+        }
+        processors.push(Arc::new(RegisteredProcessor {
+            name: std::any::type_name::<T>(),
+            processor: Arc::new(processor),
+        }));
+    }
+
+    fn get_path_processor(&self, path: &Path) -> Option<Arc<RegisteredProcessor>> {
+        let file_name = path.file_name()?.to_str()?.to_lowercase();
+        let map = self.server.extension_to_processor_index.read();
+        let mut ext = file_name.as_str();
+        while let Some(idx) = ext.find('.') {
+            ext = &ext[idx + 1..];
+            if let Some(&index) = map.get(ext) {
+                return Some(self.server.processors.read()[index].clone());
+            }
+        }
+        None
+    }
+
+    fn hash(
+        processor: Option<&RegisteredProcessor>,
+        source_hash: &blake3::Hash,
+        dependencies: &[(PathBuf, blake3::Hash)],
+    ) -> String {
+        let mut hasher = blake3::Hasher::new();
+        if let Some(processor) = processor {
+            hasher.update(processor.name.as_bytes());
+            hasher.update(&processor.processor.version().to_le_bytes());
+        }
+        hasher.update(source_hash.as_bytes());
+        for (path, hash) in dependencies {
+            hasher.update(path.to_string_lossy().as_bytes());
+            hasher.update(hash.as_bytes());
+        }
+        hasher.finalize().to_hex().to_string()
+    }
+
+    /// Hashes the current content of the given dependencies, or returns `None` if one of them
+    /// can't be read anymore.
+    async fn hash_dependencies(
+        &self,
+        dependencies: Vec<PathBuf>,
+    ) -> Option<Vec<(PathBuf, blake3::Hash)>> {
+        let mut hashes = Vec::with_capacity(dependencies.len());
+        for dependency in dependencies {
+            let bytes = self.server.source_io.load_path(&dependency).await.ok()?;
+            hashes.push((dependency, blake3::hash(&bytes)));
+        }
+        Some(hashes)
+    }
+
+    /// Processes the asset at `path`, unless its output is already up to date.
+    pub async fn process_path(&self, path: &Path) -> Result<ProcessStatus, AssetProcessorError> {
+        let bytes = self.server.source_io.load_path(path).await?;
+        let processor = self.get_path_processor(path);
+
+        let source_hash = blake3::hash(&bytes);
+
+        let previous = self.server.index.read().assets.get(path).cloned();
+        if let Some(info) = previous {
+            if self.server.destination.join(&info.output).is_file() {
+                if let Some(dependencies) = self.hash_dependencies(info.dependencies).await {
+                    let hash = Self::hash(processor.as_deref(), &source_hash, &dependencies);
+                    if info.hash == hash {
+                        return Ok(ProcessStatus::UpToDate);
+                    }
+                }
+            }
+        }
+
+        let (output, extension, dependencies) = match &processor {
+            Some(processor) => {
+                let mut process_context = ProcessContext {
+                    path,
+                    asset_io: &self.server.source_io,
+                    dependencies: Vec::new(),
+                };
+                let output = processor
+                    .processor
+                    .process(&bytes, &mut process_context)
+                    .await
+                    .map_err(|error| AssetProcessorError::ProcessorError {
+                        path: path.to_owned(),
+                        error,
+                    })?;
+                (
+                    output,
+                    processor.processor.output_extension().to_string(),
+                    process_context.dependencies,
+                )
+            }
+            None => {
+                let file_name = path.file_name().and_then(|name| name.to_str());
+                let extension = file_name
+                    .and_then(|name| name.split_once('.'))
+                    .map(|(_, extension)| extension.to_string())
+                    .unwrap_or_default();
+                (bytes, extension, Vec::new())
+            }
+        };
+        let hash = Self::hash(processor.as_deref(), &source_hash, &dependencies);
+
+        let output_path = if extension.is_empty() {
+            PathBuf::from(&hash)
+        } else {
+            PathBuf::from(format!("{}.{}", hash, extension))
+        };
+        fs::create_dir_all(&self.server.destination).map_err(AssetIoError::from)?;
+        fs::write(self.server.destination.join(&output_path), output)
+            .map_err(AssetIoError::from)?;
+
+        let previous = self.server.index.write().assets.insert(
+            path.to_owned(),
+            ProcessedAssetInfo {
+                hash,
+                output: output_path,
+                dependencies: dependencies.into_iter().map(|(path, _)| path).collect(),
+            },
+        );
+        if let Some(previous) = previous {
+            self.remove_unused_output(&previous.output);
+        }
+        Ok(ProcessStatus::Processed)
+    }
+
+    /// Processes the asset at `path` on the task pool, then calls `on_processed` with the
+    /// result.
+    ///
+    /// An asset is never processed twice at once: if it is already being processed, `None` is
+    /// returned and it is processed again by the running task once it is done.
+    pub fn queue_path<F>(&self, path: PathBuf, on_processed: F) -> Option<Task<()>>
+    where
+        F: Fn(&Path, Result<ProcessStatus, AssetProcessorError>) + Send + Sync + 'static,
+    {
+        match self.server.in_flight.lock().entry(path.clone()) {
+            Entry::Occupied(mut entry) => {
+                *entry.get_mut() = true;
+                return None;
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(false);
+            }
+        }
+
+        let processor = self.clone();
+        Some(self.server.task_pool.spawn(async move {
+            loop {
+                on_processed(&path, processor.process_path(&path).await);
+
+                let mut in_flight = processor.server.in_flight.lock();
+                if in_flight.get(&path) == Some(&true) {
+                    in_flight.insert(path.clone(), false);
+                } else {
+                    in_flight.remove(&path);
+                    break;
+                }
+            }
+        }))
+    }
+
+    /// Processes every asset of the source folder, then removes the outputs of the assets that
+    /// no longer exist.
+    ///
+    /// Returns the paths of the assets that were processed again.
+    pub async fn process_all(&self) -> Result<Vec<PathBuf>, AssetProcessorError> {
+        let mut paths = Vec::new();
+        self.collect_paths(Path::new(""), &mut paths)?;
+
+        let processed = Arc::new(Mutex::new(Vec::new()));
+        let tasks = paths
+            .iter()
+            .filter_map(|path| {
+                let processed = processed.clone();
+                self.queue_path(path.clone(), move |path, result| match result {
+                    Ok(ProcessStatus::Processed) => processed.lock().push(path.to_owned()),
+                    Ok(ProcessStatus::UpToDate) => {}
+                    Err(err) => warn!("{}", err),
+                })
+            })
+            .collect::<Vec<_>>();
+        for task in tasks {
+            task.await;
+        }
+
+        let paths = paths.into_iter().collect::<HashSet<_>>();
+        let removed = self
+            .server
+            .index
+            .read()
+            .assets
+            .keys()
+            .filter(|source| !paths.contains(*source))
+            .cloned()
+            .collect::<Vec<_>>();
+        for source in removed {
+            self.remove_path(&source);
+        }
+
+        self.write_index()?;
+        let processed = std::mem::take(&mut *processed.lock());
+        Ok(processed)
+    }
+
+    fn collect_paths(&self, path: &Path, paths: &mut Vec<PathBuf>) -> Result<(), AssetIoError> {
+        for child_path in self.server.source_io.read_directory(path)? {
+            if self.server.source_io.is_dir(&child_path) {
+                self.collect_paths(&child_path, paths)?;
+            } else {
+                paths.push(child_path);
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the processed outputs of the source asset at `path`, or of the assets in the
+    /// folder at `path`.
+    pub fn remove_path(&self, path: &Path) {
+        let removed = {
+            let mut index = self.server.index.write();
+            let sources = index
+                .assets
+                .keys()
+                .filter(|source| source.starts_with(path))
+                .cloned()
+                .collect::<Vec<_>>();
+            sources
+                .into_iter()
+                .filter_map(|source| index.assets.remove(&source))
+                .collect::<Vec<_>>()
+        };
+        for info in removed {
+            self.remove_unused_output(&info.output);
+        }
+    }
+
+    /// Returns the processed assets that read one of the given source assets while processed.
+    fn dependents(&self, paths: &HashSet<PathBuf>) -> Vec<PathBuf> {
+        self.server
+            .index
+            .read()
+            .assets
+            .iter()
+            .filter(|(_, info)| info.dependencies.iter().any(|path| paths.contains(path)))
+            .map(|(source, _)| source.clone())
+            .collect()
+    }
+
+    fn remove_unused_output(&self, output: &Path) {
+        let index = self.server.index.read();
+        if output.as_os_str() != PROCESSED_ASSET_INDEX_FILE
+            && !index.assets.values().any(|info| info.output == output)
+        {
+            let _ = fs::remove_file(self.server.destination.join(output));
+        }
+    }
+
+    /// Writes the [`ProcessedAssetIndex`] to the processed asset folder.
+    pub fn write_index(&self) -> Result<(), AssetProcessorError> {
+        let _guard = self.server.write_index_lock.lock();
+        let index = self.server.index.read().clone();
+        fs::create_dir_all(&self.server.destination).map_err(AssetIoError::from)?;
+        index.write(&self.server.destination)?;
+        Ok(())
+    }
+}
+
+/// Processes the source assets created or modified since the last run, along with the assets
+/// depending on them, and reloads the ones already loaded by the [`AssetServer`]. The outputs of
+/// removed source assets are removed.
+#[cfg(feature = "filesystem_watcher")]
+pub fn asset_processor_system(
+    processor: Res<AssetProcessorServer>,
+    asset_server: Res<AssetServer>,
+) {
+    let mut paths = processor.source_io().take_modified_paths();
+    paths.extend(processor.dependents(&paths));
+    for path in paths {
+        if !processor.source_io().is_file(&path) {
+            if !processor.source_io().is_dir(&path) {
+                processor.remove_path(&path);
+                if let Err(err) = processor.write_index() {
+                    warn!("{}", err);
+                }
+            }
+            continue;
+        }
+        let asset_server = asset_server.clone();
+        let index_writer = processor.clone();
+        let task = processor.queue_path(path, move |path, result| match result {
+            Ok(ProcessStatus::Processed) => {
+                if let Err(err) = index_writer.write_index() {
+                    warn!("{}", err);
+                }
+                let asset_path = AssetPath::from(path);
+                if asset_server.get_load_state(asset_path.get_id()) != LoadState::NotLoaded {
+                    asset_server.load_untracked(asset_path.to_owned(), true);
+                }
+            }
+            Ok(ProcessStatus::UpToDate) => {}
+            Err(err) => warn!("{}", err),
+        });
+        if let Some(task) = task {
+            task.detach();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        update_asset_storage_system, AssetLoader, AssetPath, AssetServer, Assets, LoadContext,
+        LoadState, LoadedAsset, ProcessedAssetIo,
+    };
+    use bevy_app::App;
+    use bevy_reflect::TypeUuid;
+    use futures_lite::future;
+
+    struct UppercaseProcessor;
+    impl AssetProcessor for UppercaseProcessor {
+        fn process<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            _: &'a mut ProcessContext,
+        ) -> BoxedFuture<'a, Result<Vec<u8>, anyhow::Error>> {
+            Box::pin(async move { Ok(bytes.to_ascii_uppercase()) })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+
+        fn output_extension(&self) -> &str {
+            "upper"
+        }
+    }
+
+    /// Appends the content of the source asset named by the processed asset.
+    struct IncludeProcessor;
+    impl AssetProcessor for IncludeProcessor {
+        fn process<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            ctx: &'a mut ProcessContext,
+        ) -> BoxedFuture<'a, Result<Vec<u8>, anyhow::Error>> {
+            Box::pin(async move {
+                let path = std::str::from_utf8(bytes)?.trim().to_string();
+                Ok(ctx.read_asset_bytes(path).await?)
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["include"]
+        }
+
+        fn output_extension(&self) -> &str {
+            "txt"
+        }
+    }
+
+    #[derive(Debug, TypeUuid)]
+    #[uuid = "1ab5ed8e-9b0f-4a53-9b07-ab6c8b4d6e1f"]
+    struct Text(String);
+
+    struct UpperLoader;
+    impl AssetLoader for UpperLoader {
+        type Settings = ();
+
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            _settings: &'a Self::Settings,
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let text = String::from_utf8(bytes.to_vec())?;
+                anyhow::ensure!(text == "HELLO", "unexpected content: {}", text);
+                ctx.set_default_asset(LoadedAsset::new(Text(text)));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["upper"]
+        }
+    }
+
+    fn setup() -> (tempfile::TempDir, tempfile::TempDir, AssetProcessorServer) {
+        let source = tempfile::tempdir().unwrap();
+        let destination = tempfile::tempdir().unwrap();
+        fs::create_dir(source.path().join("data")).unwrap();
+        fs::write(source.path().join("data/hello.txt"), "hello").unwrap();
+        fs::write(source.path().join("scene.scn.ron"), "()").unwrap();
+
+        let processed_io = ProcessedAssetIo::new(destination.path());
+        let processor = AssetProcessorServer::new(
+            FileAssetIo::new(source.path(), false),
+            destination.path(),
+            processed_io.index().clone(),
+            Default::default(),
+        );
+        processor.add_processor(UppercaseProcessor);
+        (source, destination, processor)
+    }
+
+    #[test]
+    fn process_and_cache() {
+        let (source, destination, processor) = setup();
+        let hello = Path::new("data/hello.txt");
+        let scene = Path::new("scene.scn.ron");
+
+        let mut processed = future::block_on(processor.process_all()).unwrap();
+        processed.sort();
+        assert_eq!(processed, vec![hello.to_owned(), scene.to_owned()]);
+
+        let index = ProcessedAssetIndex::read(destination.path()).unwrap();
+        let hello_output = index.assets[hello].output.clone();
+        assert_eq!(hello_output.extension().unwrap(), "upper");
+        assert!(index.assets[scene]
+            .output
+            .to_str()
+            .unwrap()
+            .ends_with(".scn.ron"));
+        assert_eq!(
+            fs::read(destination.path().join(&hello_output)).unwrap(),
+            b"HELLO"
+        );
+
+        // nothing changed, so nothing is processed again
+        assert!(future::block_on(processor.process_all())
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            future::block_on(processor.process_path(hello)).unwrap(),
+            ProcessStatus::UpToDate
+        );
+
+        // a modified source gets a new output, and the stale one is removed
+        fs::write(source.path().join(hello), "hello again").unwrap();
+        assert_eq!(
+            future::block_on(processor.process_path(hello)).unwrap(),
+            ProcessStatus::Processed
+        );
+        assert!(!destination.path().join(&hello_output).exists());
+
+        // removed sources lose their outputs
+        fs::remove_file(source.path().join(scene)).unwrap();
+        future::block_on(processor.process_all()).unwrap();
+        let index = ProcessedAssetIndex::read(destination.path()).unwrap();
+        assert!(!index.assets.contains_key(scene));
+        assert_eq!(fs::read_dir(destination.path()).unwrap().count(), 2);
+    }
+
+    #[test]
+    fn process_dependencies() {
+        let (source, destination, processor) = setup();
+        processor.add_processor(IncludeProcessor);
+        fs::write(source.path().join("a.include"), "data/hello.txt").unwrap();
+        let path = Path::new("a.include");
+
+        assert_eq!(
+            future::block_on(processor.process_path(path)).unwrap(),
+            ProcessStatus::Processed
+        );
+        let info = processor.server.index.read().assets[path].clone();
+        assert_eq!(info.dependencies, vec![PathBuf::from("data/hello.txt")]);
+        assert_eq!(
+            future::block_on(processor.process_path(path)).unwrap(),
+            ProcessStatus::UpToDate
+        );
+
+        // a modified dependency gets the asset processed again
+        fs::write(source.path().join("data/hello.txt"), "hello again").unwrap();
+        assert_eq!(
+            future::block_on(processor.process_path(path)).unwrap(),
+            ProcessStatus::Processed
+        );
+        let output = processor.server.index.read().assets[path].output.clone();
+        assert_ne!(output, info.output);
+        assert_eq!(
+            fs::read(destination.path().join(output)).unwrap(),
+            b"hello again"
+        );
+    }
+
+    #[test]
+    fn queue_changed_path_while_processing() {
+        let (_source, _destination, processor) = setup();
+        let runs = Arc::new(Mutex::new(Vec::new()));
+        let on_processed = {
+            let processor = processor.clone();
+            let runs = runs.clone();
+            move |path: &Path, result: Result<ProcessStatus, AssetProcessorError>| {
+                let mut runs = runs.lock();
+                if runs.is_empty() {
+                    // the asset changes again while it is being processed
+                    assert!(processor.queue_path(path.to_owned(), |_, _| {}).is_none());
+                }
+                runs.push(result.unwrap());
+            }
+        };
+
+        let task = processor.queue_path("data/hello.txt".into(), on_processed);
+        future::block_on(task.unwrap());
+        assert_eq!(
+            *runs.lock(),
+            vec![ProcessStatus::Processed, ProcessStatus::UpToDate]
+        );
+        assert!(processor.server.in_flight.lock().is_empty());
+    }
+
+    #[test]
+    fn load_processed_assets() {
+        let (_source, destination, processor) = setup();
+        future::block_on(processor.process_all()).unwrap();
+
+        let processed_io = ProcessedAssetIo::new(destination.path());
+        assert!(processed_io.is_dir(Path::new("data")));
+        assert_eq!(
+            processed_io
+                .read_directory(Path::new(""))
+                .unwrap()
+                .collect::<Vec<_>>(),
+            vec![PathBuf::from("data"), PathBuf::from("scene.scn.ron")]
+        );
+
+        let asset_server = AssetServer::new(processed_io, Default::default());
+        asset_server.add_loader(UpperLoader);
+        let assets = asset_server.register_asset_type::<Text>();
+        let path: AssetPath = "data/hello.txt".into();
+        future::block_on(asset_server.load_async(path.clone(), false)).unwrap();
+        let handle = asset_server.get_handle::<Text, _>(path.get_id());
+
+        let mut app = App::new();
+        app.insert_resource(assets)
+            .insert_resource(asset_server.clone())
+            .add_system(update_asset_storage_system::<Text>);
+        app.update();
+
+        assert_eq!(
+            asset_server.get_load_state(path.get_id()),
+            LoadState::Loaded
+        );
+        let assets = app.world.resource::<Assets<Text>>();
+        assert_eq!(assets.get(&handle).unwrap().0, "HELLO");
+    }
+
+    fn processor_app(
+        source: &tempfile::TempDir,
+        destination: &tempfile::TempDir,
+        watch_for_changes: bool,
+    ) -> App {
+        use crate::{AddAsset, AssetMode, AssetPlugin, AssetServerSettings};
+
+        let mut app = App::new();
+        app.insert_resource(AssetServerSettings {
+            asset_folder: source.path().to_str().unwrap().to_string(),
+            mode: AssetMode::Processor,
+            processed_asset_folder: destination.path().to_str().unwrap().to_string(),
+            watch_for_changes,
+        })
+        .add_plugin(bevy_core::CorePlugin)
+        .add_plugin(AssetPlugin)
+        .add_asset_processor(UppercaseProcessor);
+        app.finish();
+        app
+    }
+
+    #[test]
+    fn processor_mode() {
+        let (source, destination, _) = setup();
+        let app = processor_app(&source, &destination, false);
+
+        let asset_server = app.world.resource::<AssetServer>();
+        let processed_io = asset_server
+            .asset_io()
+            .downcast_ref::<ProcessedAssetIo>()
+            .unwrap();
+        // loads wait for the assets to be processed in the background
+        let hello = future::block_on(processed_io.load_path(Path::new("data/hello.txt")));
+        assert_eq!(hello.unwrap(), b"HELLO");
+        let output = processed_io
+            .processed_path(Path::new("data/hello.txt"))
+            .unwrap();
+        assert_eq!(output.extension().unwrap(), "upper");
+        assert!(destination
+            .path()
+            .join(PROCESSED_ASSET_INDEX_FILE)
+            .is_file());
+    }
+
+    /// Runs the app until the processed asset index satisfies `condition`.
+    #[cfg(feature = "filesystem_watcher")]
+    fn update_until(app: &mut App, condition: impl Fn(&ProcessedAssetIndex) -> bool) -> bool {
+        for _ in 0..500 {
+            app.update();
+            let processor = app.world.resource::<AssetProcessorServer>();
+            if condition(&processor.server.index.read()) {
+                return true;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        false
+    }
+
+    #[cfg(feature = "filesystem_watcher")]
+    #[test]
+    fn process_created_assets() {
+        let (source, destination, _) = setup();
+        let mut app = processor_app(&source, &destination, true);
+
+        fs::write(source.path().join("data/new.txt"), "new").unwrap();
+        let new = Path::new("data/new.txt");
+        assert!(update_until(&mut app, |index| index
+            .assets
+            .contains_key(new)));
+
+        let processor = app.world.resource::<AssetProcessorServer>();
+        let output = processor.server.index.read().assets[new].output.clone();
+        assert_eq!(fs::read(destination.path().join(output)).unwrap(), b"NEW");
+    }
+
+    #[cfg(feature = "filesystem_watcher")]
+    #[test]
+    fn remove_outputs_of_removed_assets() {
+        let (source, destination, _) = setup();
+        let mut app = processor_app(&source, &destination, true);
+        let hello = Path::new("data/hello.txt");
+        assert!(update_until(&mut app, |index| index
+            .assets
+            .contains_key(hello)));
+        let output = {
+            let processor = app.world.resource::<AssetProcessorServer>();
+            let index = processor.server.index.read();
+            index.assets[hello].output.clone()
+        };
+        assert!(destination.path().join(&output).is_file());
+
+        fs::remove_file(source.path().join(hello)).unwrap();
+        assert!(update_until(&mut app, |index| !index
+            .assets
+            .contains_key(hello)));
+        assert!(!destination.path().join(&output).exists());
+        let index = ProcessedAssetIndex::read(destination.path()).unwrap();
+        assert!(!index.assets.contains_key(hello));
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{Asset, AssetDynamic};
+use bevy_reflect::TypeUuid;
+use bevy_utils::Uuid;
+
+/// A saver for an asset type, writing assets to files with the given extensions.
+///
+/// Used by [`AssetServer::save`](crate::AssetServer::save).
+pub trait AssetSaver: Send + Sync + 'static {
+    type Asset: Asset;
+    /// Serializes `asset` to the bytes of a file.
+    fn save(&self, asset: &Self::Asset) -> Result<Vec<u8>, anyhow::Error>;
+    fn extensions(&self) -> &[&str];
+}
+
+/// An object-safe version of [`AssetSaver`], implemented for every saver.
+pub(crate) trait ErasedAssetSaver: Send + Sync + 'static {
+    fn save(&self, asset: &dyn AssetDynamic) -> Result<Vec<u8>, anyhow::Error>;
+    fn extensions(&self) -> &[&str];
+    /// The type uuid of the assets saved by this saver.
+    fn asset_type_uuid(&self) -> Uuid;
+}
+
+impl<S: AssetSaver> ErasedAssetSaver for S {
+    fn save(&self, asset: &dyn AssetDynamic) -> Result<Vec<u8>, anyhow::Error> {
+        let asset = asset
+            .downcast_ref::<S::Asset>()
+            .expect("the asset should have the type of the saver asset");
+        <S as AssetSaver>::save(self, asset)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        <S as AssetSaver>::extensions(self)
+    }
+
+    fn asset_type_uuid(&self) -> Uuid {
+        <S::Asset as TypeUuid>::TYPE_UUID
+    }
+}
@@ -30,7 +30,14 @@ impl AsRef<[u8]> for AudioSource {
 pub struct AudioLoader;
 
 impl AssetLoader for AudioLoader {
-    fn load(&self, bytes: &[u8], load_context: &mut LoadContext) -> BoxedFuture<Result<()>> {
+    type Settings = ();
+
+    fn load(
+        &self,
+        bytes: &[u8],
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext,
+    ) -> BoxedFuture<Result<()>> {
         load_context.set_default_asset(LoadedAsset::new(AudioSource {
             bytes: bytes.into(),
         }));
@@ -1,5 +1,7 @@
 use bevy_ecs::world::World;
-use bevy_tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPoolBuilder};
+use bevy_tasks::{
+    AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, MainThreadExecutor, TaskPoolBuilder,
+};
 use bevy_utils::tracing::trace;
 
 /// Defines a simple way to determine how many threads to use given the number of remaining cores
@@ -92,8 +94,13 @@ impl DefaultTaskPoolOptions {
         }
     }
 
-    /// Inserts the default thread pools into the given resource map based on the configured values
+    /// Inserts the default thread pools into the given resource map based on the configured values,
+    /// along with a [`MainThreadExecutor`] belonging to the calling thread
     pub fn create_default_pools(&self, world: &mut World) {
+        if !world.contains_resource::<MainThreadExecutor>() {
+            world.insert_resource(MainThreadExecutor::new());
+        }
+
         let total_threads =
             bevy_tasks::logical_core_count().clamp(self.min_total_threads, self.max_total_threads);
         trace!("Assigning {} cores to default task pools", total_threads);
@@ -332,7 +332,32 @@ impl Schedule {
 
     /// Executes each [`Stage`] contained in the schedule, one at a time.
     pub fn run_once(&mut self, world: &mut World) {
+        self.run_once_filtered(world, |_| true);
+    }
+
+    /// Executes each [`Stage`] contained in the schedule whose label is accepted by `filter`, one
+    /// at a time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// # let mut schedule = Schedule::default();
+    /// schedule.add_stage("skipped", SystemStage::parallel());
+    /// schedule.add_stage("run", SystemStage::parallel());
+    /// schedule.run_once_filtered(&mut world, |label| label != &"skipped" as &dyn StageLabel);
+    /// ```
+    pub fn run_once_filtered(
+        &mut self,
+        world: &mut World,
+        mut filter: impl FnMut(&dyn StageLabel) -> bool,
+    ) {
         for label in &self.stage_order {
+            if !filter(&**label) {
+                continue;
+            }
             #[cfg(feature = "trace")]
             let _stage_span = bevy_utils::tracing::info_span!("stage", name = ?label).entered();
             let stage = self.stages.get_mut(label).unwrap();
@@ -1,7 +1,5 @@
 use anyhow::Result;
-use bevy_asset::{
-    AssetIoError, AssetLoader, AssetPath, BoxedFuture, Handle, LoadContext, LoadedAsset,
-};
+use bevy_asset::{AssetIoError, AssetLoader, BoxedFuture, Handle, LoadContext, LoadedAsset};
 use bevy_core::Name;
 use bevy_ecs::{entity::Entity, prelude::FromWorld, world::World};
 use bevy_hierarchy::{BuildWorldChildren, WorldChildBuilder};
@@ -70,9 +68,12 @@ pub struct GltfLoader {
 }
 
 impl AssetLoader for GltfLoader {
+    type Settings = ();
+
     fn load<'a>(
         &'a self,
         bytes: &'a [u8],
+        _settings: &'a Self::Settings,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
@@ -616,7 +617,7 @@ fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<
     let base_color_texture = if let Some(info) = pbr.base_color_texture() {
         // TODO: handle info.tex_coord() (the *set* index for the right texcoords)
         let label = texture_label(&info.texture());
-        let path = AssetPath::new_ref(load_context.path(), Some(&label));
+        let path = load_context.asset_path().with_label(label.as_str());
         Some(load_context.get_handle(path))
     } else {
         None
@@ -627,7 +628,7 @@ fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<
             // TODO: handle normal_texture.scale
             // TODO: handle normal_texture.tex_coord() (the *set* index for the right texcoords)
             let label = texture_label(&normal_texture.texture());
-            let path = AssetPath::new_ref(load_context.path(), Some(&label));
+            let path = load_context.asset_path().with_label(label.as_str());
             Some(load_context.get_handle(path))
         } else {
             None
@@ -636,7 +637,7 @@ fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<
     let metallic_roughness_texture = if let Some(info) = pbr.metallic_roughness_texture() {
         // TODO: handle info.tex_coord() (the *set* index for the right texcoords)
         let label = texture_label(&info.texture());
-        let path = AssetPath::new_ref(load_context.path(), Some(&label));
+        let path = load_context.asset_path().with_label(label.as_str());
         Some(load_context.get_handle(path))
     } else {
         None
@@ -646,7 +647,7 @@ fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<
         // TODO: handle occlusion_texture.tex_coord() (the *set* index for the right texcoords)
         // TODO: handle occlusion_texture.strength() (a scalar multiplier for occlusion strength)
         let label = texture_label(&occlusion_texture.texture());
-        let path = AssetPath::new_ref(load_context.path(), Some(&label));
+        let path = load_context.asset_path().with_label(label.as_str());
         Some(load_context.get_handle(path))
     } else {
         None
@@ -657,7 +658,7 @@ fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<
         // TODO: handle occlusion_texture.tex_coord() (the *set* index for the right texcoords)
         // TODO: handle occlusion_texture.strength() (a scalar multiplier for occlusion strength)
         let label = texture_label(&info.texture());
-        let path = AssetPath::new_ref(load_context.path(), Some(&label));
+        let path = load_context.asset_path().with_label(label.as_str());
         Some(load_context.get_handle(path))
     } else {
         None
@@ -779,10 +780,12 @@ fn load_node(
 
                 let primitive_label = primitive_label(&mesh, &primitive);
                 let bounds = primitive.bounding_box();
-                let mesh_asset_path =
-                    AssetPath::new_ref(load_context.path(), Some(&primitive_label));
-                let material_asset_path =
-                    AssetPath::new_ref(load_context.path(), Some(&material_label));
+                let mesh_asset_path = load_context
+                    .asset_path()
+                    .with_label(primitive_label.as_str());
+                let material_asset_path = load_context
+                    .asset_path()
+                    .with_label(material_label.as_str());
 
                 let mut mesh_entity = parent.spawn_bundle(PbrBundle {
                     mesh: load_context.get_handle(mesh_asset_path),
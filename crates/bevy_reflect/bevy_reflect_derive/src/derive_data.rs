@@ -1,13 +1,20 @@
 use crate::container_attributes::ReflectTraits;
-use crate::field_attributes::{parse_field_attrs, ReflectFieldAttr};
+use crate::field_attributes::{parse_field_attrs, DefaultBehavior, ReflectFieldAttr};
 use crate::utility::get_bevy_reflect_path;
 use crate::{REFLECT_ATTRIBUTE_NAME, REFLECT_VALUE_ATTRIBUTE_NAME};
-use syn::{Data, DataStruct, DeriveInput, Field, Fields, Generics, Ident, Meta, Path};
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::token::Comma;
+use syn::{
+    Data, DataEnum, DataStruct, DeriveInput, Field, Fields, Generics, Ident, Meta, Path, Variant,
+};
 
 pub(crate) enum DeriveType {
     Struct,
     TupleStruct,
     UnitStruct,
+    Enum,
     Value,
 }
 
@@ -21,6 +28,38 @@ pub(crate) struct StructField<'a> {
     pub index: usize,
 }
 
+/// Represents a variant on an enum.
+pub(crate) struct EnumVariant<'a> {
+    /// The raw variant.
+    pub data: &'a Variant,
+    /// The fields within this variant.
+    pub fields: EnumVariantFields<'a>,
+    /// The index of this variant within the enum.
+    pub index: usize,
+}
+
+/// The fields of an [`EnumVariant`], depending on the kind of variant.
+pub(crate) enum EnumVariantFields<'a> {
+    Named(Vec<StructField<'a>>),
+    Unnamed(Vec<StructField<'a>>),
+    Unit,
+}
+
+impl<'a> EnumVariant<'a> {
+    /// Get an iterator over the active fields of this variant.
+    pub fn active_fields(&self) -> impl Iterator<Item = &StructField<'a>> {
+        self.fields().iter().filter(|field| !field.attrs.ignore)
+    }
+
+    /// The complete set of fields in this variant.
+    pub fn fields(&self) -> &[StructField<'a>] {
+        match &self.fields {
+            EnumVariantFields::Named(fields) | EnumVariantFields::Unnamed(fields) => fields,
+            EnumVariantFields::Unit => &[],
+        }
+    }
+}
+
 /// Data used by derive macros for `Reflect` and `FromReflect`
 ///
 /// # Example
@@ -42,6 +81,7 @@ pub(crate) struct ReflectDeriveData<'a> {
     type_name: &'a Ident,
     generics: &'a Generics,
     fields: Vec<StructField<'a>>,
+    variants: Vec<EnumVariant<'a>>,
     bevy_reflect_path: Path,
 }
 
@@ -52,6 +92,7 @@ impl<'a> ReflectDeriveData<'a> {
             derive_type: DeriveType::Value,
             generics: &input.generics,
             fields: Vec::new(),
+            variants: Vec::new(),
             traits: ReflectTraits::default(),
             bevy_reflect_path: get_bevy_reflect_path(),
         };
@@ -104,13 +145,62 @@ impl<'a> ReflectDeriveData<'a> {
                 }
                 return Ok(output);
             }
+            Data::Enum(DataEnum { variants, .. }) => {
+                if !force_reflect_value {
+                    output.derive_type = DeriveType::Enum;
+                    output.variants = Self::collect_variants(variants)?;
+                    Self::validate_variant_fields(&output.variants)?;
+                }
+                return Ok(output);
+            }
             _ => {
                 return Ok(output);
             }
         };
 
+        output.fields = Self::collect_fields(fields)?;
+        if !force_reflect_value {
+            Self::validate_fields(&output.fields)?;
+        }
+
+        Ok(output)
+    }
+
+    fn validate_fields(fields: &[StructField]) -> Result<(), syn::Error> {
+        for field in fields {
+            if field.attrs.ignore && field.attrs.has_serialization_attrs() {
+                return Err(syn::Error::new(
+                    field.data.span(),
+                    "ignored fields cannot have serialization attributes",
+                ));
+            }
+            if field.data.ident.is_none() && !field.attrs.aliases.is_empty() {
+                return Err(syn::Error::new(
+                    field.attrs.aliases[0].span(),
+                    "aliases are only supported on named fields",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_variant_fields(variants: &[EnumVariant]) -> Result<(), syn::Error> {
+        for field in variants.iter().flat_map(|variant| variant.fields()) {
+            if field.attrs.has_serialization_attrs() {
+                return Err(syn::Error::new(
+                    field.data.span(),
+                    "serialization attributes are not supported on enum variant fields",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn collect_fields(
+        fields: &'a Punctuated<Field, Comma>,
+    ) -> Result<Vec<StructField<'a>>, syn::Error> {
         let mut errors: Option<syn::Error> = None;
-        output.fields = fields
+        let fields = fields
             .iter()
             .enumerate()
             .map(|(index, field)| {
@@ -134,7 +224,32 @@ impl<'a> ReflectDeriveData<'a> {
             return Err(errs);
         }
 
-        Ok(output)
+        Ok(fields)
+    }
+
+    fn collect_variants(
+        variants: &'a Punctuated<Variant, Comma>,
+    ) -> Result<Vec<EnumVariant<'a>>, syn::Error> {
+        variants
+            .iter()
+            .enumerate()
+            .map(|(index, variant)| {
+                let fields = match &variant.fields {
+                    Fields::Named(fields) => {
+                        EnumVariantFields::Named(Self::collect_fields(&fields.named)?)
+                    }
+                    Fields::Unnamed(fields) => {
+                        EnumVariantFields::Unnamed(Self::collect_fields(&fields.unnamed)?)
+                    }
+                    Fields::Unit => EnumVariantFields::Unit,
+                };
+                Ok(EnumVariant {
+                    data: variant,
+                    fields,
+                    index,
+                })
+            })
+            .collect()
     }
 
     /// Get an iterator over the active fields
@@ -180,6 +295,11 @@ impl<'a> ReflectDeriveData<'a> {
         &self.fields
     }
 
+    /// The variants of this enum.
+    pub fn variants(&self) -> &[EnumVariant<'a>] {
+        &self.variants
+    }
+
     /// The cached `bevy_reflect` path.
     pub fn bevy_reflect_path(&self) -> &Path {
         &self.bevy_reflect_path
@@ -192,6 +312,51 @@ impl<'a> ReflectDeriveData<'a> {
             &self.bevy_reflect_path,
             self.traits.idents(),
             self.generics,
+            self.serialization_data(),
         )
     }
+
+    /// Returns the statements registering this type's `SerializationData`, if
+    /// any of its fields have serialization attributes.
+    fn serialization_data(&self) -> Option<proc_macro2::TokenStream> {
+        if !self
+            .active_fields()
+            .any(|field| field.attrs.has_serialization_attrs())
+        {
+            return None;
+        }
+
+        let bevy_reflect_path = &self.bevy_reflect_path;
+        let is_tuple = matches!(self.derive_type, DeriveType::TupleStruct);
+        let statements = self.active_fields().enumerate().map(|(index, field)| {
+            let ty = &field.data.ty;
+            let skip = field
+                .attrs
+                .skip_serializing
+                .then(|| quote!(serialization_data.skip_field(#index);));
+            // Skipped tuple struct fields are always restored to keep the
+            // remaining fields at the right positions.
+            let default = match &field.attrs.default {
+                DefaultBehavior::Func(func) => Some(quote!(#func())),
+                DefaultBehavior::Default => Some(quote!(<#ty as Default>::default())),
+                DefaultBehavior::Required if is_tuple && field.attrs.skip_serializing => {
+                    Some(quote!(<#ty as Default>::default()))
+                }
+                DefaultBehavior::Required => None,
+            }
+            .map(|default| quote!(serialization_data.set_default(#index, || Box::new(#default));));
+            let aliases = &field.attrs.aliases;
+            quote! {
+                #skip
+                #default
+                #(serialization_data.add_alias(#aliases, #index);)*
+            }
+        });
+
+        Some(quote! {
+            let mut serialization_data = #bevy_reflect_path::serde::SerializationData::default();
+            #(#statements)*
+            registration.insert(serialization_data);
+        })
+    }
 }
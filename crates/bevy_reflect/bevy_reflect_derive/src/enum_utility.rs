@@ -0,0 +1,99 @@
+//! Contains code shared by the `Reflect` and `FromReflect` derives for enums.
+
+use crate::derive_data::{EnumVariantFields, ReflectDeriveData};
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{Index, Member};
+
+/// Contains all the data needed to construct the variants of an enum from a `&dyn Enum`.
+pub(crate) struct EnumVariantConstructors {
+    /// The names of each variant as a string.
+    pub variant_names: Vec<String>,
+    /// The stream of tokens that will construct each variant.
+    pub variant_constructors: Vec<proc_macro2::TokenStream>,
+}
+
+/// Gets the constructors for all variants in the given enum.
+///
+/// The fields of each variant are read from `ref_value`, a `&dyn Enum`, and converted with
+/// `FromReflect`. Ignored fields are constructed with their `Default` implementation.
+///
+/// If `can_panic` is `true`, a missing or invalid field panics. Otherwise, the constructor
+/// returns `None` early using the `?` operator.
+pub(crate) fn get_variant_constructors(
+    derive_data: &ReflectDeriveData,
+    ref_value: &Ident,
+    can_panic: bool,
+) -> EnumVariantConstructors {
+    let bevy_reflect_path = derive_data.bevy_reflect_path();
+    let variant_count = derive_data.variants().len();
+    let mut variant_names = Vec::with_capacity(variant_count);
+    let mut variant_constructors = Vec::with_capacity(variant_count);
+
+    for variant in derive_data.variants() {
+        let ident = &variant.data.ident;
+        let name = ident.to_string();
+        let is_named = matches!(variant.fields, EnumVariantFields::Named(..));
+
+        let mut reflect_index: usize = 0;
+        let (members, values): (Vec<_>, Vec<_>) = variant
+            .fields()
+            .iter()
+            .map(|field| {
+                let member = field
+                    .data
+                    .ident
+                    .as_ref()
+                    .map(|ident| Member::Named(ident.clone()))
+                    .unwrap_or_else(|| Member::Unnamed(Index::from(field.index)));
+
+                let value = if field.attrs.ignore {
+                    quote! { Default::default() }
+                } else {
+                    let ty = &field.data.ty;
+                    let accessor = if is_named {
+                        let field_name = field.data.ident.as_ref().unwrap().to_string();
+                        quote! { #ref_value.field(#field_name) }
+                    } else {
+                        quote! { #ref_value.field_at(#reflect_index) }
+                    };
+                    reflect_index += 1;
+
+                    if can_panic {
+                        let missing = format!("the field `{}` is missing", member_name(&member));
+                        let invalid =
+                            format!("the field `{}` has an invalid type", member_name(&member));
+                        quote! {
+                            <#ty as #bevy_reflect_path::FromReflect>::from_reflect(
+                                #accessor.expect(#missing)
+                            ).expect(#invalid)
+                        }
+                    } else {
+                        quote! {
+                            <#ty as #bevy_reflect_path::FromReflect>::from_reflect(#accessor?)?
+                        }
+                    }
+                };
+
+                (member, value)
+            })
+            .unzip();
+
+        variant_names.push(name);
+        variant_constructors.push(quote! {
+            Self::#ident { #(#members: #values),* }
+        });
+    }
+
+    EnumVariantConstructors {
+        variant_names,
+        variant_constructors,
+    }
+}
+
+fn member_name(member: &Member) -> String {
+    match member {
+        Member::Named(ident) => ident.to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    }
+}
@@ -7,15 +7,49 @@
 use crate::REFLECT_ATTRIBUTE_NAME;
 use quote::ToTokens;
 use syn::spanned::Spanned;
-use syn::{Attribute, Meta, NestedMeta};
+use syn::{Attribute, ExprPath, Lit, LitStr, Meta, NestedMeta};
 
 pub(crate) static IGNORE_ATTR: &str = "ignore";
+pub(crate) static SKIP_SERIALIZING_ATTR: &str = "skip_serializing";
+pub(crate) static DEFAULT_ATTR: &str = "default";
+pub(crate) static ALIAS_ATTR: &str = "alias";
+
+/// Controls how a field's value is produced when it is missing.
+pub(crate) enum DefaultBehavior {
+    /// The field is required.
+    Required,
+    /// The field's value is produced with [`Default::default`].
+    Default,
+    /// The field's value is produced by calling the given function.
+    Func(ExprPath),
+}
+
+impl Default for DefaultBehavior {
+    fn default() -> Self {
+        DefaultBehavior::Required
+    }
+}
 
 /// A container for attributes defined on a field reflected type's field.
 #[derive(Default)]
 pub(crate) struct ReflectFieldAttr {
     /// Determines if this field should be ignored.
     pub ignore: bool,
+    /// Determines if this field should be left out when serializing.
+    pub skip_serializing: bool,
+    /// Determines how this field is produced when it is missing.
+    pub default: DefaultBehavior,
+    /// Alternative names this field is deserialized from.
+    pub aliases: Vec<LitStr>,
+}
+
+impl ReflectFieldAttr {
+    /// Returns true if any serialization attribute has been set on this field.
+    pub fn has_serialization_attrs(&self) -> bool {
+        self.skip_serializing
+            || !matches!(self.default, DefaultBehavior::Required)
+            || !self.aliases.is_empty()
+    }
 }
 
 /// Parse all field attributes marked "reflect" (such as `#[reflect(ignore)]`).
@@ -50,10 +84,37 @@ fn parse_meta(args: &mut ReflectFieldAttr, meta: &Meta) -> Result<(), syn::Error
             args.ignore = true;
             Ok(())
         }
+        Meta::Path(path) if path.is_ident(SKIP_SERIALIZING_ATTR) => {
+            args.skip_serializing = true;
+            Ok(())
+        }
+        Meta::Path(path) if path.is_ident(DEFAULT_ATTR) => {
+            args.default = DefaultBehavior::Default;
+            Ok(())
+        }
         Meta::Path(path) => Err(syn::Error::new(
             path.span(),
             format!("unknown attribute parameter: {}", path.to_token_stream()),
         )),
+        Meta::NameValue(pair) if pair.path.is_ident(DEFAULT_ATTR) => {
+            if let Lit::Str(lit) = &pair.lit {
+                args.default = DefaultBehavior::Func(lit.parse()?);
+                Ok(())
+            } else {
+                Err(syn::Error::new(
+                    pair.lit.span(),
+                    "expected a string containing the path to a function",
+                ))
+            }
+        }
+        Meta::NameValue(pair) if pair.path.is_ident(ALIAS_ATTR) => {
+            if let Lit::Str(lit) = &pair.lit {
+                args.aliases.push(lit.clone());
+                Ok(())
+            } else {
+                Err(syn::Error::new(pair.lit.span(), "expected a string"))
+            }
+        }
         Meta::NameValue(pair) => {
             let path = &pair.path;
             Err(syn::Error::new(
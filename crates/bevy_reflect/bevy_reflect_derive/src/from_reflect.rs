@@ -1,3 +1,5 @@
+use crate::enum_utility::{get_variant_constructors, EnumVariantConstructors};
+use crate::field_attributes::DefaultBehavior;
 use crate::ReflectDeriveData;
 use proc_macro::TokenStream;
 use proc_macro2::Span;
@@ -14,6 +16,54 @@ pub(crate) fn impl_tuple_struct(derive_data: &ReflectDeriveData) -> TokenStream
     impl_struct_internal(derive_data, true)
 }
 
+/// Implements `FromReflect` for the given enum type
+pub(crate) fn impl_enum(derive_data: &ReflectDeriveData) -> TokenStream {
+    let enum_name = derive_data.type_name();
+    let bevy_reflect_path = derive_data.bevy_reflect_path();
+
+    let ref_value = Ident::new("__param0", Span::call_site());
+    let EnumVariantConstructors {
+        variant_names,
+        variant_constructors,
+    } = get_variant_constructors(derive_data, &ref_value, false);
+
+    let field_types = derive_data
+        .variants()
+        .iter()
+        .flat_map(|variant| variant.active_fields())
+        .map(|field| field.data.ty.clone())
+        .collect::<Vec<_>>();
+
+    let (impl_generics, ty_generics, where_clause) = derive_data.generics().split_for_impl();
+
+    // Add FromReflect bound for each active field
+    let mut where_from_reflect_clause = if where_clause.is_some() {
+        quote! {#where_clause}
+    } else if !field_types.is_empty() {
+        quote! {where}
+    } else {
+        quote! {}
+    };
+    where_from_reflect_clause.extend(quote! {
+        #(#field_types: #bevy_reflect_path::FromReflect,)*
+    });
+
+    TokenStream::from(quote! {
+        impl #impl_generics #bevy_reflect_path::FromReflect for #enum_name #ty_generics #where_from_reflect_clause {
+            fn from_reflect(#ref_value: &dyn #bevy_reflect_path::Reflect) -> Option<Self> {
+                if let #bevy_reflect_path::ReflectRef::Enum(#ref_value) = #ref_value.reflect_ref() {
+                    match #ref_value.variant_name() {
+                        #(#variant_names => Some(#variant_constructors),)*
+                        _ => None,
+                    }
+                } else {
+                    None
+                }
+            }
+        }
+    })
+}
+
 /// Implements `FromReflect` for the given value type
 pub(crate) fn impl_value(
     type_name: &Ident,
@@ -139,12 +189,26 @@ fn get_active_fields(
                 let accessor = get_field_accessor(field.data, field.index, is_tuple);
                 let ty = field.data.ty.clone();
 
-                let value = quote! { {
-                    <#ty as #bevy_reflect_path::FromReflect>::from_reflect(
-                        // Accesses the field on the given dynamic struct or tuple struct
-                        #bevy_reflect_path::#struct_type::field(#dyn_struct_name, #accessor)?
-                    )?
-                }};
+                // Accesses the field on the given dynamic struct or tuple struct
+                let field_value =
+                    quote!(#bevy_reflect_path::#struct_type::field(#dyn_struct_name, #accessor));
+                let value = match &field.attrs.default {
+                    DefaultBehavior::Required => quote! { {
+                        <#ty as #bevy_reflect_path::FromReflect>::from_reflect(#field_value?)?
+                    }},
+                    DefaultBehavior::Default => quote! {
+                        match #field_value {
+                            Some(field) => <#ty as #bevy_reflect_path::FromReflect>::from_reflect(field)?,
+                            None => <#ty as Default>::default(),
+                        }
+                    },
+                    DefaultBehavior::Func(func) => quote! {
+                        match #field_value {
+                            Some(field) => <#ty as #bevy_reflect_path::FromReflect>::from_reflect(field)?,
+                            None => #func(),
+                        }
+                    },
+                };
 
                 (member, value)
             })
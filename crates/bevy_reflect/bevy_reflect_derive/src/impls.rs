@@ -1,11 +1,13 @@
 use crate::container_attributes::ReflectTraits;
+use crate::derive_data::EnumVariantFields;
+use crate::enum_utility::{get_variant_constructors, EnumVariantConstructors};
 use crate::ReflectDeriveData;
 use proc_macro::TokenStream;
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Span};
 use quote::quote;
 use syn::{Generics, Index, Member, Path};
 
-/// Implements `Struct`, `GetTypeRegistration`, and `Reflect` for the given derive data.
+/// Implements `Struct`, `Typed`, `GetTypeRegistration`, and `Reflect` for the given derive data.
 pub(crate) fn impl_struct(derive_data: &ReflectDeriveData) -> TokenStream {
     let bevy_reflect_path = derive_data.bevy_reflect_path();
     let struct_name = derive_data.type_name();
@@ -48,12 +50,28 @@ pub(crate) fn impl_struct(derive_data: &ReflectDeriveData) -> TokenStream {
             }
         });
 
+    let field_types = derive_data.active_types();
+    let typed_impl = impl_typed(
+        struct_name,
+        derive_data.generics(),
+        None,
+        quote! {
+            let info = #bevy_reflect_path::StructInfo::new::<Self>(&[
+                #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names),)*
+            ]);
+            #bevy_reflect_path::TypeInfo::Struct(info)
+        },
+        bevy_reflect_path,
+    );
+
     let get_type_registration_impl = derive_data.get_type_registration();
     let (impl_generics, ty_generics, where_clause) = derive_data.generics().split_for_impl();
 
     TokenStream::from(quote! {
         #get_type_registration_impl
 
+        #typed_impl
+
         impl #impl_generics #bevy_reflect_path::Struct for #struct_name #ty_generics #where_clause {
             fn field(&self, name: &str) -> Option<&dyn #bevy_reflect_path::Reflect> {
                 match name {
@@ -171,7 +189,7 @@ pub(crate) fn impl_struct(derive_data: &ReflectDeriveData) -> TokenStream {
     })
 }
 
-/// Implements `TupleStruct`, `GetTypeRegistration`, and `Reflect` for the given derive data.
+/// Implements `TupleStruct`, `Typed`, `GetTypeRegistration`, and `Reflect` for the given derive data.
 pub(crate) fn impl_tuple_struct(derive_data: &ReflectDeriveData) -> TokenStream {
     let bevy_reflect_path = derive_data.bevy_reflect_path();
     let struct_name = derive_data.type_name();
@@ -197,10 +215,26 @@ pub(crate) fn impl_tuple_struct(derive_data: &ReflectDeriveData) -> TokenStream
             }
         });
 
+    let field_types = derive_data.active_types();
+    let typed_impl = impl_typed(
+        struct_name,
+        derive_data.generics(),
+        None,
+        quote! {
+            let info = #bevy_reflect_path::TupleStructInfo::new::<Self>(&[
+                #(#bevy_reflect_path::UnnamedField::new::<#field_types>(#field_indices),)*
+            ]);
+            #bevy_reflect_path::TypeInfo::TupleStruct(info)
+        },
+        bevy_reflect_path,
+    );
+
     let (impl_generics, ty_generics, where_clause) = derive_data.generics().split_for_impl();
     TokenStream::from(quote! {
         #get_type_registration_impl
 
+        #typed_impl
+
         impl #impl_generics #bevy_reflect_path::TupleStruct for #struct_name #ty_generics #where_clause {
             fn field(&self, index: usize) -> Option<&dyn #bevy_reflect_path::Reflect> {
                 match index {
@@ -296,7 +330,371 @@ pub(crate) fn impl_tuple_struct(derive_data: &ReflectDeriveData) -> TokenStream
     })
 }
 
-/// Implements `GetTypeRegistration` and `Reflect` for the given type data.
+/// Implements `Enum`, `Typed`, `GetTypeRegistration`, and `Reflect` for the given derive data.
+pub(crate) fn impl_enum(derive_data: &ReflectDeriveData) -> TokenStream {
+    let bevy_reflect_path = derive_data.bevy_reflect_path();
+    let enum_name = derive_data.type_name();
+    let get_type_registration_impl = derive_data.get_type_registration();
+
+    let ref_name = Ident::new("__name_param", Span::call_site());
+    let ref_index = Ident::new("__index_param", Span::call_site());
+    let ref_value = Ident::new("__value_param", Span::call_site());
+
+    let EnumImpls {
+        variant_info,
+        field,
+        field_at,
+        index_of,
+        name_at,
+        field_len,
+        variant_name,
+        variant_index,
+        variant_type,
+    } = generate_enum_impls(derive_data, &ref_index, &ref_name);
+
+    let EnumVariantConstructors {
+        variant_names,
+        variant_constructors,
+    } = get_variant_constructors(derive_data, &ref_value, true);
+
+    let hash_fn = derive_data
+        .traits()
+        .get_hash_impl(bevy_reflect_path)
+        .unwrap_or_else(|| {
+            quote! {
+                fn reflect_hash(&self) -> Option<u64> {
+                    #bevy_reflect_path::enum_hash(self)
+                }
+            }
+        });
+    let serialize_fn = derive_data.traits().get_serialize_impl(bevy_reflect_path);
+    let partial_eq_fn = derive_data
+        .traits()
+        .get_partial_eq_impl(bevy_reflect_path)
+        .unwrap_or_else(|| {
+            quote! {
+                fn reflect_partial_eq(&self, value: &dyn #bevy_reflect_path::Reflect) -> Option<bool> {
+                    #bevy_reflect_path::enum_partial_eq(self, value)
+                }
+            }
+        });
+
+    // Switching to another variant requires constructing its fields with `FromReflect`
+    let field_types = derive_data
+        .variants()
+        .iter()
+        .flat_map(|variant| variant.active_fields())
+        .map(|field| field.data.ty.clone())
+        .collect::<Vec<_>>();
+    let (impl_generics, ty_generics, where_clause) = derive_data.generics().split_for_impl();
+    let mut where_from_reflect_clause = if where_clause.is_some() {
+        quote! {#where_clause}
+    } else if !field_types.is_empty() {
+        quote! {where}
+    } else {
+        quote! {}
+    };
+    where_from_reflect_clause.extend(quote! {
+        #(#field_types: #bevy_reflect_path::FromReflect,)*
+    });
+
+    let typed_impl = impl_typed(
+        enum_name,
+        derive_data.generics(),
+        Some(&where_from_reflect_clause),
+        quote! {
+            let info = #bevy_reflect_path::EnumInfo::new::<Self>(&[#(#variant_info),*]);
+            #bevy_reflect_path::TypeInfo::Enum(info)
+        },
+        bevy_reflect_path,
+    );
+
+    TokenStream::from(quote! {
+        #get_type_registration_impl
+
+        #typed_impl
+
+        impl #impl_generics #bevy_reflect_path::Enum for #enum_name #ty_generics #where_from_reflect_clause {
+            fn field(&self, #ref_name: &str) -> Option<&dyn #bevy_reflect_path::Reflect> {
+                match self {
+                    #(#field,)*
+                    _ => None,
+                }
+            }
+
+            fn field_at(&self, #ref_index: usize) -> Option<&dyn #bevy_reflect_path::Reflect> {
+                match self {
+                    #(#field_at,)*
+                    _ => None,
+                }
+            }
+
+            fn field_mut(&mut self, #ref_name: &str) -> Option<&mut dyn #bevy_reflect_path::Reflect> {
+                match self {
+                    #(#field,)*
+                    _ => None,
+                }
+            }
+
+            fn field_at_mut(&mut self, #ref_index: usize) -> Option<&mut dyn #bevy_reflect_path::Reflect> {
+                match self {
+                    #(#field_at,)*
+                    _ => None,
+                }
+            }
+
+            fn index_of(&self, #ref_name: &str) -> Option<usize> {
+                match self {
+                    #(#index_of,)*
+                    _ => None,
+                }
+            }
+
+            fn name_at(&self, #ref_index: usize) -> Option<&str> {
+                match self {
+                    #(#name_at,)*
+                    _ => None,
+                }
+            }
+
+            fn iter_fields(&self) -> #bevy_reflect_path::VariantFieldIter {
+                #bevy_reflect_path::VariantFieldIter::new(self)
+            }
+
+            #[inline]
+            fn field_len(&self) -> usize {
+                match self {
+                    #(#field_len,)*
+                }
+            }
+
+            #[inline]
+            fn variant_name(&self) -> &str {
+                match self {
+                    #(#variant_name,)*
+                }
+            }
+
+            #[inline]
+            fn variant_index(&self) -> usize {
+                match self {
+                    #(#variant_index,)*
+                }
+            }
+
+            #[inline]
+            fn variant_type(&self) -> #bevy_reflect_path::VariantType {
+                match self {
+                    #(#variant_type,)*
+                }
+            }
+
+            fn clone_dynamic(&self) -> #bevy_reflect_path::DynamicEnum {
+                #bevy_reflect_path::DynamicEnum::from_ref::<Self>(self)
+            }
+        }
+
+        // SAFE: any and any_mut both return self
+        unsafe impl #impl_generics #bevy_reflect_path::Reflect for #enum_name #ty_generics #where_from_reflect_clause {
+            #[inline]
+            fn type_name(&self) -> &str {
+                std::any::type_name::<Self>()
+            }
+
+            #[inline]
+            fn any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            #[inline]
+            fn any_mut(&mut self) -> &mut dyn std::any::Any {
+                self
+            }
+
+            #[inline]
+            fn as_reflect(&self) -> &dyn #bevy_reflect_path::Reflect {
+                self
+            }
+
+            #[inline]
+            fn as_reflect_mut(&mut self) -> &mut dyn #bevy_reflect_path::Reflect {
+                self
+            }
+
+            #[inline]
+            fn clone_value(&self) -> Box<dyn #bevy_reflect_path::Reflect> {
+                Box::new(#bevy_reflect_path::Enum::clone_dynamic(self))
+            }
+
+            #[inline]
+            fn set(&mut self, #ref_value: Box<dyn #bevy_reflect_path::Reflect>) -> Result<(), Box<dyn #bevy_reflect_path::Reflect>> {
+                *self = #ref_value.take()?;
+                Ok(())
+            }
+
+            #[inline]
+            fn apply(&mut self, #ref_value: &dyn #bevy_reflect_path::Reflect) {
+                if let #bevy_reflect_path::ReflectRef::Enum(#ref_value) = #ref_value.reflect_ref() {
+                    if #bevy_reflect_path::Enum::variant_name(self) == #ref_value.variant_name() {
+                        // Same variant -> just update fields
+                        for (index, field) in #ref_value.iter_fields().enumerate() {
+                            let target = match field.name() {
+                                Some(name) => #bevy_reflect_path::Enum::field_mut(self, name),
+                                None => #bevy_reflect_path::Enum::field_at_mut(self, index),
+                            };
+                            if let Some(target) = target {
+                                target.apply(field.value());
+                            }
+                        }
+                    } else {
+                        // New variant -> perform a switch
+                        match #ref_value.variant_name() {
+                            #(#variant_names => {
+                                *self = #variant_constructors
+                            })*
+                            name => panic!("Variant with name `{}` does not exist on enum `{}`.", name, std::any::type_name::<Self>()),
+                        }
+                    }
+                } else {
+                    panic!("Attempted to apply non-enum type to enum type.");
+                }
+            }
+
+            fn reflect_ref(&self) -> #bevy_reflect_path::ReflectRef {
+                #bevy_reflect_path::ReflectRef::Enum(self)
+            }
+
+            fn reflect_mut(&mut self) -> #bevy_reflect_path::ReflectMut {
+                #bevy_reflect_path::ReflectMut::Enum(self)
+            }
+
+            #hash_fn
+
+            #partial_eq_fn
+
+            #serialize_fn
+        }
+    })
+}
+
+/// The variant infos and the match arms used to implement the methods of `Enum`.
+struct EnumImpls {
+    variant_info: Vec<proc_macro2::TokenStream>,
+    field: Vec<proc_macro2::TokenStream>,
+    field_at: Vec<proc_macro2::TokenStream>,
+    index_of: Vec<proc_macro2::TokenStream>,
+    name_at: Vec<proc_macro2::TokenStream>,
+    field_len: Vec<proc_macro2::TokenStream>,
+    variant_name: Vec<proc_macro2::TokenStream>,
+    variant_index: Vec<proc_macro2::TokenStream>,
+    variant_type: Vec<proc_macro2::TokenStream>,
+}
+
+fn generate_enum_impls(
+    derive_data: &ReflectDeriveData,
+    ref_index: &Ident,
+    ref_name: &Ident,
+) -> EnumImpls {
+    let bevy_reflect_path = derive_data.bevy_reflect_path();
+    let mut impls = EnumImpls {
+        variant_info: Vec::new(),
+        field: Vec::new(),
+        field_at: Vec::new(),
+        index_of: Vec::new(),
+        name_at: Vec::new(),
+        field_len: Vec::new(),
+        variant_name: Vec::new(),
+        variant_index: Vec::new(),
+        variant_type: Vec::new(),
+    };
+
+    for variant in derive_data.variants() {
+        let ident = &variant.data.ident;
+        let name = ident.to_string();
+        let unit = quote! { Self::#ident };
+        let index = variant.index;
+
+        let variant_type = match &variant.fields {
+            EnumVariantFields::Named(..) => quote! { Struct },
+            EnumVariantFields::Unnamed(..) => quote! { Tuple },
+            EnumVariantFields::Unit => quote! { Unit },
+        };
+        let is_named = matches!(variant.fields, EnumVariantFields::Named(..));
+
+        let mut field_len: usize = 0;
+        let mut field_info = Vec::new();
+        for (reflect_index, field) in variant.active_fields().enumerate() {
+            let member = field
+                .data
+                .ident
+                .as_ref()
+                .map(|ident| Member::Named(ident.clone()))
+                .unwrap_or_else(|| Member::Unnamed(Index::from(field.index)));
+
+            impls.field_at.push(quote! {
+                #unit { #member: value, .. } if #ref_index == #reflect_index => Some(value)
+            });
+
+            let field_ty = &field.data.ty;
+            if is_named {
+                let field_name = field.data.ident.as_ref().unwrap().to_string();
+                field_info.push(quote! {
+                    #bevy_reflect_path::NamedField::new::<#field_ty>(#field_name)
+                });
+                impls.field.push(quote! {
+                    #unit { #member: value, .. } if #ref_name == #field_name => Some(value)
+                });
+                impls.index_of.push(quote! {
+                    #unit { .. } if #ref_name == #field_name => Some(#reflect_index)
+                });
+                impls.name_at.push(quote! {
+                    #unit { .. } if #ref_index == #reflect_index => Some(#field_name)
+                });
+            } else {
+                field_info.push(quote! {
+                    #bevy_reflect_path::UnnamedField::new::<#field_ty>(#reflect_index)
+                });
+            }
+
+            field_len += 1;
+        }
+
+        impls.variant_info.push(match &variant.fields {
+            EnumVariantFields::Named(..) => quote! {
+                #bevy_reflect_path::VariantInfo::Struct(
+                    #bevy_reflect_path::StructVariantInfo::new(#name, &[#(#field_info),*])
+                )
+            },
+            EnumVariantFields::Unnamed(..) => quote! {
+                #bevy_reflect_path::VariantInfo::Tuple(
+                    #bevy_reflect_path::TupleVariantInfo::new(#name, &[#(#field_info),*])
+                )
+            },
+            EnumVariantFields::Unit => quote! {
+                #bevy_reflect_path::VariantInfo::Unit(
+                    #bevy_reflect_path::UnitVariantInfo::new(#name)
+                )
+            },
+        });
+
+        impls.field_len.push(quote! {
+            #unit { .. } => #field_len
+        });
+        impls.variant_name.push(quote! {
+            #unit { .. } => #name
+        });
+        impls.variant_index.push(quote! {
+            #unit { .. } => #index
+        });
+        impls.variant_type.push(quote! {
+            #unit { .. } => #bevy_reflect_path::VariantType::#variant_type
+        });
+    }
+
+    impls
+}
+
+/// Implements `Typed`, `GetTypeRegistration`, and `Reflect` for the given type data.
 pub(crate) fn impl_value(
     type_name: &Ident,
     generics: &Generics,
@@ -308,10 +706,23 @@ pub(crate) fn impl_value(
     let serialize_fn = reflect_traits.get_serialize_impl(bevy_reflect_path);
     let partial_eq_fn = reflect_traits.get_partial_eq_impl(bevy_reflect_path);
 
+    let typed_impl = impl_typed(
+        type_name,
+        generics,
+        None,
+        quote! {
+            let info = #bevy_reflect_path::ValueInfo::new::<Self>();
+            #bevy_reflect_path::TypeInfo::Value(info)
+        },
+        bevy_reflect_path,
+    );
+
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     TokenStream::from(quote! {
         #get_type_registration_impl
 
+        #typed_impl
+
         // SAFE: any and any_mut both return self
         unsafe impl #impl_generics #bevy_reflect_path::Reflect for #type_name #ty_generics #where_clause  {
             #[inline]
@@ -376,3 +787,27 @@ pub(crate) fn impl_value(
         }
     })
 }
+
+/// Implements `Typed` for the given type, using `generator` as the body of `Typed::type_info`.
+///
+/// If `where_clause` is `None`, the where clause of `generics` is used.
+fn impl_typed(
+    type_name: &Ident,
+    generics: &Generics,
+    where_clause: Option<&proc_macro2::TokenStream>,
+    generator: proc_macro2::TokenStream,
+    bevy_reflect_path: &Path,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, generics_where_clause) = generics.split_for_impl();
+    let where_clause = where_clause
+        .cloned()
+        .unwrap_or_else(|| quote! { #generics_where_clause });
+
+    quote! {
+        impl #impl_generics #bevy_reflect_path::Typed for #type_name #ty_generics #where_clause {
+            fn type_info() -> #bevy_reflect_path::TypeInfo {
+                #generator
+            }
+        }
+    }
+}
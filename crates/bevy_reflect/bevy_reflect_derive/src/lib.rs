@@ -2,7 +2,7 @@
 //!
 //! The main export of this crate is the derive macro for [`Reflect`]. This allows
 //! types to easily implement `Reflect` along with other `bevy_reflect` traits,
-//! such as `Struct`, `GetTypeRegistration`, and more— all with a single derive!
+//! such as `Struct`, `Enum`, `GetTypeRegistration`, and more— all with a single derive!
 //!
 //! Some other noteworthy exports include the derive macros for [`FromReflect`] and
 //! [`TypeUuid`], as well as the [`reflect_trait`] attribute macro.
@@ -16,9 +16,11 @@ extern crate proc_macro;
 
 mod container_attributes;
 mod derive_data;
+mod enum_utility;
 mod field_attributes;
 mod from_reflect;
 mod impls;
+mod reflect_functions;
 mod reflect_value;
 mod registration;
 mod trait_reflection;
@@ -35,6 +37,17 @@ use syn::{parse_macro_input, DeriveInput};
 pub(crate) static REFLECT_ATTRIBUTE_NAME: &str = "reflect";
 pub(crate) static REFLECT_VALUE_ATTRIBUTE_NAME: &str = "reflect_value";
 
+/// Derives the `Reflect` trait.
+///
+/// This macro supports the following field attributes on structs and tuple structs:
+/// * `#[reflect(ignore)]`: Ignores the field entirely.
+/// * `#[reflect(skip_serializing)]`: Reflects the field but leaves it out of serialized data.
+///   Skipped tuple struct fields are restored with their default value, so their type must
+///   implement [`Default`] unless `#[reflect(default = "...")]` is given.
+/// * `#[reflect(default)]` or `#[reflect(default = "path::to::function")]`: Provides a value
+///   for the field when it is missing from serialized data.
+/// * `#[reflect(alias = "name")]`: Also accepts the given name when deserializing a named
+///   field. May be repeated.
 #[proc_macro_derive(Reflect, attributes(reflect, reflect_value, module))]
 pub fn derive_reflect(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
@@ -47,6 +60,7 @@ pub fn derive_reflect(input: TokenStream) -> TokenStream {
     match derive_data.derive_type() {
         DeriveType::Struct | DeriveType::UnitStruct => impls::impl_struct(&derive_data),
         DeriveType::TupleStruct => impls::impl_tuple_struct(&derive_data),
+        DeriveType::Enum => impls::impl_enum(&derive_data),
         DeriveType::Value => impls::impl_value(
             derive_data.type_name(),
             derive_data.generics(),
@@ -61,6 +75,8 @@ pub fn derive_reflect(input: TokenStream) -> TokenStream {
 ///
 /// This macro supports the following field attributes:
 /// * `#[reflect(ignore)]`: Ignores the field. This requires the field to implement [`Default`].
+/// * `#[reflect(default)]`: Uses [`Default::default`] if the field is missing.
+/// * `#[reflect(default = "path::to::function")]`: Calls the given function if the field is missing.
 ///
 #[proc_macro_derive(FromReflect, attributes(reflect))]
 pub fn derive_from_reflect(input: TokenStream) -> TokenStream {
@@ -74,6 +90,7 @@ pub fn derive_from_reflect(input: TokenStream) -> TokenStream {
     match derive_data.derive_type() {
         DeriveType::Struct | DeriveType::UnitStruct => from_reflect::impl_struct(&derive_data),
         DeriveType::TupleStruct => from_reflect::impl_tuple_struct(&derive_data),
+        DeriveType::Enum => from_reflect::impl_enum(&derive_data),
         DeriveType::Value => from_reflect::impl_value(
             derive_data.type_name(),
             &ast.generics,
@@ -93,6 +110,19 @@ pub fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
     trait_reflection::reflect_trait(&args, input)
 }
 
+/// An attribute for inherent impl blocks which implements `TypeFunctions`,
+/// allowing the block's public functions to be called with reflected arguments.
+///
+/// Methods must take `&self` or `&mut self`, and arguments and return values
+/// must be passed by value. Arguments must implement `FromReflect`. Public
+/// functions which don't fit can be left out with `#[reflect(skip)]`.
+///
+/// Register the functions by adding `#[reflect(Functions)]` to the type.
+#[proc_macro_attribute]
+pub fn reflect_functions(_args: TokenStream, input: TokenStream) -> TokenStream {
+    reflect_functions::reflect_functions(input)
+}
+
 #[proc_macro]
 pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
     let reflect_value_def = parse_macro_input!(input as ReflectValueDef);
@@ -106,6 +136,7 @@ pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
         &bevy_reflect_path,
         registration_data,
         &reflect_value_def.generics,
+        None,
     );
     impls::impl_value(
         ty,
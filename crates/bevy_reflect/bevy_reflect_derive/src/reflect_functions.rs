@@ -0,0 +1,264 @@
+//! Contains code related to the `#[reflect_functions]` attribute macro.
+
+use crate::utility::get_bevy_reflect_path;
+use crate::REFLECT_ATTRIBUTE_NAME;
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, FnArg, Ident, ImplItem, ImplItemMethod, ItemImpl, LitStr, Meta, NestedMeta,
+    Pat, Path, ReturnType, Type, Visibility,
+};
+
+static SKIP_ATTR: &str = "skip";
+
+/// An attribute macro for inherent impl blocks that implements `TypeFunctions`
+/// for the implementing type, exposing each of its public functions.
+pub(crate) fn reflect_functions(input: TokenStream) -> TokenStream {
+    let mut item_impl = parse_macro_input!(input as ItemImpl);
+    let type_functions = take_skipped_methods(&mut item_impl)
+        .and_then(|skipped| impl_type_functions(&item_impl, &skipped));
+    match type_functions {
+        Ok(type_functions) => TokenStream::from(quote! {
+            #item_impl
+
+            #type_functions
+        }),
+        Err(err) => {
+            let err = err.into_compile_error();
+            TokenStream::from(quote! {
+                #item_impl
+
+                #err
+            })
+        }
+    }
+}
+
+/// Removes the `#[reflect(skip)]` attributes from the methods of `item_impl`, since they
+/// are not real attributes, and returns the names of the skipped methods.
+fn take_skipped_methods(item_impl: &mut ItemImpl) -> Result<Vec<Ident>, syn::Error> {
+    let mut skipped = Vec::new();
+    for item in &mut item_impl.items {
+        let method = match item {
+            ImplItem::Method(method) => method,
+            _ => continue,
+        };
+        let (reflect_attrs, attrs) = method
+            .attrs
+            .drain(..)
+            .partition::<Vec<_>, _>(|attr| attr.path.is_ident(REFLECT_ATTRIBUTE_NAME));
+        method.attrs = attrs;
+
+        for attr in reflect_attrs {
+            match attr.parse_meta()? {
+                Meta::List(list)
+                    if list.nested.len() == 1
+                        && matches!(
+                            list.nested.first(),
+                            Some(NestedMeta::Meta(Meta::Path(path))) if path.is_ident(SKIP_ATTR)
+                        ) =>
+                {
+                    skipped.push(method.sig.ident.clone());
+                }
+                meta => {
+                    return Err(syn::Error::new(
+                        meta.span(),
+                        format!("expected `#[{}({})]`", REFLECT_ATTRIBUTE_NAME, SKIP_ATTR),
+                    ))
+                }
+            }
+        }
+    }
+    Ok(skipped)
+}
+
+fn impl_type_functions(
+    item_impl: &ItemImpl,
+    skipped: &[Ident],
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    if let Some((_, trait_path, _)) = &item_impl.trait_ {
+        return Err(syn::Error::new(
+            trait_path.span(),
+            "`#[reflect_functions]` can only be used on inherent impl blocks",
+        ));
+    }
+
+    let bevy_reflect_path = get_bevy_reflect_path();
+    let functions = item_impl
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Method(method)
+                if matches!(method.vis, Visibility::Public(_))
+                    && !skipped.contains(&method.sig.ident) =>
+            {
+                Some(impl_function(method, &bevy_reflect_path))
+            }
+            _ => None,
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let self_ty = &item_impl.self_ty;
+    let (impl_generics, _, where_clause) = item_impl.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #bevy_reflect_path::TypeFunctions for #self_ty #where_clause {
+            fn functions() -> Vec<#bevy_reflect_path::ReflectFunction> {
+                vec![#(#functions),*]
+            }
+        }
+    })
+}
+
+/// How a reflected function takes its receiver.
+enum Receiver {
+    None,
+    Ref,
+    Mut,
+}
+
+/// An error for a public function which can't be reflected.
+fn unsupported(span: Span, message: &str) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!(
+            "{}, use `#[{}({})]` to leave this function out",
+            message, REFLECT_ATTRIBUTE_NAME, SKIP_ATTR
+        ),
+    )
+}
+
+/// Generates the `ReflectFunction` constructor for a single method.
+fn impl_function(
+    method: &ImplItemMethod,
+    bevy_reflect_path: &Path,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let sig = &method.sig;
+    if let Some(asyncness) = &sig.asyncness {
+        return Err(unsupported(
+            asyncness.span(),
+            "reflected functions cannot be async",
+        ));
+    }
+    if !sig.generics.params.is_empty() {
+        return Err(unsupported(
+            sig.generics.span(),
+            "reflected functions cannot be generic",
+        ));
+    }
+
+    let mut receiver = Receiver::None;
+    let mut arg_names = Vec::new();
+    let mut arg_types = Vec::new();
+    for input in sig.inputs.iter() {
+        match input {
+            FnArg::Receiver(self_arg) if self_arg.reference.is_none() => {
+                return Err(unsupported(
+                    self_arg.span(),
+                    "reflected methods must take `&self` or `&mut self`",
+                ));
+            }
+            FnArg::Receiver(self_arg) if self_arg.mutability.is_some() => {
+                receiver = Receiver::Mut;
+            }
+            FnArg::Receiver(_) => receiver = Receiver::Ref,
+            FnArg::Typed(pat_type) => {
+                if let Type::Reference(reference) = &*pat_type.ty {
+                    return Err(unsupported(
+                        reference.span(),
+                        "reflected functions can only take arguments by value",
+                    ));
+                }
+                let name = match &*pat_type.pat {
+                    Pat::Ident(pat_ident) => pat_ident.ident.to_string(),
+                    _ => format!("arg{}", arg_names.len()),
+                };
+                arg_names.push(LitStr::new(&name, Span::call_site()));
+                arg_types.push(&*pat_type.ty);
+            }
+        }
+    }
+
+    let return_type = match &sig.output {
+        ReturnType::Default => quote!(()),
+        ReturnType::Type(_, ty) => {
+            if let Type::Reference(reference) = &**ty {
+                return Err(unsupported(
+                    reference.span(),
+                    "reflected functions must return values by value",
+                ));
+            }
+            quote!(#ty)
+        }
+    };
+
+    let fn_ident = &sig.ident;
+    let fn_name = LitStr::new(&fn_ident.to_string(), Span::call_site());
+    let arg_idents = (0..arg_types.len())
+        .map(|index| Ident::new(&format!("__arg{}", index), Span::call_site()))
+        .collect::<Vec<_>>();
+    let arg_indices = 0..arg_types.len();
+    let args_iter = (!arg_types.is_empty()).then(|| quote!(let mut __args = __args.into_iter();));
+
+    let (receiver_kind, receiver_arg, call_receiver) = match receiver {
+        Receiver::None => (quote!(None), quote!(), quote!()),
+        Receiver::Ref => (
+            quote!(Ref),
+            quote! {
+                let __receiver = __receiver.unwrap();
+                let __this = match __receiver.downcast_ref::<Self>() {
+                    Some(this) => this,
+                    None => return Err(#bevy_reflect_path::FunctionError::InvalidReceiver {
+                        expected: std::any::type_name::<Self>(),
+                        received: __receiver.type_name().to_string(),
+                    }),
+                };
+            },
+            quote!(__this,),
+        ),
+        Receiver::Mut => (
+            quote!(Mut),
+            quote! {
+                let __receiver = __receiver.unwrap();
+                if !__receiver.is::<Self>() {
+                    return Err(#bevy_reflect_path::FunctionError::InvalidReceiver {
+                        expected: std::any::type_name::<Self>(),
+                        received: __receiver.type_name().to_string(),
+                    });
+                }
+                let __this = __receiver.downcast_mut::<Self>().unwrap();
+            },
+            quote!(__this,),
+        ),
+    };
+
+    Ok(quote! {
+        #bevy_reflect_path::ReflectFunction::new(
+            #fn_name,
+            #bevy_reflect_path::FunctionReceiver::#receiver_kind,
+            &[#(#bevy_reflect_path::ArgInfo::new::<#arg_types>(#arg_names)),*],
+            std::any::type_name::<#return_type>(),
+            |__receiver, __args| {
+                #receiver_arg
+                #args_iter
+                #(
+                    let #arg_idents = {
+                        let __arg = __args.next().unwrap();
+                        match <#arg_types as #bevy_reflect_path::FromReflect>::from_reflect(__arg.as_ref()) {
+                            Some(value) => value,
+                            None => return Err(#bevy_reflect_path::FunctionError::InvalidArg {
+                                index: #arg_indices,
+                                expected: std::any::type_name::<#arg_types>(),
+                                received: __arg.type_name().to_string(),
+                            }),
+                        }
+                    };
+                )*
+                let __return: Box<dyn #bevy_reflect_path::Reflect> =
+                    Box::new(Self::#fn_ident(#call_receiver #(#arg_idents),*));
+                Ok(__return)
+            },
+        )
+    })
+}
@@ -5,11 +5,14 @@ use quote::quote;
 use syn::{Generics, Path};
 
 /// Creates the `GetTypeRegistration` impl for the given type data.
+///
+/// `serialization_data` is inserted into the body to register a `SerializationData`, if any.
 pub(crate) fn impl_get_type_registration(
     type_name: &Ident,
     bevy_reflect_path: &Path,
     registration_data: &[Ident],
     generics: &Generics,
+    serialization_data: Option<proc_macro2::TokenStream>,
 ) -> proc_macro2::TokenStream {
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     quote! {
@@ -18,6 +21,7 @@ pub(crate) fn impl_get_type_registration(
             fn get_type_registration() -> #bevy_reflect_path::TypeRegistration {
                 let mut registration = #bevy_reflect_path::TypeRegistration::of::<#type_name #ty_generics>();
                 #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<#type_name #ty_generics>::from_type());)*
+                #serialization_data
                 registration
             }
         }
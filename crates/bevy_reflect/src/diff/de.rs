@@ -0,0 +1,345 @@
+use crate::{
+    diff::{diff_fields, Diff},
+    serde::{type_fields, ReflectDeserializer},
+    Reflect, TypeRegistry,
+};
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use std::marker::PhantomData;
+
+/// Deserializes a [`Diff`] serialized with [`DiffSerializer`](crate::diff::DiffSerializer).
+#[derive(Clone, Copy)]
+pub struct DiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> DiffDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        DiffDeserializer { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for DiffDeserializer<'a> {
+    type Value = Diff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(DiffVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct DiffVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for DiffVisitor<'a> {
+    type Value = Diff;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("diff")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let registry = self.registry;
+        let key = map
+            .next_key::<String>()?
+            .ok_or_else(|| de::Error::invalid_length(0, &"a single diff kind"))?;
+        let diff = match key.as_str() {
+            diff_fields::REPLACE => Diff::Replace(map.next_value_seed(ValueSeed(registry))?),
+            type_fields::STRUCT => Diff::Struct(map.next_value_seed(StructDiffSeed(registry))?),
+            type_fields::TUPLE_STRUCT => {
+                Diff::TupleStruct(map.next_value_seed(indexed_diff_seed(registry))?)
+            }
+            type_fields::TUPLE => Diff::Tuple(map.next_value_seed(indexed_diff_seed(registry))?),
+            type_fields::ARRAY => Diff::Array(map.next_value_seed(indexed_diff_seed(registry))?),
+            type_fields::LIST => map.next_value_seed(ListDiffSeed(registry))?,
+            type_fields::MAP => map.next_value_seed(MapDiffSeed(registry))?,
+            type_fields::ENUM => Diff::Enum(map.next_value_seed(indexed_diff_seed(registry))?),
+            kind => {
+                return Err(de::Error::unknown_field(
+                    kind,
+                    &[
+                        diff_fields::REPLACE,
+                        type_fields::STRUCT,
+                        type_fields::TUPLE_STRUCT,
+                        type_fields::TUPLE,
+                        type_fields::ARRAY,
+                        type_fields::LIST,
+                        type_fields::MAP,
+                        type_fields::ENUM,
+                    ],
+                ))
+            }
+        };
+
+        Ok(diff)
+    }
+}
+
+/// Deserializes a reflected value with [`ReflectDeserializer`].
+#[derive(Clone, Copy)]
+struct ValueSeed<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for ValueSeed<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        ReflectDeserializer::new(self.0).deserialize(deserializer)
+    }
+}
+
+struct StructDiffSeed<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for StructDiffSeed<'a> {
+    type Value = Vec<(String, Diff)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for StructDiffSeed<'a> {
+    type Value = Vec<(String, Diff)>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("map of changed fields")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut changed = Vec::new();
+        while let Some(name) = map.next_key::<String>()? {
+            let diff = map.next_value_seed(DiffDeserializer::new(self.0))?;
+            changed.push((name, diff));
+        }
+        Ok(changed)
+    }
+}
+
+fn indexed_diff_seed(
+    registry: &TypeRegistry,
+) -> SeqSeed<PairSeed<PhantomData<usize>, DiffDeserializer>> {
+    SeqSeed(PairSeed(PhantomData, DiffDeserializer::new(registry)))
+}
+
+struct ListDiffSeed<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for ListDiffSeed<'a> {
+    type Value = Diff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ListDiffSeed<'a> {
+    type Value = Diff;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("list diff")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut removed = None;
+        let mut changed = None;
+        let mut appended = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                diff_fields::REMOVED => removed = Some(map.next_value::<usize>()?),
+                diff_fields::CHANGED => {
+                    changed = Some(map.next_value_seed(indexed_diff_seed(self.0))?);
+                }
+                diff_fields::APPENDED => {
+                    appended = Some(map.next_value_seed(SeqSeed(ValueSeed(self.0)))?);
+                }
+                key => {
+                    return Err(de::Error::unknown_field(
+                        key,
+                        &[
+                            diff_fields::REMOVED,
+                            diff_fields::CHANGED,
+                            diff_fields::APPENDED,
+                        ],
+                    ))
+                }
+            }
+        }
+
+        Ok(Diff::List {
+            removed: removed.ok_or_else(|| de::Error::missing_field(diff_fields::REMOVED))?,
+            changed: changed.ok_or_else(|| de::Error::missing_field(diff_fields::CHANGED))?,
+            appended: appended.ok_or_else(|| de::Error::missing_field(diff_fields::APPENDED))?,
+        })
+    }
+}
+
+struct MapDiffSeed<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for MapDiffSeed<'a> {
+    type Value = Diff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for MapDiffSeed<'a> {
+    type Value = Diff;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("map diff")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let registry = self.0;
+        let mut removed = None;
+        let mut changed = None;
+        let mut inserted = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                diff_fields::REMOVED => {
+                    removed = Some(map.next_value_seed(SeqSeed(ValueSeed(registry)))?);
+                }
+                diff_fields::CHANGED => {
+                    changed = Some(map.next_value_seed(SeqSeed(PairSeed(
+                        ValueSeed(registry),
+                        DiffDeserializer::new(registry),
+                    )))?);
+                }
+                diff_fields::INSERTED => {
+                    inserted = Some(map.next_value_seed(SeqSeed(PairSeed(
+                        ValueSeed(registry),
+                        ValueSeed(registry),
+                    )))?);
+                }
+                key => {
+                    return Err(de::Error::unknown_field(
+                        key,
+                        &[
+                            diff_fields::REMOVED,
+                            diff_fields::CHANGED,
+                            diff_fields::INSERTED,
+                        ],
+                    ))
+                }
+            }
+        }
+
+        Ok(Diff::Map {
+            removed: removed.ok_or_else(|| de::Error::missing_field(diff_fields::REMOVED))?,
+            changed: changed.ok_or_else(|| de::Error::missing_field(diff_fields::CHANGED))?,
+            inserted: inserted.ok_or_else(|| de::Error::missing_field(diff_fields::INSERTED))?,
+        })
+    }
+}
+
+/// Deserializes a sequence, using a copy of the given seed for each element.
+#[derive(Clone, Copy)]
+struct SeqSeed<S>(S);
+
+impl<'de, S> DeserializeSeed<'de> for SeqSeed<S>
+where
+    S: DeserializeSeed<'de> + Copy,
+{
+    type Value = Vec<S::Value>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, S> Visitor<'de> for SeqSeed<S>
+where
+    S: DeserializeSeed<'de> + Copy,
+{
+    type Value = Vec<S::Value>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("sequence")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(self.0)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Deserializes a 2-tuple, using a seed for each element.
+#[derive(Clone, Copy)]
+struct PairSeed<A, B>(A, B);
+
+impl<'de, A, B> DeserializeSeed<'de> for PairSeed<A, B>
+where
+    A: DeserializeSeed<'de>,
+    B: DeserializeSeed<'de>,
+{
+    type Value = (A::Value, B::Value);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de, A, B> Visitor<'de> for PairSeed<A, B>
+where
+    A: DeserializeSeed<'de>,
+    B: DeserializeSeed<'de>,
+{
+    type Value = (A::Value, B::Value);
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("tuple of two elements")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let PairSeed(first_seed, second_seed) = self;
+        let first = seq
+            .next_element_seed(first_seed)?
+            .ok_or_else(|| de::Error::invalid_length(0, &"tuple of two elements"))?;
+        let second = seq
+            .next_element_seed(second_seed)?
+            .ok_or_else(|| de::Error::invalid_length(1, &"tuple of two elements"))?;
+        Ok((first, second))
+    }
+}
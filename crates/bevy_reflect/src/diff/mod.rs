@@ -0,0 +1,616 @@
+//! Structural diffing of reflected values.
+//!
+//! A [`Diff`] describes only the parts of a value that changed: fields of a [`Struct`] by name,
+//! fields of a [`TupleStruct`], [`Tuple`] or enum variant by index, elements of a [`List`] or
+//! [`Array`] by index and entries of a [`Map`] by key. Everything else is replaced as a whole.
+//!
+//! [`TupleStruct`]: crate::TupleStruct
+//! [`Tuple`]: crate::Tuple
+//! [`Array`]: crate::Array
+//!
+//! Diffs can be sent over the wire with [`DiffSerializer`] and [`DiffDeserializer`], which
+//! store the changed values using [`ReflectSerializer`](crate::serde::ReflectSerializer).
+//!
+//! # Example
+//!
+//! ```
+//! use bevy_reflect::{diff::diff, Reflect};
+//!
+//! #[derive(Reflect, Clone)]
+//! struct Player {
+//!     name: String,
+//!     health: u32,
+//!     inventory: Vec<String>,
+//! }
+//!
+//! # fn main() {
+//! let old = Player {
+//!     name: "Player 1".to_string(),
+//!     health: 100,
+//!     inventory: vec!["sword".to_string()],
+//! };
+//! let mut new = old.clone();
+//! new.health = 80;
+//! new.inventory.push("shield".to_string());
+//!
+//! let patch = diff(&old, &new).unwrap();
+//!
+//! let mut value = old.clone();
+//! patch.apply(&mut value).unwrap();
+//! assert_eq!(value.health, 80);
+//! assert_eq!(value.inventory, new.inventory);
+//! # }
+//! ```
+
+mod de;
+mod ser;
+
+pub use de::*;
+pub use ser::*;
+
+use crate::{List, Map, MapInsertError, Reflect, ReflectMut, ReflectRef, Struct};
+use thiserror::Error;
+
+pub(crate) mod diff_fields {
+    pub const REPLACE: &str = "replace";
+    pub const REMOVED: &str = "removed";
+    pub const CHANGED: &str = "changed";
+    pub const APPENDED: &str = "appended";
+    pub const INSERTED: &str = "inserted";
+}
+
+/// The changes required to turn one reflected value into another.
+///
+/// Created with [`diff`] and applied with [`Diff::apply`].
+#[derive(Debug)]
+pub enum Diff {
+    /// The value is replaced by applying the given value to it with [`Reflect::apply`].
+    Replace(Box<dyn Reflect>),
+    /// The changed fields of a [`Struct`], by name.
+    Struct(Vec<(String, Diff)>),
+    /// The changed fields of a [`TupleStruct`](crate::TupleStruct), by index.
+    TupleStruct(Vec<(usize, Diff)>),
+    /// The changed fields of a [`Tuple`](crate::Tuple), by index.
+    Tuple(Vec<(usize, Diff)>),
+    /// The changed elements of an [`Array`](crate::Array), by index.
+    Array(Vec<(usize, Diff)>),
+    /// The changes to a [`List`].
+    List {
+        /// The number of elements removed from the end of the list.
+        removed: usize,
+        /// The changed elements, by index.
+        changed: Vec<(usize, Diff)>,
+        /// The elements appended to the end of the list.
+        appended: Vec<Box<dyn Reflect>>,
+    },
+    /// The changes to a [`Map`].
+    Map {
+        /// The keys of the removed entries.
+        removed: Vec<Box<dyn Reflect>>,
+        /// The changed values, by key.
+        changed: Vec<(Box<dyn Reflect>, Diff)>,
+        /// The inserted entries.
+        inserted: Vec<(Box<dyn Reflect>, Box<dyn Reflect>)>,
+    },
+    /// The changed fields of the current variant of an [`Enum`](crate::Enum), by index.
+    ///
+    /// A change of variant is represented by [`Diff::Replace`].
+    Enum(Vec<(usize, Diff)>),
+}
+
+/// An error returned when a [`Diff`] could not be applied to a value.
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum DiffApplyError {
+    #[error("expected a {expected}, but found `{type_name}`")]
+    MismatchedKinds {
+        expected: &'static str,
+        type_name: String,
+    },
+    #[error("the value doesn't have a field named `{0}`")]
+    MissingField(String),
+    #[error("the value doesn't have a field or element at index {0}")]
+    MissingIndex(usize),
+    #[error("the map doesn't contain the changed key")]
+    MissingKey,
+    #[error("`{0}` doesn't support removing elements")]
+    RemoveUnsupported(String),
+    #[error(transparent)]
+    Insert(#[from] MapInsertError),
+}
+
+/// Computes the [`Diff`] that turns `old` into `new`.
+///
+/// Returns `None` if the two values are equal. Values which can't be compared with
+/// [`Reflect::reflect_partial_eq`] are always considered changed.
+pub fn diff(old: &dyn Reflect, new: &dyn Reflect) -> Option<Diff> {
+    if old.type_name() != new.type_name() {
+        return Some(Diff::Replace(new.clone_value()));
+    }
+
+    match (old.reflect_ref(), new.reflect_ref()) {
+        (ReflectRef::Struct(old_struct), ReflectRef::Struct(new_struct)) => {
+            let changed = diff_struct(old_struct, new_struct);
+            (!changed.is_empty()).then(|| Diff::Struct(changed))
+        }
+        (ReflectRef::TupleStruct(old_struct), ReflectRef::TupleStruct(new_struct)) => {
+            if old_struct.field_len() != new_struct.field_len() {
+                return Some(Diff::Replace(new.clone_value()));
+            }
+            let changed = diff_indexed(old_struct.iter_fields(), new_struct.iter_fields());
+            (!changed.is_empty()).then(|| Diff::TupleStruct(changed))
+        }
+        (ReflectRef::Tuple(old_tuple), ReflectRef::Tuple(new_tuple)) => {
+            if old_tuple.field_len() != new_tuple.field_len() {
+                return Some(Diff::Replace(new.clone_value()));
+            }
+            let changed = diff_indexed(old_tuple.iter_fields(), new_tuple.iter_fields());
+            (!changed.is_empty()).then(|| Diff::Tuple(changed))
+        }
+        (ReflectRef::Array(old_array), ReflectRef::Array(new_array)) => {
+            if old_array.len() != new_array.len() {
+                return Some(Diff::Replace(new.clone_value()));
+            }
+            let changed = diff_indexed(old_array.iter(), new_array.iter());
+            (!changed.is_empty()).then(|| Diff::Array(changed))
+        }
+        (ReflectRef::List(old_list), ReflectRef::List(new_list)) => diff_list(old_list, new_list),
+        (ReflectRef::Map(old_map), ReflectRef::Map(new_map)) => diff_map(old_map, new_map),
+        (ReflectRef::Enum(old_enum), ReflectRef::Enum(new_enum)) => {
+            if old_enum.variant_name() != new_enum.variant_name()
+                || old_enum.variant_type() != new_enum.variant_type()
+                || old_enum.field_len() != new_enum.field_len()
+            {
+                return Some(Diff::Replace(new.clone_value()));
+            }
+            let changed = diff_indexed(
+                old_enum.iter_fields().map(|field| field.value()),
+                new_enum.iter_fields().map(|field| field.value()),
+            );
+            (!changed.is_empty()).then(|| Diff::Enum(changed))
+        }
+        _ => match old.reflect_partial_eq(new) {
+            Some(true) => None,
+            _ => Some(Diff::Replace(new.clone_value())),
+        },
+    }
+}
+
+fn diff_struct(old: &dyn Struct, new: &dyn Struct) -> Vec<(String, Diff)> {
+    let mut changed = Vec::new();
+    for (index, new_field) in new.iter_fields().enumerate() {
+        let name = match new.name_at(index) {
+            Some(name) => name,
+            None => continue,
+        };
+        let field_diff = match old.field(name) {
+            Some(old_field) => diff(old_field, new_field),
+            None => Some(Diff::Replace(new_field.clone_value())),
+        };
+        if let Some(field_diff) = field_diff {
+            changed.push((name.to_string(), field_diff));
+        }
+    }
+    changed
+}
+
+fn diff_indexed<'a>(
+    old: impl Iterator<Item = &'a dyn Reflect>,
+    new: impl Iterator<Item = &'a dyn Reflect>,
+) -> Vec<(usize, Diff)> {
+    old.zip(new)
+        .enumerate()
+        .filter_map(|(index, (old, new))| diff(old, new).map(|diff| (index, diff)))
+        .collect()
+}
+
+fn diff_list(old: &dyn List, new: &dyn List) -> Option<Diff> {
+    let removed = old.len().saturating_sub(new.len());
+    let changed = diff_indexed(old.iter(), new.iter());
+    let appended = new
+        .iter()
+        .skip(old.len())
+        .map(|value| value.clone_value())
+        .collect::<Vec<_>>();
+
+    if removed == 0 && changed.is_empty() && appended.is_empty() {
+        None
+    } else {
+        Some(Diff::List {
+            removed,
+            changed,
+            appended,
+        })
+    }
+}
+
+fn diff_map(old: &dyn Map, new: &dyn Map) -> Option<Diff> {
+    let mut changed = Vec::new();
+    let mut inserted = Vec::new();
+    for (key, new_value) in new.iter() {
+        match old.get(key) {
+            Some(old_value) => {
+                if let Some(value_diff) = diff(old_value, new_value) {
+                    changed.push((key.clone_value(), value_diff));
+                }
+            }
+            None => inserted.push((key.clone_value(), new_value.clone_value())),
+        }
+    }
+    let removed = old
+        .iter()
+        .filter(|(key, _)| new.get(*key).is_none())
+        .map(|(key, _)| key.clone_value())
+        .collect::<Vec<_>>();
+
+    if removed.is_empty() && changed.is_empty() && inserted.is_empty() {
+        None
+    } else {
+        Some(Diff::Map {
+            removed,
+            changed,
+            inserted,
+        })
+    }
+}
+
+impl Diff {
+    /// Applies this diff to `target`.
+    ///
+    /// Changed values are written with [`Reflect::apply`], so the diff can be applied to the
+    /// value it was computed from as well as to a dynamic representation of it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Reflect::apply`] panics for a replaced value, or if an appended list element
+    /// can't be converted to the type stored in `target`.
+    pub fn apply(&self, target: &mut dyn Reflect) -> Result<(), DiffApplyError> {
+        match (self, target.reflect_mut()) {
+            (Diff::Replace(value), _) => {
+                target.apply(&**value);
+            }
+            (Diff::Struct(changed), ReflectMut::Struct(target)) => {
+                for (name, diff) in changed {
+                    let field = target
+                        .field_mut(name)
+                        .ok_or_else(|| DiffApplyError::MissingField(name.clone()))?;
+                    diff.apply(field)?;
+                }
+            }
+            (Diff::TupleStruct(changed), ReflectMut::TupleStruct(target)) => {
+                for (index, diff) in changed {
+                    let field = target
+                        .field_mut(*index)
+                        .ok_or(DiffApplyError::MissingIndex(*index))?;
+                    diff.apply(field)?;
+                }
+            }
+            (Diff::Tuple(changed), ReflectMut::Tuple(target)) => {
+                for (index, diff) in changed {
+                    let field = target
+                        .field_mut(*index)
+                        .ok_or(DiffApplyError::MissingIndex(*index))?;
+                    diff.apply(field)?;
+                }
+            }
+            (Diff::Array(changed), ReflectMut::Array(target)) => {
+                for (index, diff) in changed {
+                    let element = target
+                        .get_mut(*index)
+                        .ok_or(DiffApplyError::MissingIndex(*index))?;
+                    diff.apply(element)?;
+                }
+            }
+            (
+                Diff::List {
+                    removed,
+                    changed,
+                    appended,
+                },
+                ReflectMut::List(target),
+            ) => {
+                for _ in 0..*removed {
+                    if target.pop().is_none() {
+                        return Err(if target.is_empty() {
+                            DiffApplyError::MissingIndex(0)
+                        } else {
+                            DiffApplyError::RemoveUnsupported(target.type_name().to_string())
+                        });
+                    }
+                }
+                for (index, diff) in changed {
+                    let element = target
+                        .get_mut(*index)
+                        .ok_or(DiffApplyError::MissingIndex(*index))?;
+                    diff.apply(element)?;
+                }
+                for value in appended {
+                    target.push(value.clone_value());
+                }
+            }
+            (
+                Diff::Map {
+                    removed,
+                    changed,
+                    inserted,
+                },
+                ReflectMut::Map(target),
+            ) => {
+                for key in removed {
+                    if target.remove(&**key).is_none() && target.get(&**key).is_some() {
+                        return Err(DiffApplyError::RemoveUnsupported(
+                            target.type_name().to_string(),
+                        ));
+                    }
+                }
+                for (key, diff) in changed {
+                    let value = target.get_mut(&**key).ok_or(DiffApplyError::MissingKey)?;
+                    diff.apply(value)?;
+                }
+                for (key, value) in inserted {
+                    target.insert_boxed(key.clone_value(), value.clone_value())?;
+                }
+            }
+            (Diff::Enum(changed), ReflectMut::Enum(target)) => {
+                for (index, diff) in changed {
+                    let field = target
+                        .field_at_mut(*index)
+                        .ok_or(DiffApplyError::MissingIndex(*index))?;
+                    diff.apply(field)?;
+                }
+            }
+            (diff, _) => {
+                return Err(DiffApplyError::MismatchedKinds {
+                    expected: diff.kind(),
+                    type_name: target.type_name().to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The kind of value this diff applies to.
+    fn kind(&self) -> &'static str {
+        match self {
+            Diff::Replace(_) => "value",
+            Diff::Struct(_) => "struct",
+            Diff::TupleStruct(_) => "tuple struct",
+            Diff::Tuple(_) => "tuple",
+            Diff::Array(_) => "array",
+            Diff::List { .. } => "list",
+            Diff::Map { .. } => "map",
+            Diff::Enum(_) => "enum",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{DynamicEnum, Enum, FromReflect, TypeRegistry};
+    use bevy_utils::HashMap;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+    struct Point {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+    struct Id(u32, String);
+
+    #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+    enum Shape {
+        Empty,
+        Circle(Point, f32),
+        Rect { min: Point, max: Point },
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct State {
+        id: Id,
+        position: Point,
+        pair: (u8, String),
+        corners: [Point; 2],
+        path: Vec<Point>,
+        scores: HashMap<String, u32>,
+        shape: Shape,
+    }
+
+    fn state() -> State {
+        let mut scores = HashMap::default();
+        scores.insert("a".to_string(), 1);
+        scores.insert("b".to_string(), 2);
+        State {
+            id: Id(1, "one".to_string()),
+            position: Point { x: 0.0, y: 0.0 },
+            pair: (1, "pair".to_string()),
+            corners: [Point { x: 0.0, y: 0.0 }, Point { x: 1.0, y: 1.0 }],
+            path: vec![Point { x: 0.0, y: 0.0 }, Point { x: 1.0, y: 0.0 }],
+            scores,
+            shape: Shape::Circle(Point { x: 0.0, y: 0.0 }, 1.0),
+        }
+    }
+
+    fn changed_state() -> State {
+        let mut new = state();
+        new.id.1 = "uno".to_string();
+        new.position.y = 2.0;
+        new.pair.0 = 2;
+        new.corners[1].x = 3.0;
+        new.path[0].x = 5.0;
+        new.path.push(Point { x: 2.0, y: 2.0 });
+        new.scores.remove("a");
+        *new.scores.get_mut("b").unwrap() = 20;
+        new.scores.insert("c".to_string(), 3);
+        new.shape = Shape::Circle(Point { x: 0.0, y: 4.0 }, 1.0);
+        new
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<u8>();
+        registry.register::<u32>();
+        registry.register::<f32>();
+        registry.register::<String>();
+        registry
+    }
+
+    #[test]
+    fn equal_values_have_no_diff() {
+        assert!(diff(&state(), &state()).is_none());
+        assert!(diff(&1u32, &1u32).is_none());
+    }
+
+    #[test]
+    fn diff_only_contains_changes() {
+        let mut new = state();
+        new.position.y = 2.0;
+        new.id.0 = 2;
+
+        let patch = diff(&state(), &new).unwrap();
+        if let Diff::Struct(changed) = &patch {
+            let names = changed
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(names, vec!["id", "position"]);
+            assert!(
+                matches!(&changed[0].1, Diff::TupleStruct(fields) if fields.len() == 1 && fields[0].0 == 0)
+            );
+            assert!(
+                matches!(&changed[1].1, Diff::Struct(fields) if fields.len() == 1 && fields[0].0 == "y")
+            );
+        } else {
+            panic!("Expected `Diff::Struct`");
+        }
+    }
+
+    #[test]
+    fn apply_diff_all_kinds() {
+        let old = state();
+        let new = changed_state();
+
+        let patch = diff(&old, &new).unwrap();
+        let mut value = old.clone();
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+
+        // Applying the reverse diff restores the original value
+        let patch = diff(&new, &old).unwrap();
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, old);
+    }
+
+    #[test]
+    fn apply_diff_list_removal() {
+        let old = vec![1u32, 2, 3, 4];
+        let new = vec![1u32, 5];
+
+        let patch = diff(&old, &new).unwrap();
+        assert!(matches!(
+            &patch,
+            Diff::List { removed: 2, changed, appended } if changed.len() == 1 && appended.is_empty()
+        ));
+
+        let mut value = old.clone();
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+    }
+
+    #[test]
+    fn apply_diff_map_of_structs() {
+        let mut old = HashMap::<String, Point>::default();
+        old.insert("a".to_string(), Point { x: 0.0, y: 0.0 });
+        let mut new = old.clone();
+        new.insert("b".to_string(), Point { x: 1.0, y: 2.0 });
+
+        // the inserted value is a `DynamicStruct`, converted back with `FromReflect`
+        let patch = diff(&old, &new).unwrap();
+        let mut value = old.clone();
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+
+        let patch = Diff::Map {
+            removed: Vec::new(),
+            changed: Vec::new(),
+            inserted: vec![(Box::new("c".to_string()), Box::new(3u32))],
+        };
+        assert_eq!(
+            patch.apply(&mut value),
+            Err(DiffApplyError::Insert(MapInsertError::InvalidValue(
+                std::any::type_name::<u32>().to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn apply_diff_enum_variant_change() {
+        let old = Shape::Circle(Point { x: 0.0, y: 0.0 }, 1.0);
+        let new = Shape::Rect {
+            min: Point { x: 0.0, y: 0.0 },
+            max: Point { x: 1.0, y: 1.0 },
+        };
+
+        let patch = diff(&old, &new).unwrap();
+        assert!(matches!(patch, Diff::Replace(_)));
+
+        let mut value = old.clone();
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+
+        let patch = diff(&new, &Shape::Empty).unwrap();
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, Shape::Empty);
+    }
+
+    #[test]
+    fn apply_diff_to_dynamic() {
+        let old = Shape::Circle(Point { x: 0.0, y: 0.0 }, 1.0);
+        let new = Shape::Circle(Point { x: 0.0, y: 0.0 }, 2.0);
+
+        let mut value = DynamicEnum::from_ref(&old);
+        diff(&old, &new).unwrap().apply(&mut value).unwrap();
+        assert_eq!(value.reflect_partial_eq(&new), Some(true));
+
+        let patch = diff(&old, &Shape::Empty).unwrap();
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value.variant_name(), "Empty");
+        assert_eq!(value.field_len(), 0);
+    }
+
+    #[test]
+    fn apply_diff_mismatched_kinds() {
+        let patch = diff(&Point { x: 0.0, y: 0.0 }, &Point { x: 1.0, y: 0.0 }).unwrap();
+        let mut value = Id(0, String::new());
+        assert_eq!(
+            patch.apply(&mut value),
+            Err(DiffApplyError::MismatchedKinds {
+                expected: "struct",
+                type_name: std::any::type_name::<Id>().to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn diff_serialization_roundtrip() {
+        let registry = registry();
+        let old = state();
+        let new = changed_state();
+        let patch = diff(&old, &new).unwrap();
+
+        let serializer = DiffSerializer::new(&patch, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let patch = DiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let mut value = old.clone();
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+    }
+}
@@ -0,0 +1,269 @@
+use crate::{
+    diff::{diff_fields, Diff},
+    serde::{type_fields, ReflectSerializer},
+    Reflect, TypeRegistry,
+};
+use serde::{
+    ser::{SerializeMap, SerializeSeq, SerializeTuple},
+    Serialize,
+};
+
+/// Serializes a [`Diff`], using [`ReflectSerializer`] for the changed values.
+pub struct DiffSerializer<'a> {
+    pub diff: &'a Diff,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> DiffSerializer<'a> {
+    pub fn new(diff: &'a Diff, registry: &'a TypeRegistry) -> Self {
+        DiffSerializer { diff, registry }
+    }
+}
+
+impl<'a> Serialize for DiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let registry = self.registry;
+        let mut state = serializer.serialize_map(Some(1))?;
+        match self.diff {
+            Diff::Replace(value) => {
+                state.serialize_entry(
+                    diff_fields::REPLACE,
+                    &ReflectSerializer::new(&**value, registry),
+                )?;
+            }
+            Diff::Struct(changed) => {
+                state.serialize_entry(
+                    type_fields::STRUCT,
+                    &StructDiffSerializer { changed, registry },
+                )?;
+            }
+            Diff::TupleStruct(changed) => {
+                state.serialize_entry(
+                    type_fields::TUPLE_STRUCT,
+                    &IndexedDiffSerializer { changed, registry },
+                )?;
+            }
+            Diff::Tuple(changed) => {
+                state.serialize_entry(
+                    type_fields::TUPLE,
+                    &IndexedDiffSerializer { changed, registry },
+                )?;
+            }
+            Diff::Array(changed) => {
+                state.serialize_entry(
+                    type_fields::ARRAY,
+                    &IndexedDiffSerializer { changed, registry },
+                )?;
+            }
+            Diff::List {
+                removed,
+                changed,
+                appended,
+            } => {
+                state.serialize_entry(
+                    type_fields::LIST,
+                    &ListDiffSerializer {
+                        removed: *removed,
+                        changed,
+                        appended,
+                        registry,
+                    },
+                )?;
+            }
+            Diff::Map {
+                removed,
+                changed,
+                inserted,
+            } => {
+                state.serialize_entry(
+                    type_fields::MAP,
+                    &MapDiffSerializer {
+                        removed,
+                        changed,
+                        inserted,
+                        registry,
+                    },
+                )?;
+            }
+            Diff::Enum(changed) => {
+                state.serialize_entry(
+                    type_fields::ENUM,
+                    &IndexedDiffSerializer { changed, registry },
+                )?;
+            }
+        }
+        state.end()
+    }
+}
+
+struct StructDiffSerializer<'a> {
+    changed: &'a [(String, Diff)],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for StructDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.changed.len()))?;
+        for (name, diff) in self.changed {
+            state.serialize_entry(name, &DiffSerializer::new(diff, self.registry))?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes changes addressed by index as a sequence of `(index, diff)` tuples.
+struct IndexedDiffSerializer<'a> {
+    changed: &'a [(usize, Diff)],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for IndexedDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.changed.len()))?;
+        for (index, diff) in self.changed {
+            state.serialize_element(&PairSerializer(
+                index,
+                &DiffSerializer::new(diff, self.registry),
+            ))?;
+        }
+        state.end()
+    }
+}
+
+struct ListDiffSerializer<'a> {
+    removed: usize,
+    changed: &'a [(usize, Diff)],
+    appended: &'a [Box<dyn Reflect>],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ListDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(3))?;
+        state.serialize_entry(diff_fields::REMOVED, &self.removed)?;
+        state.serialize_entry(
+            diff_fields::CHANGED,
+            &IndexedDiffSerializer {
+                changed: self.changed,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_entry(
+            diff_fields::APPENDED,
+            &ValuesSerializer {
+                values: self.appended,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+struct MapDiffSerializer<'a> {
+    removed: &'a [Box<dyn Reflect>],
+    changed: &'a [(Box<dyn Reflect>, Diff)],
+    #[allow(clippy::type_complexity)]
+    inserted: &'a [(Box<dyn Reflect>, Box<dyn Reflect>)],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for MapDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let registry = self.registry;
+        let mut state = serializer.serialize_map(Some(3))?;
+        state.serialize_entry(
+            diff_fields::REMOVED,
+            &ValuesSerializer {
+                values: self.removed,
+                registry,
+            },
+        )?;
+        state.serialize_entry(
+            diff_fields::CHANGED,
+            &SeqSerializer(self.changed.iter().map(|(key, diff)| {
+                PairSerializer(
+                    ReflectSerializer::new(&**key, registry),
+                    DiffSerializer::new(diff, registry),
+                )
+            })),
+        )?;
+        state.serialize_entry(
+            diff_fields::INSERTED,
+            &SeqSerializer(self.inserted.iter().map(|(key, value)| {
+                PairSerializer(
+                    ReflectSerializer::new(&**key, registry),
+                    ReflectSerializer::new(&**value, registry),
+                )
+            })),
+        )?;
+        state.end()
+    }
+}
+
+struct ValuesSerializer<'a> {
+    values: &'a [Box<dyn Reflect>],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ValuesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.values.len()))?;
+        for value in self.values {
+            state.serialize_element(&ReflectSerializer::new(&**value, self.registry))?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes the items of an iterator as a sequence.
+struct SeqSerializer<I>(I);
+
+impl<I, T> Serialize for SeqSerializer<I>
+where
+    I: Iterator<Item = T> + ExactSizeIterator + Clone,
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.0.len()))?;
+        for item in self.0.clone() {
+            state.serialize_element(&item)?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes two values as a tuple.
+struct PairSerializer<A, B>(A, B);
+
+impl<A: Serialize, B: Serialize> Serialize for PairSerializer<A, B> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&self.0)?;
+        state.serialize_element(&self.1)?;
+        state.end()
+    }
+}
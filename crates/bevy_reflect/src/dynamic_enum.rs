@@ -0,0 +1,330 @@
+use crate::{
+    enum_hash, enum_partial_eq, DynamicStruct, DynamicTuple, Enum, Reflect, ReflectMut, ReflectRef,
+    Struct, Tuple, VariantFieldIter, VariantType,
+};
+use std::any::Any;
+
+/// A dynamic representation of an enum variant.
+#[derive(Default)]
+pub enum DynamicVariant {
+    #[default]
+    Unit,
+    Tuple(DynamicTuple),
+    Struct(DynamicStruct),
+}
+
+impl Clone for DynamicVariant {
+    fn clone(&self) -> Self {
+        match self {
+            DynamicVariant::Unit => DynamicVariant::Unit,
+            DynamicVariant::Tuple(data) => DynamicVariant::Tuple(data.clone_dynamic()),
+            DynamicVariant::Struct(data) => DynamicVariant::Struct(data.clone_dynamic()),
+        }
+    }
+}
+
+impl From<DynamicTuple> for DynamicVariant {
+    fn from(dyn_tuple: DynamicTuple) -> Self {
+        Self::Tuple(dyn_tuple)
+    }
+}
+
+impl From<DynamicStruct> for DynamicVariant {
+    fn from(dyn_struct: DynamicStruct) -> Self {
+        Self::Struct(dyn_struct)
+    }
+}
+
+impl From<()> for DynamicVariant {
+    fn from(_: ()) -> Self {
+        Self::Unit
+    }
+}
+
+/// An enum which allows its variant to be changed at runtime.
+///
+/// # Example
+///
+/// ```
+/// use bevy_reflect::{DynamicEnum, DynamicVariant, Enum, Reflect};
+///
+/// #[derive(Reflect)]
+/// enum Shape {
+///     Point,
+///     Circle { radius: f32 },
+/// }
+///
+/// # fn main() {
+/// // The original enum value
+/// let mut value = Shape::Circle { radius: 1.0 };
+///
+/// // Create a DynamicEnum to represent the new value
+/// let dyn_enum = DynamicEnum::new(value.type_name(), 0, "Point", DynamicVariant::Unit);
+///
+/// // Apply the DynamicEnum as a patch to the original value
+/// value.apply(&dyn_enum);
+///
+/// assert_eq!("Point", value.variant_name());
+/// # }
+/// ```
+#[derive(Default)]
+pub struct DynamicEnum {
+    name: String,
+    variant_name: String,
+    variant_index: usize,
+    variant: DynamicVariant,
+}
+
+impl DynamicEnum {
+    /// Creates a new [`DynamicEnum`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: The type name of the enum
+    /// * `variant_index`: The index of the variant to set
+    /// * `variant_name`: The name of the variant to set
+    /// * `variant`: The variant data
+    pub fn new<I: Into<String>, V: Into<DynamicVariant>>(
+        name: I,
+        variant_index: usize,
+        variant_name: I,
+        variant: V,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            variant_index,
+            variant_name: variant_name.into(),
+            variant: variant.into(),
+        }
+    }
+
+    /// Returns the type name of the enum.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Sets the type name of the enum.
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    /// Sets the current variant along with its index.
+    pub fn set_variant<I: Into<String>, V: Into<DynamicVariant>>(
+        &mut self,
+        variant_index: usize,
+        name: I,
+        variant: V,
+    ) {
+        self.variant_index = variant_index;
+        self.variant_name = name.into();
+        self.variant = variant.into();
+    }
+
+    /// Creates a new [`DynamicEnum`] from an existing one.
+    ///
+    /// This is functionally the same as [`DynamicEnum::from_ref`] except it takes an owned value.
+    pub fn from<TEnum: Enum>(value: TEnum) -> Self {
+        Self::from_ref(&value)
+    }
+
+    /// Creates a new [`DynamicEnum`] from a reference to an existing one.
+    pub fn from_ref<TEnum: Enum + ?Sized>(value: &TEnum) -> Self {
+        let variant = match value.variant_type() {
+            VariantType::Unit => DynamicVariant::Unit,
+            VariantType::Tuple => {
+                let mut data = DynamicTuple::default();
+                for field in value.iter_fields() {
+                    data.insert_boxed(field.value().clone_value());
+                }
+                DynamicVariant::Tuple(data)
+            }
+            VariantType::Struct => {
+                let mut data = DynamicStruct::default();
+                for field in value.iter_fields() {
+                    let name = field.name().unwrap();
+                    data.insert_boxed(name, field.value().clone_value());
+                }
+                DynamicVariant::Struct(data)
+            }
+        };
+
+        Self::new(
+            value.type_name().to_string(),
+            value.variant_index(),
+            value.variant_name().to_string(),
+            variant,
+        )
+    }
+}
+
+impl Enum for DynamicEnum {
+    fn field(&self, name: &str) -> Option<&dyn Reflect> {
+        if let DynamicVariant::Struct(data) = &self.variant {
+            data.field(name)
+        } else {
+            None
+        }
+    }
+
+    fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
+        match &self.variant {
+            DynamicVariant::Tuple(data) => data.field(index),
+            DynamicVariant::Struct(data) => data.field_at(index),
+            DynamicVariant::Unit => None,
+        }
+    }
+
+    fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+        if let DynamicVariant::Struct(data) = &mut self.variant {
+            data.field_mut(name)
+        } else {
+            None
+        }
+    }
+
+    fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        match &mut self.variant {
+            DynamicVariant::Tuple(data) => data.field_mut(index),
+            DynamicVariant::Struct(data) => data.field_at_mut(index),
+            DynamicVariant::Unit => None,
+        }
+    }
+
+    fn index_of(&self, name: &str) -> Option<usize> {
+        if let DynamicVariant::Struct(data) = &self.variant {
+            (0..data.field_len()).find(|&index| data.name_at(index) == Some(name))
+        } else {
+            None
+        }
+    }
+
+    fn name_at(&self, index: usize) -> Option<&str> {
+        if let DynamicVariant::Struct(data) = &self.variant {
+            data.name_at(index)
+        } else {
+            None
+        }
+    }
+
+    fn iter_fields(&self) -> VariantFieldIter {
+        VariantFieldIter::new(self)
+    }
+
+    fn field_len(&self) -> usize {
+        match &self.variant {
+            DynamicVariant::Unit => 0,
+            DynamicVariant::Tuple(data) => data.field_len(),
+            DynamicVariant::Struct(data) => data.field_len(),
+        }
+    }
+
+    fn variant_name(&self) -> &str {
+        &self.variant_name
+    }
+
+    fn variant_index(&self) -> usize {
+        self.variant_index
+    }
+
+    fn variant_type(&self) -> VariantType {
+        match &self.variant {
+            DynamicVariant::Unit => VariantType::Unit,
+            DynamicVariant::Tuple(..) => VariantType::Tuple,
+            DynamicVariant::Struct(..) => VariantType::Struct,
+        }
+    }
+
+    fn clone_dynamic(&self) -> DynamicEnum {
+        Self {
+            name: self.name.clone(),
+            variant_index: self.variant_index,
+            variant_name: self.variant_name.clone(),
+            variant: self.variant.clone(),
+        }
+    }
+}
+
+// SAFE: any and any_mut both return self
+unsafe impl Reflect for DynamicEnum {
+    #[inline]
+    fn type_name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    fn any(&self) -> &dyn Any {
+        self
+    }
+
+    #[inline]
+    fn any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone_dynamic())
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef {
+        ReflectRef::Enum(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut {
+        ReflectMut::Enum(self)
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        if let ReflectRef::Enum(value) = value.reflect_ref() {
+            if Enum::variant_name(self) == value.variant_name()
+                && Enum::variant_type(self) == value.variant_type()
+            {
+                // Same variant -> just update fields
+                for (index, field) in value.iter_fields().enumerate() {
+                    let target = match field.name() {
+                        Some(name) => Enum::field_mut(self, name),
+                        None => Enum::field_at_mut(self, index),
+                    };
+                    if let Some(target) = target {
+                        target.apply(field.value());
+                    }
+                }
+            } else {
+                // New variant -> perform a switch
+                let dyn_enum = DynamicEnum::from_ref(value);
+                self.set_variant(
+                    dyn_enum.variant_index,
+                    dyn_enum.variant_name,
+                    dyn_enum.variant,
+                );
+            }
+        } else {
+            panic!("Attempted to apply non-enum type to enum type.");
+        }
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        enum_hash(self)
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        enum_partial_eq(self, value)
+    }
+}
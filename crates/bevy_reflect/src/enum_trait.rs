@@ -0,0 +1,468 @@
+use crate::{DynamicEnum, NamedField, Reflect, ReflectRef, UnnamedField};
+use bevy_utils::HashMap;
+use std::any::{Any, TypeId};
+use std::hash::{Hash, Hasher};
+use std::slice::Iter;
+
+/// A reflected Rust enum.
+///
+/// Implementors of this trait allow the fields of their active variant to be
+/// addressed by name (for struct variants) or by index (for both struct and
+/// tuple variants).
+///
+/// This trait is automatically implemented for `enum` types when using
+/// `#[derive(Reflect)]`, unless the `#[reflect_value]` attribute is used.
+///
+/// # Example
+///
+/// ```
+/// use bevy_reflect::{Enum, Reflect, VariantType};
+///
+/// #[derive(Reflect)]
+/// enum Foo {
+///     A,
+///     B(usize),
+///     C { value: f32 },
+/// }
+///
+/// # fn main() {
+/// let foo = Foo::C { value: 1.5 };
+///
+/// assert_eq!(foo.variant_name(), "C");
+/// assert_eq!(foo.variant_index(), 2);
+/// assert_eq!(foo.variant_type(), VariantType::Struct);
+///
+/// let value = foo.field("value").unwrap();
+/// assert_eq!(value.downcast_ref::<f32>(), Some(&1.5));
+/// # }
+/// ```
+///
+/// # Implementation
+///
+/// Deriving `Reflect` on an enum requires every field of its variants (other than
+/// those marked `#[reflect(ignore)]`) to implement [`FromReflect`], so that
+/// [`Reflect::apply`] can switch the value to another variant.
+///
+/// [`FromReflect`]: crate::FromReflect
+pub trait Enum: Reflect {
+    /// Returns a reference to the value of the field (in the current variant) with the given name.
+    ///
+    /// For non-[`VariantType::Struct`] variants, this should return `None`.
+    fn field(&self, name: &str) -> Option<&dyn Reflect>;
+
+    /// Returns a reference to the value of the field (in the current variant) at the given index.
+    fn field_at(&self, index: usize) -> Option<&dyn Reflect>;
+
+    /// Returns a mutable reference to the value of the field (in the current variant) with the
+    /// given name.
+    ///
+    /// For non-[`VariantType::Struct`] variants, this should return `None`.
+    fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect>;
+
+    /// Returns a mutable reference to the value of the field (in the current variant) at the
+    /// given index.
+    fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect>;
+
+    /// Returns the index of the field (in the current variant) with the given name.
+    ///
+    /// For non-[`VariantType::Struct`] variants, this should return `None`.
+    fn index_of(&self, name: &str) -> Option<usize>;
+
+    /// Returns the name of the field (in the current variant) with the given index.
+    ///
+    /// For non-[`VariantType::Struct`] variants, this should return `None`.
+    fn name_at(&self, index: usize) -> Option<&str>;
+
+    /// Returns an iterator over the values of the current variant's fields.
+    fn iter_fields(&self) -> VariantFieldIter;
+
+    /// Returns the number of fields in the current variant.
+    fn field_len(&self) -> usize;
+
+    /// The name of the current variant.
+    fn variant_name(&self) -> &str;
+
+    /// The index of the current variant, in declaration order.
+    fn variant_index(&self) -> usize;
+
+    /// The type of the current variant.
+    fn variant_type(&self) -> VariantType;
+
+    /// Clones the enum into a [`DynamicEnum`].
+    fn clone_dynamic(&self) -> DynamicEnum;
+
+    /// Returns true if the current variant's type matches the given one.
+    fn is_variant(&self, variant_type: VariantType) -> bool {
+        self.variant_type() == variant_type
+    }
+
+    /// Returns the full path to the current variant, such as `my_crate::MyEnum::MyVariant`.
+    fn variant_path(&self) -> String {
+        format!("{}::{}", self.type_name(), self.variant_name())
+    }
+}
+
+/// Describes the form of an enum variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariantType {
+    /// Struct enums take the form:
+    ///
+    /// ```
+    /// enum MyEnum {
+    ///   A {
+    ///     foo: usize
+    ///   }
+    /// }
+    /// ```
+    Struct,
+    /// Tuple enums take the form:
+    ///
+    /// ```
+    /// enum MyEnum {
+    ///   A(usize)
+    /// }
+    /// ```
+    Tuple,
+    /// Unit enums take the form:
+    ///
+    /// ```
+    /// enum MyEnum {
+    ///   A
+    /// }
+    /// ```
+    Unit,
+}
+
+/// A container for compile-time enum info.
+#[derive(Debug, Clone)]
+pub struct EnumInfo {
+    type_name: &'static str,
+    type_id: TypeId,
+    variants: Box<[VariantInfo]>,
+    variant_indices: HashMap<&'static str, usize>,
+}
+
+impl EnumInfo {
+    /// Create a new [`EnumInfo`].
+    ///
+    /// # Arguments
+    ///
+    /// * `variants`: The variants of this enum in the order they are defined
+    pub fn new<TEnum: Enum>(variants: &[VariantInfo]) -> Self {
+        let variant_indices = variants
+            .iter()
+            .enumerate()
+            .map(|(index, variant)| (variant.name(), index))
+            .collect();
+
+        Self {
+            type_name: std::any::type_name::<TEnum>(),
+            type_id: TypeId::of::<TEnum>(),
+            variants: variants.to_vec().into_boxed_slice(),
+            variant_indices,
+        }
+    }
+
+    /// Get the variant with the given name.
+    pub fn variant(&self, name: &str) -> Option<&VariantInfo> {
+        self.variant_indices
+            .get(name)
+            .map(|index| &self.variants[*index])
+    }
+
+    /// Get the variant at the given index.
+    pub fn variant_at(&self, index: usize) -> Option<&VariantInfo> {
+        self.variants.get(index)
+    }
+
+    /// Get the index of the variant with the given name.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.variant_indices.get(name).copied()
+    }
+
+    /// Iterate over the variants of this enum.
+    pub fn iter(&self) -> Iter<'_, VariantInfo> {
+        self.variants.iter()
+    }
+
+    /// The number of variants in this enum.
+    pub fn variant_len(&self) -> usize {
+        self.variants.len()
+    }
+
+    /// The [type name] of the enum.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The [`TypeId`] of the enum.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Check if the given type matches the enum type.
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id
+    }
+}
+
+/// Compile-time info for a single enum variant.
+#[derive(Debug, Clone)]
+pub enum VariantInfo {
+    Struct(StructVariantInfo),
+    Tuple(TupleVariantInfo),
+    Unit(UnitVariantInfo),
+}
+
+impl VariantInfo {
+    /// The name of the variant.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Struct(info) => info.name(),
+            Self::Tuple(info) => info.name(),
+            Self::Unit(info) => info.name(),
+        }
+    }
+
+    /// The [`VariantType`] of the variant.
+    pub fn variant_type(&self) -> VariantType {
+        match self {
+            Self::Struct(..) => VariantType::Struct,
+            Self::Tuple(..) => VariantType::Tuple,
+            Self::Unit(..) => VariantType::Unit,
+        }
+    }
+}
+
+/// Compile-time info for a struct variant.
+#[derive(Debug, Clone)]
+pub struct StructVariantInfo {
+    name: &'static str,
+    fields: Box<[NamedField]>,
+    field_indices: HashMap<&'static str, usize>,
+}
+
+impl StructVariantInfo {
+    /// Create a new [`StructVariantInfo`].
+    pub fn new(name: &'static str, fields: &[NamedField]) -> Self {
+        let field_indices = fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| (field.name(), index))
+            .collect();
+
+        Self {
+            name,
+            fields: fields.to_vec().into_boxed_slice(),
+            field_indices,
+        }
+    }
+
+    /// The name of the variant.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the field with the given name.
+    pub fn field(&self, name: &str) -> Option<&NamedField> {
+        self.field_indices
+            .get(name)
+            .map(|index| &self.fields[*index])
+    }
+
+    /// Get the field at the given index.
+    pub fn field_at(&self, index: usize) -> Option<&NamedField> {
+        self.fields.get(index)
+    }
+
+    /// Get the index of the field with the given name.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.field_indices.get(name).copied()
+    }
+
+    /// Iterate over the fields of this variant.
+    pub fn iter(&self) -> Iter<'_, NamedField> {
+        self.fields.iter()
+    }
+
+    /// The total number of fields in this variant.
+    pub fn field_len(&self) -> usize {
+        self.fields.len()
+    }
+}
+
+/// Compile-time info for a tuple variant.
+#[derive(Debug, Clone)]
+pub struct TupleVariantInfo {
+    name: &'static str,
+    fields: Box<[UnnamedField]>,
+}
+
+impl TupleVariantInfo {
+    /// Create a new [`TupleVariantInfo`].
+    pub fn new(name: &'static str, fields: &[UnnamedField]) -> Self {
+        Self {
+            name,
+            fields: fields.to_vec().into_boxed_slice(),
+        }
+    }
+
+    /// The name of the variant.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the field at the given index.
+    pub fn field_at(&self, index: usize) -> Option<&UnnamedField> {
+        self.fields.get(index)
+    }
+
+    /// Iterate over the fields of this variant.
+    pub fn iter(&self) -> Iter<'_, UnnamedField> {
+        self.fields.iter()
+    }
+
+    /// The total number of fields in this variant.
+    pub fn field_len(&self) -> usize {
+        self.fields.len()
+    }
+}
+
+/// Compile-time info for a unit variant.
+#[derive(Debug, Clone)]
+pub struct UnitVariantInfo {
+    name: &'static str,
+}
+
+impl UnitVariantInfo {
+    /// Create a new [`UnitVariantInfo`].
+    pub fn new(name: &'static str) -> Self {
+        Self { name }
+    }
+
+    /// The name of the variant.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// An iterator over the fields in the current enum variant.
+pub struct VariantFieldIter<'a> {
+    container: &'a dyn Enum,
+    index: usize,
+}
+
+impl<'a> VariantFieldIter<'a> {
+    pub fn new(container: &'a dyn Enum) -> Self {
+        Self {
+            container,
+            index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for VariantFieldIter<'a> {
+    type Item = VariantField<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = match self.container.variant_type() {
+            VariantType::Unit => None,
+            VariantType::Tuple => Some(VariantField::Tuple(self.container.field_at(self.index)?)),
+            VariantType::Struct => {
+                let name = self.container.name_at(self.index)?;
+                Some(VariantField::Struct(name, self.container.field(name)?))
+            }
+        };
+        self.index += 1;
+        value
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.container.field_len();
+        (size, Some(size))
+    }
+}
+
+impl<'a> ExactSizeIterator for VariantFieldIter<'a> {}
+
+/// A field of the current enum variant, as returned by [`VariantFieldIter`].
+pub enum VariantField<'a> {
+    Struct(&'a str, &'a dyn Reflect),
+    Tuple(&'a dyn Reflect),
+}
+
+impl<'a> VariantField<'a> {
+    /// Returns the name of the field, or `None` if it belongs to a tuple variant.
+    pub fn name(&self) -> Option<&'a str> {
+        if let Self::Struct(name, ..) = self {
+            Some(*name)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of the field.
+    pub fn value(&self) -> &'a dyn Reflect {
+        match self {
+            Self::Struct(.., value) | Self::Tuple(value) => *value,
+        }
+    }
+}
+
+/// Returns the `u64` hash of the given [enum](Enum).
+///
+/// The hash covers the type name, the variant name and the hash of each field.
+/// Returns `None` if any of the fields does not support hashing.
+#[inline]
+pub fn enum_hash<TEnum: Enum>(value: &TEnum) -> Option<u64> {
+    let mut hasher = crate::ReflectHasher::default();
+    value.type_name().hash(&mut hasher);
+    value.variant_name().hash(&mut hasher);
+    value.variant_type().hash(&mut hasher);
+    for field in value.iter_fields() {
+        hasher.write_u64(field.value().reflect_hash()?);
+    }
+    Some(hasher.finish())
+}
+
+/// Compares an [`Enum`] with a [`Reflect`] value.
+///
+/// Returns true if and only if all of the following are true:
+/// - `b` is an enum;
+/// - `b` is the same variant as `a`;
+/// - For each field in `a`, `b` contains a field with the same name (for struct variants) or
+///   index (for tuple variants) and [`Reflect::reflect_partial_eq`] returns `Some(true)` for
+///   the two field values.
+#[inline]
+pub fn enum_partial_eq<TEnum: Enum>(a: &TEnum, b: &dyn Reflect) -> Option<bool> {
+    let b = if let ReflectRef::Enum(b) = b.reflect_ref() {
+        b
+    } else {
+        return Some(false);
+    };
+
+    if a.variant_name() != b.variant_name()
+        || a.variant_type() != b.variant_type()
+        || a.field_len() != b.field_len()
+    {
+        return Some(false);
+    }
+
+    for (index, field) in a.iter_fields().enumerate() {
+        let other = match field.name() {
+            Some(name) => b.field(name),
+            None => b.field_at(index),
+        };
+        if let Some(other) = other {
+            if let Some(false) | None = field.value().reflect_partial_eq(other) {
+                return Some(false);
+            }
+        } else {
+            return Some(false);
+        }
+    }
+
+    Some(true)
+}
@@ -0,0 +1,407 @@
+use crate::{FromType, Reflect};
+use bevy_utils::HashMap;
+use std::{any::TypeId, slice::Iter};
+use thiserror::Error;
+
+/// The arguments passed to a [`ReflectFunction`].
+pub type ArgList = Vec<Box<dyn Reflect>>;
+
+/// The type-erased body of a [`ReflectFunction`].
+///
+/// The receiver is `Some` for methods and the argument count has already been
+/// checked when this is called.
+pub type FunctionPtr =
+    fn(Option<&mut dyn Reflect>, ArgList) -> Result<Box<dyn Reflect>, FunctionError>;
+
+/// An error returned when calling a [`ReflectFunction`].
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum FunctionError {
+    #[error("expected {expected} arguments but received {received}")]
+    ArgCount { expected: usize, received: usize },
+    #[error("expected argument {index} to be of type `{expected}` but received `{received}`")]
+    InvalidArg {
+        index: usize,
+        expected: &'static str,
+        received: String,
+    },
+    #[error("expected a receiver of type `{expected}` but received `{received}`")]
+    InvalidReceiver {
+        expected: &'static str,
+        received: String,
+    },
+    #[error("the method `{0}` must be called with a receiver")]
+    MissingReceiver(&'static str),
+    #[error("the function `{0}` does not take a receiver")]
+    UnexpectedReceiver(&'static str),
+}
+
+/// How a [`ReflectFunction`] takes its receiver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FunctionReceiver {
+    /// An associated function without a receiver.
+    None,
+    /// A method taking `&self`.
+    Ref,
+    /// A method taking `&mut self`.
+    Mut,
+}
+
+/// A container for compile-time info about an argument of a [`ReflectFunction`].
+#[derive(Debug, Clone)]
+pub struct ArgInfo {
+    name: &'static str,
+    type_name: &'static str,
+    type_id: TypeId,
+}
+
+impl ArgInfo {
+    pub fn new<T: Reflect>(name: &'static str) -> Self {
+        Self {
+            name,
+            type_name: std::any::type_name::<T>(),
+            type_id: TypeId::of::<T>(),
+        }
+    }
+
+    /// The name of the argument.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The [type name] of the argument.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The [`TypeId`] of the argument.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Check if the given type matches the argument type.
+    pub fn is<T: Reflect>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id
+    }
+}
+
+/// A function or method which can be called with reflected arguments.
+///
+/// These are usually generated with the [`#[reflect_functions]`][0] attribute
+/// and stored in [`ReflectFunctions`].
+///
+/// [0]: crate::reflect_functions
+#[derive(Clone)]
+pub struct ReflectFunction {
+    name: &'static str,
+    receiver: FunctionReceiver,
+    args: Box<[ArgInfo]>,
+    return_type_name: &'static str,
+    func: FunctionPtr,
+}
+
+impl ReflectFunction {
+    pub fn new(
+        name: &'static str,
+        receiver: FunctionReceiver,
+        args: &[ArgInfo],
+        return_type_name: &'static str,
+        func: FunctionPtr,
+    ) -> Self {
+        Self {
+            name,
+            receiver,
+            args: args.to_vec().into_boxed_slice(),
+            return_type_name,
+            func,
+        }
+    }
+
+    /// The name of the function.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// How the function takes its receiver.
+    pub fn receiver(&self) -> FunctionReceiver {
+        self.receiver
+    }
+
+    /// Iterate over the arguments of the function, excluding the receiver.
+    pub fn args(&self) -> Iter<'_, ArgInfo> {
+        self.args.iter()
+    }
+
+    /// The number of arguments of the function, excluding the receiver.
+    pub fn arg_len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// The [type name] of the function's return value.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn return_type_name(&self) -> &'static str {
+        self.return_type_name
+    }
+
+    /// Calls an associated function.
+    ///
+    /// Functions without a return value return `()`.
+    pub fn call(&self, args: ArgList) -> Result<Box<dyn Reflect>, FunctionError> {
+        if self.receiver != FunctionReceiver::None {
+            return Err(FunctionError::MissingReceiver(self.name));
+        }
+        self.check_arg_count(&args)?;
+        (self.func)(None, args)
+    }
+
+    /// Calls a method on `receiver`.
+    ///
+    /// Methods without a return value return `()`.
+    pub fn call_method(
+        &self,
+        receiver: &mut dyn Reflect,
+        args: ArgList,
+    ) -> Result<Box<dyn Reflect>, FunctionError> {
+        if self.receiver == FunctionReceiver::None {
+            return Err(FunctionError::UnexpectedReceiver(self.name));
+        }
+        self.check_arg_count(&args)?;
+        (self.func)(Some(receiver), args)
+    }
+
+    fn check_arg_count(&self, args: &ArgList) -> Result<(), FunctionError> {
+        if args.len() == self.args.len() {
+            Ok(())
+        } else {
+            Err(FunctionError::ArgCount {
+                expected: self.args.len(),
+                received: args.len(),
+            })
+        }
+    }
+}
+
+/// A trait implemented by the [`#[reflect_functions]`][0] attribute to list
+/// the reflected functions of a type.
+///
+/// [0]: crate::reflect_functions
+pub trait TypeFunctions: Reflect {
+    fn functions() -> Vec<ReflectFunction>;
+}
+
+/// Type data containing the reflected functions of a type.
+///
+/// A [`ReflectFunctions`] for type `T` can be obtained via
+/// [`FromType::from_type`], or registered with `#[reflect(Functions)]`.
+#[derive(Clone)]
+pub struct ReflectFunctions {
+    functions: Box<[ReflectFunction]>,
+    function_indices: HashMap<&'static str, usize>,
+}
+
+impl ReflectFunctions {
+    pub fn new(functions: Vec<ReflectFunction>) -> Self {
+        let function_indices = functions
+            .iter()
+            .enumerate()
+            .map(|(index, function)| (function.name(), index))
+            .collect();
+
+        Self {
+            functions: functions.into_boxed_slice(),
+            function_indices,
+        }
+    }
+
+    /// Get the function with the given name.
+    pub fn get(&self, name: &str) -> Option<&ReflectFunction> {
+        self.function_indices
+            .get(name)
+            .map(|index| &self.functions[*index])
+    }
+
+    /// Iterate over the functions.
+    pub fn iter(&self) -> Iter<'_, ReflectFunction> {
+        self.functions.iter()
+    }
+
+    /// The number of functions.
+    pub fn len(&self) -> usize {
+        self.functions.len()
+    }
+
+    /// Returns true if there are no functions.
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+}
+
+impl<T: TypeFunctions> FromType<T> for ReflectFunctions {
+    fn from_type() -> Self {
+        Self::new(T::functions())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{reflect_functions, TypeRegistry};
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(Functions)]
+    struct Health {
+        current: u32,
+        max: u32,
+    }
+
+    #[reflect_functions]
+    impl Health {
+        pub fn new(max: u32) -> Self {
+            Self { current: max, max }
+        }
+
+        pub fn heal(&mut self, amount: u32) {
+            self.current = self.clamp(self.current + amount);
+        }
+
+        pub fn damage(&mut self, amount: u32, multiplier: f32) -> u32 {
+            let amount = (amount as f32 * multiplier) as u32;
+            self.current = self.current.saturating_sub(amount);
+            self.current
+        }
+
+        pub fn is_full(&self) -> bool {
+            self.current == self.max
+        }
+
+        fn clamp(&self, value: u32) -> u32 {
+            value.min(self.max)
+        }
+
+        #[reflect(skip)]
+        pub fn max(&self) -> &u32 {
+            &self.max
+        }
+    }
+
+    fn functions() -> ReflectFunctions {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Health>();
+        registry
+            .get_type_data::<ReflectFunctions>(TypeId::of::<Health>())
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn function_info() {
+        let functions = functions();
+        assert_eq!(functions.len(), 4);
+        assert!(functions.get("clamp").is_none());
+        assert!(functions.get("max").is_none());
+        assert_eq!(Health::new(10).max(), &10);
+
+        let damage = functions.get("damage").unwrap();
+        assert_eq!(damage.receiver(), FunctionReceiver::Mut);
+        assert_eq!(damage.return_type_name(), std::any::type_name::<u32>());
+        let args = damage.args().collect::<Vec<_>>();
+        assert_eq!(args[0].name(), "amount");
+        assert!(args[0].is::<u32>());
+        assert_eq!(args[1].name(), "multiplier");
+        assert!(args[1].is::<f32>());
+
+        assert_eq!(
+            functions.get("new").unwrap().receiver(),
+            FunctionReceiver::None
+        );
+        assert_eq!(
+            functions.get("is_full").unwrap().receiver(),
+            FunctionReceiver::Ref
+        );
+    }
+
+    #[test]
+    fn call_functions() {
+        let functions = functions();
+
+        let mut health = functions
+            .get("new")
+            .unwrap()
+            .call(vec![Box::new(100u32)])
+            .unwrap();
+        assert_eq!(
+            health.downcast_ref::<Health>(),
+            Some(&Health {
+                current: 100,
+                max: 100
+            })
+        );
+
+        let remaining = functions
+            .get("damage")
+            .unwrap()
+            .call_method(health.as_mut(), vec![Box::new(20u32), Box::new(1.5f32)])
+            .unwrap();
+        assert_eq!(remaining.downcast_ref::<u32>(), Some(&70));
+
+        let is_full = functions.get("is_full").unwrap();
+        let full = is_full.call_method(health.as_mut(), Vec::new()).unwrap();
+        assert_eq!(full.downcast_ref::<bool>(), Some(&false));
+
+        let unit = functions
+            .get("heal")
+            .unwrap()
+            .call_method(health.as_mut(), vec![Box::new(50u32)])
+            .unwrap();
+        assert!(unit.is::<()>());
+        let full = is_full.call_method(health.as_mut(), Vec::new()).unwrap();
+        assert_eq!(full.downcast_ref::<bool>(), Some(&true));
+    }
+
+    #[test]
+    fn call_errors() {
+        let functions = functions();
+        let heal = functions.get("heal").unwrap();
+        let mut health = Health::new(10);
+
+        assert_eq!(
+            heal.call(vec![Box::new(1u32)]).unwrap_err(),
+            FunctionError::MissingReceiver("heal")
+        );
+        assert_eq!(
+            functions
+                .get("new")
+                .unwrap()
+                .call_method(&mut health, vec![Box::new(1u32)])
+                .unwrap_err(),
+            FunctionError::UnexpectedReceiver("new")
+        );
+        assert_eq!(
+            heal.call_method(&mut health, Vec::new()).unwrap_err(),
+            FunctionError::ArgCount {
+                expected: 1,
+                received: 0
+            }
+        );
+        assert_eq!(
+            heal.call_method(&mut health, vec![Box::new(1.0f32)])
+                .unwrap_err(),
+            FunctionError::InvalidArg {
+                index: 0,
+                expected: std::any::type_name::<u32>(),
+                received: std::any::type_name::<f32>().to_string(),
+            }
+        );
+        assert_eq!(
+            heal.call_method(&mut 5u32, vec![Box::new(1u32)])
+                .unwrap_err(),
+            FunctionError::InvalidReceiver {
+                expected: std::any::type_name::<Health>(),
+                received: std::any::type_name::<u32>().to_string(),
+            }
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use smallvec::SmallVec;
 use std::any::Any;
 
-use crate::{Array, ArrayIter, FromReflect, List, Reflect, ReflectMut, ReflectRef};
+use crate::{
+    Array, ArrayIter, FromReflect, List, ListInfo, Reflect, ReflectMut, ReflectRef, TypeInfo, Typed,
+};
 
 impl<T: smallvec::Array + Send + Sync + 'static> Array for SmallVec<T>
 where
@@ -50,6 +52,10 @@ where
         });
         SmallVec::push(self, value);
     }
+
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        SmallVec::pop(self).map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
 }
 
 // SAFE: any and any_mut both return self
@@ -103,6 +109,15 @@ where
     }
 }
 
+impl<T: smallvec::Array + Send + Sync + 'static> Typed for SmallVec<T>
+where
+    T::Item: FromReflect + Clone,
+{
+    fn type_info() -> TypeInfo {
+        TypeInfo::List(ListInfo::new::<Self, T::Item>())
+    }
+}
+
 impl<T: smallvec::Array + Send + Sync + 'static> FromReflect for SmallVec<T>
 where
     T::Item: FromReflect + Clone,
@@ -1,8 +1,8 @@
 use crate as bevy_reflect;
 use crate::{
-    map_partial_eq, serde::Serializable, Array, ArrayIter, DynamicMap, FromReflect, FromType,
-    GetTypeRegistration, List, Map, MapIter, Reflect, ReflectDeserialize, ReflectMut, ReflectRef,
-    TypeRegistration,
+    map_partial_eq, serde::Serializable, Array, ArrayInfo, ArrayIter, DynamicMap, FromReflect,
+    FromType, GetTypeRegistration, List, ListInfo, Map, MapInfo, MapInsertError, MapIter, Reflect,
+    ReflectDeserialize, ReflectMut, ReflectRef, TypeInfo, TypeRegistration, Typed, ValueInfo,
 };
 
 use bevy_reflect_derive::{impl_from_reflect_value, impl_reflect_value};
@@ -102,6 +102,10 @@ impl<T: FromReflect> List for Vec<T> {
         });
         Vec::push(self, value);
     }
+
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        Vec::pop(self).map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
 }
 
 // SAFE: any and any_mut both return self
@@ -160,6 +164,12 @@ unsafe impl<T: FromReflect> Reflect for Vec<T> {
     }
 }
 
+impl<T: FromReflect> Typed for Vec<T> {
+    fn type_info() -> TypeInfo {
+        TypeInfo::List(ListInfo::new::<Self, T>())
+    }
+}
+
 impl<T: FromReflect + for<'de> Deserialize<'de>> GetTypeRegistration for Vec<T> {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<Vec<T>>();
@@ -182,7 +192,7 @@ impl<T: FromReflect> FromReflect for Vec<T> {
     }
 }
 
-impl<K: Reflect + Eq + Hash, V: Reflect> Map for HashMap<K, V> {
+impl<K: FromReflect + Eq + Hash, V: FromReflect> Map for HashMap<K, V> {
     fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
         key.downcast_ref::<K>()
             .and_then(|key| HashMap::get(self, key))
@@ -220,10 +230,35 @@ impl<K: Reflect + Eq + Hash, V: Reflect> Map for HashMap<K, V> {
         }
         dynamic_map
     }
+
+    fn insert_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Result<Option<Box<dyn Reflect>>, MapInsertError> {
+        let key = match key.take::<K>() {
+            Ok(key) => key,
+            Err(key) => K::from_reflect(&*key)
+                .ok_or_else(|| MapInsertError::InvalidKey(key.type_name().to_string()))?,
+        };
+        let value = match value.take::<V>() {
+            Ok(value) => value,
+            Err(value) => V::from_reflect(&*value)
+                .ok_or_else(|| MapInsertError::InvalidValue(value.type_name().to_string()))?,
+        };
+        Ok(HashMap::insert(self, key, value)
+            .map(|old_value| Box::new(old_value) as Box<dyn Reflect>))
+    }
+
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        key.downcast_ref::<K>()
+            .and_then(|key| HashMap::remove(self, key))
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
 }
 
 // SAFE: any and any_mut both return self
-unsafe impl<K: Reflect + Eq + Hash, V: Reflect> Reflect for HashMap<K, V> {
+unsafe impl<K: FromReflect + Eq + Hash, V: FromReflect> Reflect for HashMap<K, V> {
     fn type_name(&self) -> &str {
         std::any::type_name::<Self>()
     }
@@ -278,10 +313,16 @@ unsafe impl<K: Reflect + Eq + Hash, V: Reflect> Reflect for HashMap<K, V> {
     }
 }
 
+impl<K: FromReflect + Eq + Hash, V: FromReflect> Typed for HashMap<K, V> {
+    fn type_info() -> TypeInfo {
+        TypeInfo::Map(MapInfo::new::<Self, K, V>())
+    }
+}
+
 impl<K, V> GetTypeRegistration for HashMap<K, V>
 where
-    K: Reflect + Clone + Eq + Hash + for<'de> Deserialize<'de>,
-    V: Reflect + Clone + for<'de> Deserialize<'de>,
+    K: FromReflect + Clone + Eq + Hash + for<'de> Deserialize<'de>,
+    V: FromReflect + Clone + for<'de> Deserialize<'de>,
 {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<Self>();
@@ -400,6 +441,12 @@ unsafe impl<T: Reflect, const N: usize> Reflect for [T; N] {
     }
 }
 
+impl<T: Reflect, const N: usize> Typed for [T; N] {
+    fn type_info() -> TypeInfo {
+        TypeInfo::Array(ArrayInfo::new::<Self, T>(N))
+    }
+}
+
 impl<T: FromReflect, const N: usize> FromReflect for [T; N] {
     fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
         if let ReflectRef::Array(ref_array) = reflect.reflect_ref() {
@@ -521,6 +568,12 @@ unsafe impl Reflect for Cow<'static, str> {
     }
 }
 
+impl Typed for Cow<'static, str> {
+    fn type_info() -> TypeInfo {
+        TypeInfo::Value(ValueInfo::new::<Self>())
+    }
+}
+
 impl GetTypeRegistration for Cow<'static, str> {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<Cow<'static, str>>();
@@ -1,6 +1,10 @@
 #![doc = include_str!("../README.md")]
 
 mod array;
+mod dynamic_enum;
+mod enum_trait;
+mod fields;
+mod function;
 mod list;
 mod map;
 mod path;
@@ -8,6 +12,7 @@ mod reflect;
 mod struct_trait;
 mod tuple;
 mod tuple_struct;
+mod type_info;
 mod type_registry;
 mod type_uuid;
 mod impls {
@@ -24,6 +29,7 @@ mod impls {
     pub use self::std::*;
 }
 
+pub mod diff;
 pub mod serde;
 pub mod std_traits;
 
@@ -31,12 +37,16 @@ pub mod prelude {
     pub use crate::std_traits::*;
     #[doc(hidden)]
     pub use crate::{
-        reflect_trait, GetField, GetTupleStructField, Reflect, ReflectDeserialize, Struct,
-        TupleStruct,
+        reflect_functions, reflect_trait, GetField, GetTupleStructField, Reflect,
+        ReflectDeserialize, ReflectFunctions, Struct, TupleStruct,
     };
 }
 
 pub use array::*;
+pub use dynamic_enum::*;
+pub use enum_trait::*;
+pub use fields::*;
+pub use function::*;
 pub use impls::*;
 pub use list::*;
 pub use map::*;
@@ -45,6 +55,7 @@ pub use reflect::*;
 pub use struct_trait::*;
 pub use tuple::*;
 pub use tuple_struct::*;
+pub use type_info::*;
 pub use type_registry::*;
 pub use type_uuid::*;
 
@@ -199,6 +210,384 @@ mod tests {
         assert_eq!(4, *iter.next().unwrap().downcast_ref::<u64>().unwrap());
     }
 
+    #[test]
+    fn reflect_enum() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Foo {
+            A,
+            B(usize, #[reflect(ignore)] u8, String),
+            C { value: f32 },
+        }
+
+        let mut foo = Foo::B(1, 2, "hi".to_string());
+        assert_eq!(foo.variant_name(), "B");
+        assert_eq!(foo.variant_index(), 1);
+        assert_eq!(foo.variant_type(), VariantType::Tuple);
+        assert_eq!(foo.variant_path(), format!("{}::B", foo.type_name()));
+        assert_eq!(Enum::field_len(&foo), 2);
+        assert_eq!(foo.field_at(0).unwrap().downcast_ref::<usize>(), Some(&1));
+        assert_eq!(
+            foo.field_at(1).unwrap().downcast_ref::<String>(),
+            Some(&"hi".to_string())
+        );
+        assert!(foo.field_at(2).is_none());
+        assert!(Enum::field(&foo, "0").is_none());
+
+        *foo.field_at_mut(0)
+            .unwrap()
+            .downcast_mut::<usize>()
+            .unwrap() = 3;
+        assert_eq!(foo, Foo::B(3, 2, "hi".to_string()));
+
+        let foo = Foo::C { value: 1.5 };
+        assert_eq!(foo.variant_type(), VariantType::Struct);
+        assert_eq!(foo.index_of("value"), Some(0));
+        assert_eq!(foo.name_at(0), Some("value"));
+        let fields: Vec<_> = foo.iter_fields().map(|field| field.name()).collect();
+        assert_eq!(fields, vec![Some("value")]);
+
+        let foo = Foo::A;
+        assert!(foo.is_variant(VariantType::Unit));
+        assert_eq!(foo.iter_fields().count(), 0);
+    }
+
+    #[test]
+    fn reflect_enum_apply() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        enum Foo {
+            A,
+            B(usize),
+            C { value: f32, bar: Bar },
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Bar {
+            x: u32,
+        }
+
+        // same variant: fields are applied
+        let mut foo = Foo::C {
+            value: 1.0,
+            bar: Bar { x: 1 },
+        };
+        let mut data = DynamicStruct::default();
+        data.insert("value", 2.0f32);
+        foo.apply(&DynamicEnum::new(foo.type_name(), 2, "C", data));
+        assert_eq!(
+            foo,
+            Foo::C {
+                value: 2.0,
+                bar: Bar { x: 1 }
+            }
+        );
+
+        // new variant: the value is switched
+        let mut data = DynamicTuple::default();
+        data.insert(5usize);
+        foo.apply(&DynamicEnum::new(foo.type_name(), 1, "B", data));
+        assert_eq!(foo, Foo::B(5));
+
+        foo.apply(&Foo::A);
+        assert_eq!(foo, Foo::A);
+
+        let dynamic = Foo::C {
+            value: 3.0,
+            bar: Bar { x: 4 },
+        }
+        .clone_dynamic();
+        foo.apply(&dynamic);
+        assert_eq!(
+            foo,
+            Foo::C {
+                value: 3.0,
+                bar: Bar { x: 4 }
+            }
+        );
+        assert!(foo.reflect_partial_eq(&dynamic).unwrap());
+        assert_eq!(foo.reflect_hash(), None);
+        assert_eq!(Foo::from_reflect(&dynamic), Some(foo));
+
+        let mut data = DynamicTuple::default();
+        data.insert("not a usize".to_string());
+        assert_eq!(
+            Foo::from_reflect(&DynamicEnum::new("Foo", 1, "B", data)),
+            None
+        );
+        assert_eq!(
+            Foo::from_reflect(&DynamicEnum::new("Foo", 3, "D", DynamicVariant::Unit)),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Variant with name `D` does not exist")]
+    fn reflect_enum_apply_unknown_variant() {
+        #[derive(Reflect)]
+        enum Foo {
+            A,
+        }
+
+        let mut foo = Foo::A;
+        foo.apply(&DynamicEnum::new("Foo", 3, "D", DynamicVariant::Unit));
+    }
+
+    #[test]
+    fn reflect_enum_hash() {
+        #[derive(Reflect)]
+        enum Foo {
+            A,
+            B(usize),
+        }
+
+        assert_eq!(Foo::A.reflect_hash(), Foo::A.clone_value().reflect_hash());
+        assert_eq!(
+            Foo::B(1).reflect_hash(),
+            Foo::B(1).clone_value().reflect_hash()
+        );
+        assert_ne!(Foo::B(1).reflect_hash(), Foo::B(2).reflect_hash());
+        assert_ne!(Foo::A.reflect_hash(), Foo::B(0).reflect_hash());
+
+        let mut map = DynamicMap::default();
+        map.insert(Foo::B(1), 10u32);
+        assert_eq!(map.get(&Foo::B(1)).unwrap().downcast_ref(), Some(&10u32));
+    }
+
+    #[test]
+    fn reflect_enum_serialize() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        enum Foo {
+            A,
+            B(usize, String),
+            C { value: f32, list: Vec<u32> },
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+        registry.register::<usize>();
+        registry.register::<String>();
+        registry.register::<f32>();
+        registry.register::<u32>();
+
+        for foo in [
+            Foo::A,
+            Foo::B(1, "hi".to_string()),
+            Foo::C {
+                value: 1.5,
+                list: vec![1, 2],
+            },
+        ] {
+            let serializer = ReflectSerializer::new(&foo, &registry);
+            let serialized = to_string_pretty(&serializer, PrettyConfig::default()).unwrap();
+
+            let mut deserializer = Deserializer::from_str(&serialized).unwrap();
+            let reflect_deserializer = ReflectDeserializer::new(&registry);
+            let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+            let dynamic_enum = value.take::<DynamicEnum>().unwrap();
+
+            assert_eq!(dynamic_enum.type_name(), foo.type_name());
+            assert_eq!(dynamic_enum.variant_index(), foo.variant_index());
+            assert!(foo.reflect_partial_eq(&dynamic_enum).unwrap());
+            assert_eq!(Foo::from_reflect(&dynamic_enum), Some(foo));
+        }
+
+        // enums deserialize without a registration, like structs do
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Bar {
+            foo: Foo,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Bar>();
+        registry.register::<usize>();
+        registry.register::<String>();
+
+        let bar = Bar {
+            foo: Foo::B(2, "nested".to_string()),
+        };
+        let serializer = ReflectSerializer::new(&bar, &registry);
+        let serialized = to_string_pretty(&serializer, PrettyConfig::default()).unwrap();
+
+        let mut deserializer = Deserializer::from_str(&serialized).unwrap();
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        assert_eq!(Bar::from_reflect(value.as_ref()), Some(bar));
+    }
+
+    #[test]
+    fn reflect_serialization_attributes() {
+        fn default_health() -> f32 {
+            100.0
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Foo {
+            #[reflect(alias = "old_name")]
+            name: String,
+            #[reflect(default = "default_health")]
+            health: f32,
+            #[reflect(skip_serializing, default)]
+            cache: Vec<u32>,
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Bar(u32, #[reflect(skip_serializing)] Vec<u32>, String);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+        registry.register::<Bar>();
+        registry.register::<String>();
+        registry.register::<f32>();
+        registry.register::<u32>();
+        registry.register::<Vec<u32>>();
+
+        // Skipped fields are left out and restored from their defaults
+        let foo = Foo {
+            name: "foo".to_string(),
+            health: 5.0,
+            cache: vec![1, 2],
+        };
+        let serializer = ReflectSerializer::new(&foo, &registry);
+        let serialized = to_string_pretty(&serializer, PrettyConfig::default()).unwrap();
+        assert!(!serialized.contains("cache"));
+
+        let mut deserializer = Deserializer::from_str(&serialized).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let expected = Foo {
+            cache: Vec::new(),
+            ..foo
+        };
+        assert_eq!(Foo::from_reflect(value.as_ref()), Some(expected));
+
+        // Aliases are accepted and missing fields use their defaults
+        let input = format!(
+            r#"{{
+                "type": "{}",
+                "struct": {{
+                    "old_name": {{
+                        "type": "alloc::string::String",
+                        "value": "old",
+                    }},
+                }},
+            }}"#,
+            std::any::type_name::<Foo>()
+        );
+        let mut deserializer = Deserializer::from_str(&input).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let expected = Foo {
+            name: "old".to_string(),
+            health: 100.0,
+            cache: Vec::new(),
+        };
+        assert_eq!(Foo::from_reflect(value.as_ref()), Some(expected));
+
+        // Skipped tuple struct fields keep the remaining fields in place
+        let bar = Bar(1, vec![2], "bar".to_string());
+        let serializer = ReflectSerializer::new(&bar, &registry);
+        let serialized = to_string_pretty(&serializer, PrettyConfig::default()).unwrap();
+
+        let mut deserializer = Deserializer::from_str(&serialized).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            Bar::from_reflect(value.as_ref()),
+            Some(Bar(1, Vec::new(), "bar".to_string()))
+        );
+    }
+
+    #[test]
+    fn reflect_serialization_skip_dynamic() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Foo {
+            name: String,
+            #[reflect(skip_serializing, default)]
+            cache: Vec<u32>,
+            health: f32,
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Skipped {
+            #[reflect(skip_serializing, default)]
+            a: u32,
+            #[reflect(skip_serializing, default)]
+            b: u32,
+        }
+
+        #[derive(Reflect)]
+        struct Bar(#[reflect(skip_serializing)] u32, u32);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+        registry.register::<Skipped>();
+        registry.register::<Bar>();
+        registry.register::<String>();
+        registry.register::<f32>();
+        registry.register::<u32>();
+        registry.register::<Vec<u32>>();
+
+        let serialize = |value: &dyn Reflect| {
+            let serializer = ReflectSerializer::new(value, &registry);
+            to_string_pretty(&serializer, PrettyConfig::default()).unwrap()
+        };
+        let deserialize = |input: &str| {
+            let mut deserializer = Deserializer::from_str(input).unwrap();
+            ReflectDeserializer::new(&registry)
+                .deserialize(&mut deserializer)
+                .unwrap()
+        };
+
+        // Fields are skipped by name, whatever their position in the dynamic struct
+        let mut dynamic = DynamicStruct::default();
+        dynamic.set_name(std::any::type_name::<Foo>().to_string());
+        dynamic.insert("health", 5.0f32);
+        dynamic.insert("cache", vec![1u32, 2]);
+        dynamic.insert("name", "foo".to_string());
+        let serialized = serialize(&dynamic);
+        assert!(!serialized.contains("cache"));
+        assert_eq!(
+            Foo::from_reflect(deserialize(&serialized).as_ref()),
+            Some(Foo {
+                name: "foo".to_string(),
+                cache: Vec::new(),
+                health: 5.0,
+            })
+        );
+
+        // A struct with every field skipped is written empty
+        let serialized = serialize(&Skipped { a: 1, b: 2 });
+        assert_eq!(
+            Skipped::from_reflect(deserialize(&serialized).as_ref()),
+            Some(Skipped { a: 0, b: 0 })
+        );
+        let mut dynamic = DynamicStruct::default();
+        dynamic.set_name(std::any::type_name::<Skipped>().to_string());
+        dynamic.insert("a", 1u32);
+        assert!(!serialize(&dynamic).contains("\"a\""));
+
+        // Dynamic tuple structs skip the same fields as their type
+        let mut dynamic = DynamicTupleStruct::default();
+        dynamic.set_name(std::any::type_name::<Bar>().to_string());
+        dynamic.insert(1u32);
+        dynamic.insert(2u32);
+        assert_eq!(serialize(&dynamic), serialize(&Bar(1, 2)));
+
+        // Dynamic values with fewer fields than their type are written as they are
+        let mut dynamic = DynamicTupleStruct::default();
+        dynamic.set_name(std::any::type_name::<Bar>().to_string());
+        dynamic.insert(1u32);
+        assert_eq!(
+            serialize(&dynamic),
+            format!(
+                "{{\n    \"type\": \"{}\",\n    \"tuple_struct\": [],\n}}",
+                std::any::type_name::<Bar>()
+            )
+        );
+    }
+
     #[test]
     #[should_panic(expected = "the given key does not support hashing")]
     fn reflect_map_no_hash() {
@@ -489,6 +878,128 @@ mod tests {
         let _ = trait_object.as_reflect();
     }
 
+    #[test]
+    fn reflect_type_info() {
+        #[derive(Reflect)]
+        struct MyStruct<T: Reflect> {
+            foo: i32,
+            #[reflect(ignore)]
+            _ignored: usize,
+            bar: T,
+        }
+
+        let info = MyStruct::<String>::type_info();
+        if let TypeInfo::Struct(info) = &info {
+            assert!(info.is::<MyStruct<String>>());
+            assert_eq!(2, info.field_len());
+            assert!(info.field("foo").unwrap().is::<i32>());
+            assert_eq!("bar", info.field_at(1).unwrap().name());
+            assert_eq!(
+                std::any::type_name::<String>(),
+                info.field("bar").unwrap().type_name()
+            );
+            assert!(info.field("_ignored").is_none());
+        } else {
+            panic!("Expected `TypeInfo::Struct`");
+        }
+
+        #[derive(Reflect)]
+        struct MyTupleStruct(usize, f32);
+
+        let info = MyTupleStruct::type_info();
+        if let TypeInfo::TupleStruct(info) = &info {
+            assert_eq!(2, info.field_len());
+            assert!(info.field_at(1).unwrap().is::<f32>());
+        } else {
+            panic!("Expected `TypeInfo::TupleStruct`");
+        }
+
+        let info = <(i32, String)>::type_info();
+        if let TypeInfo::Tuple(info) = &info {
+            assert_eq!(1, info.field_at(1).unwrap().index());
+            assert!(info.field_at(1).unwrap().is::<String>());
+        } else {
+            panic!("Expected `TypeInfo::Tuple`");
+        }
+
+        #[derive(Reflect)]
+        enum MyEnum {
+            A,
+            B(usize),
+            C { value: f32 },
+        }
+
+        let info = MyEnum::type_info();
+        if let TypeInfo::Enum(info) = &info {
+            assert_eq!(3, info.variant_len());
+            assert_eq!(Some(1), info.index_of("B"));
+            assert!(matches!(info.variant("A"), Some(VariantInfo::Unit(_))));
+            if let Some(VariantInfo::Tuple(variant)) = info.variant_at(1) {
+                assert!(variant.field_at(0).unwrap().is::<usize>());
+            } else {
+                panic!("Expected `VariantInfo::Tuple`");
+            }
+            if let Some(VariantInfo::Struct(variant)) = info.variant("C") {
+                assert!(variant.field("value").unwrap().is::<f32>());
+            } else {
+                panic!("Expected `VariantInfo::Struct`");
+            }
+        } else {
+            panic!("Expected `TypeInfo::Enum`");
+        }
+
+        let info = Vec::<usize>::type_info();
+        if let TypeInfo::List(info) = &info {
+            assert!(info.is::<Vec<usize>>());
+            assert!(info.item_is::<usize>());
+        } else {
+            panic!("Expected `TypeInfo::List`");
+        }
+
+        let info = <[usize; 3]>::type_info();
+        if let TypeInfo::Array(info) = &info {
+            assert_eq!(3, info.capacity());
+            assert!(info.item_is::<usize>());
+        } else {
+            panic!("Expected `TypeInfo::Array`");
+        }
+
+        let info = HashMap::<usize, f32>::type_info();
+        if let TypeInfo::Map(info) = &info {
+            assert!(info.key_is::<usize>());
+            assert!(info.value_is::<f32>());
+        } else {
+            panic!("Expected `TypeInfo::Map`");
+        }
+
+        let info = String::type_info();
+        assert!(matches!(info, TypeInfo::Value(_)));
+        assert!(info.is::<String>());
+    }
+
+    #[test]
+    fn registry_type_info() {
+        #[derive(Reflect)]
+        struct Foo {
+            a: u32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+
+        let info = registry
+            .get_type_info(std::any::TypeId::of::<Foo>())
+            .unwrap();
+        assert!(info.is::<Foo>());
+        assert!(matches!(info, TypeInfo::Struct(_)));
+
+        let info = registry
+            .get_type_info_with_name(std::any::type_name::<Foo>())
+            .unwrap();
+        assert_eq!(std::any::type_name::<Foo>(), info.type_name());
+        assert!(registry.get_type_info_with_name("Bar").is_none());
+    }
+
     #[cfg(feature = "glam")]
     mod glam {
         use super::*;
@@ -1,4 +1,4 @@
-use std::any::Any;
+use std::any::{Any, TypeId};
 
 use crate::{serde::Serializable, Array, ArrayIter, DynamicArray, Reflect, ReflectMut, ReflectRef};
 
@@ -10,6 +10,13 @@ pub trait List: Reflect + Array {
     /// Appends an element to the list.
     fn push(&mut self, value: Box<dyn Reflect>);
 
+    /// Removes the last element from the list and returns it, or `None` if it is empty.
+    ///
+    /// The default implementation doesn't support removal and always returns `None`.
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        None
+    }
+
     /// Clones the list, producing a [`DynamicList`].
     fn clone_dynamic(&self) -> DynamicList {
         DynamicList {
@@ -19,6 +26,61 @@ pub trait List: Reflect + Array {
     }
 }
 
+/// A container for compile-time list info.
+#[derive(Debug, Clone)]
+pub struct ListInfo {
+    type_name: &'static str,
+    type_id: TypeId,
+    item_type_name: &'static str,
+    item_type_id: TypeId,
+}
+
+impl ListInfo {
+    /// Create a new [`ListInfo`].
+    pub fn new<TList: List, TItem: Reflect>() -> Self {
+        Self {
+            type_name: std::any::type_name::<TList>(),
+            type_id: TypeId::of::<TList>(),
+            item_type_name: std::any::type_name::<TItem>(),
+            item_type_id: TypeId::of::<TItem>(),
+        }
+    }
+
+    /// The [type name] of the list.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The [`TypeId`] of the list.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Check if the given type matches the list type.
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id
+    }
+
+    /// The [type name] of the list items.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn item_type_name(&self) -> &'static str {
+        self.item_type_name
+    }
+
+    /// The [`TypeId`] of the list items.
+    pub fn item_type_id(&self) -> TypeId {
+        self.item_type_id
+    }
+
+    /// Check if the given type matches the list items type.
+    pub fn item_is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.item_type_id
+    }
+}
+
 /// A list of reflected values.
 #[derive(Default)]
 pub struct DynamicList {
@@ -91,6 +153,10 @@ impl List for DynamicList {
         DynamicList::push_box(self, value);
     }
 
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        self.values.pop()
+    }
+
     fn clone_dynamic(&self) -> DynamicList {
         DynamicList {
             name: self.name.clone(),
@@ -1,6 +1,7 @@
-use std::any::Any;
+use std::any::{Any, TypeId};
 
 use bevy_utils::{Entry, HashMap};
+use thiserror::Error;
 
 use crate::{Reflect, ReflectMut, ReflectRef};
 
@@ -41,6 +42,118 @@ pub trait Map: Reflect {
 
     /// Clones the map, producing a [`DynamicMap`].
     fn clone_dynamic(&self) -> DynamicMap;
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map did not have this key present, `Ok(None)` is returned.
+    /// If the map did have this key present, the value is updated, and the old value is returned.
+    ///
+    /// The default implementation doesn't support insertion and returns
+    /// [`MapInsertError::Unsupported`].
+    fn insert_boxed(
+        &mut self,
+        _key: Box<dyn Reflect>,
+        _value: Box<dyn Reflect>,
+    ) -> Result<Option<Box<dyn Reflect>>, MapInsertError> {
+        Err(MapInsertError::Unsupported(self.type_name().to_string()))
+    }
+
+    /// Removes an entry from the map.
+    ///
+    /// If the map did not have this key present, `None` is returned.
+    /// If the map did have this key present, the removed value is returned.
+    ///
+    /// The default implementation doesn't support removal and always returns `None`.
+    fn remove(&mut self, _key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        None
+    }
+}
+
+/// An error returned by [`Map::insert_boxed`].
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum MapInsertError {
+    #[error("`{0}` doesn't support inserting entries")]
+    Unsupported(String),
+    #[error("a key of type `{0}` can't be inserted in this map")]
+    InvalidKey(String),
+    #[error("a value of type `{0}` can't be inserted in this map")]
+    InvalidValue(String),
+}
+
+/// A container for compile-time map info.
+#[derive(Debug, Clone)]
+pub struct MapInfo {
+    type_name: &'static str,
+    type_id: TypeId,
+    key_type_name: &'static str,
+    key_type_id: TypeId,
+    value_type_name: &'static str,
+    value_type_id: TypeId,
+}
+
+impl MapInfo {
+    /// Create a new [`MapInfo`].
+    pub fn new<TMap: Map, TKey: Reflect, TValue: Reflect>() -> Self {
+        Self {
+            type_name: std::any::type_name::<TMap>(),
+            type_id: TypeId::of::<TMap>(),
+            key_type_name: std::any::type_name::<TKey>(),
+            key_type_id: TypeId::of::<TKey>(),
+            value_type_name: std::any::type_name::<TValue>(),
+            value_type_id: TypeId::of::<TValue>(),
+        }
+    }
+
+    /// The [type name] of the map.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The [`TypeId`] of the map.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Check if the given type matches the map type.
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id
+    }
+
+    /// The [type name] of the keys.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn key_type_name(&self) -> &'static str {
+        self.key_type_name
+    }
+
+    /// The [`TypeId`] of the keys.
+    pub fn key_type_id(&self) -> TypeId {
+        self.key_type_id
+    }
+
+    /// Check if the given type matches the keys type.
+    pub fn key_is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.key_type_id
+    }
+
+    /// The [type name] of the values.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn value_type_name(&self) -> &'static str {
+        self.value_type_name
+    }
+
+    /// The [`TypeId`] of the values.
+    pub fn value_type_id(&self) -> TypeId {
+        self.value_type_id
+    }
+
+    /// Check if the given type matches the values type.
+    pub fn value_is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.value_type_id
+    }
 }
 
 const HASH_ERROR: &str = "the given key does not support hashing";
@@ -76,17 +189,41 @@ impl DynamicMap {
     }
 
     /// Inserts a key-value pair of [`Reflect`] values into the map.
-    pub fn insert_boxed(&mut self, key: Box<dyn Reflect>, value: Box<dyn Reflect>) {
+    ///
+    /// If the key was already present, the old value is returned.
+    pub fn insert_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Option<Box<dyn Reflect>> {
         match self.indices.entry(key.reflect_hash().expect(HASH_ERROR)) {
             Entry::Occupied(entry) => {
-                self.values[*entry.get()] = (key, value);
+                let (_, old_value) =
+                    std::mem::replace(&mut self.values[*entry.get()], (key, value));
+                Some(old_value)
             }
             Entry::Vacant(entry) => {
                 entry.insert(self.values.len());
                 self.values.push((key, value));
+                None
             }
         }
     }
+
+    /// Removes the entry with the given key from the map, returning its value.
+    ///
+    /// The last entry of the map takes the place of the removed one.
+    pub fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        let index = self
+            .indices
+            .remove(&key.reflect_hash().expect(HASH_ERROR))?;
+        let (_, value) = self.values.swap_remove(index);
+        if let Some((moved_key, _)) = self.values.get(index) {
+            self.indices
+                .insert(moved_key.reflect_hash().expect(HASH_ERROR), index);
+        }
+        Some(value)
+    }
 }
 
 impl Map for DynamicMap {
@@ -131,6 +268,18 @@ impl Map for DynamicMap {
             .get(index)
             .map(|(key, value)| (&**key, &**value))
     }
+
+    fn insert_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Result<Option<Box<dyn Reflect>>, MapInsertError> {
+        Ok(DynamicMap::insert_boxed(self, key, value))
+    }
+
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        DynamicMap::remove(self, key)
+    }
 }
 
 // SAFE: any and any_mut both return self
@@ -1,6 +1,6 @@
-use std::num::ParseIntError;
+use std::{fmt, num::ParseIntError};
 
-use crate::{Reflect, ReflectMut, ReflectRef};
+use crate::{Map, Reflect, ReflectMut, ReflectRef};
 use thiserror::Error;
 
 /// An error returned from a failed path string query.
@@ -15,18 +15,26 @@ pub enum ReflectPathError<'a> {
         index: usize,
         tuple_struct_index: usize,
     },
+    #[error("the current tuple doesn't have a field with the given index")]
+    InvalidTupleIndex { index: usize, tuple_index: usize },
     #[error("the current list doesn't have a value at the given index")]
     InvalidListIndex { index: usize, list_index: usize },
+    #[error("the current map doesn't have a value with the given key")]
+    InvalidMapKey { index: usize, key: String },
     #[error("encountered an unexpected token")]
     UnexpectedToken { index: usize, token: &'a str },
     #[error("expected a token, but it wasn't there.")]
     ExpectedToken { index: usize, token: &'a str },
     #[error("expected a struct, but found a different reflect value")]
     ExpectedStruct { index: usize },
+    #[error("expected a tuple or tuple struct, but found a different reflect value")]
+    ExpectedTuple { index: usize },
     #[error("expected a list, but found a different reflect value")]
     ExpectedList { index: usize },
+    #[error("expected a map, but found a different reflect value")]
+    ExpectedMap { index: usize },
     #[error("failed to parse a usize")]
-    IndexParseError(#[from] ParseIntError),
+    IndexParseError { index: usize, error: ParseIntError },
     #[error("failed to downcast to the path result to the given type")]
     InvalidDowncast,
 }
@@ -34,9 +42,13 @@ pub enum ReflectPathError<'a> {
 /// A trait which allows nested values to be retrieved with path strings.
 ///
 /// Path strings use Rust syntax:
-/// - [`Struct`] items are accessed with a dot and a field name: `.field_name`
-/// - [`TupleStruct`] and [`Tuple`] items are accessed with a dot and a number: `.0`
-/// - [`List`] items are accessed with brackets: `[0]`
+/// - [`Struct`] items and fields of struct variants of an [`Enum`] are accessed
+///   with a dot and a field name: `.field_name`
+/// - [`TupleStruct`] and [`Tuple`] items and fields of tuple variants of an
+///   [`Enum`] are accessed with a dot and a number: `.0`
+/// - [`List`] and [`Array`] items are accessed with brackets: `[0]`
+/// - [`Map`] items are accessed with brackets and a quoted string key:
+///   `["key"]`, or with an unquoted integer key: `[0]`
 ///
 /// If the initial path element is a field of a struct, tuple struct, or tuple,
 /// the initial '.' may be omitted.
@@ -45,10 +57,15 @@ pub enum ReflectPathError<'a> {
 /// 2-tuples (like a `Vec<(T, U)>`), the path string `foo[3].0` would access tuple
 /// element 0 of element 3 of `foo`.
 ///
+/// The path string is parsed on every call. Paths which are accessed
+/// repeatedly can be parsed once into a [`ParsedPath`] instead.
+///
 /// [`Struct`]: crate::Struct
+/// [`Enum`]: crate::Enum
 /// [`TupleStruct`]: crate::TupleStruct
 /// [`Tuple`]: crate::Tuple
 /// [`List`]: crate::List
+/// [`Array`]: crate::Array
 pub trait GetPath {
     /// Returns a reference to the value specified by `path`.
     ///
@@ -104,65 +121,11 @@ impl<T: Reflect> GetPath for T {
 
 impl GetPath for dyn Reflect {
     fn path<'r, 'p>(&'r self, path: &'p str) -> Result<&'r dyn Reflect, ReflectPathError<'p>> {
-        let mut index = 0;
         let mut current: &dyn Reflect = self;
-        while let Some(token) = next_token(path, &mut index) {
-            let current_index = index;
-            match token {
-                Token::Dot => {
-                    if let Some(Token::Ident(value)) = next_token(path, &mut index) {
-                        current = read_field(current, value, current_index)?;
-                    } else {
-                        return Err(ReflectPathError::ExpectedIdent {
-                            index: current_index,
-                        });
-                    }
-                }
-                Token::OpenBracket => {
-                    if let Some(Token::Ident(value)) = next_token(path, &mut index) {
-                        match current.reflect_ref() {
-                            ReflectRef::List(reflect_list) => {
-                                let list_index = value.parse::<usize>()?;
-                                let list_item = reflect_list.get(list_index).ok_or(
-                                    ReflectPathError::InvalidListIndex {
-                                        index: current_index,
-                                        list_index,
-                                    },
-                                )?;
-                                current = list_item;
-                            }
-                            _ => {
-                                return Err(ReflectPathError::ExpectedList {
-                                    index: current_index,
-                                })
-                            }
-                        }
-                    } else {
-                        return Err(ReflectPathError::ExpectedIdent {
-                            index: current_index,
-                        });
-                    }
-
-                    if let Some(Token::CloseBracket) = next_token(path, &mut index) {
-                    } else {
-                        return Err(ReflectPathError::ExpectedToken {
-                            index: current_index,
-                            token: "]",
-                        });
-                    }
-                }
-                Token::CloseBracket => {
-                    return Err(ReflectPathError::UnexpectedToken {
-                        index: current_index,
-                        token: "]",
-                    })
-                }
-                Token::Ident(value) => {
-                    current = read_field(current, value, current_index)?;
-                }
-            }
+        for access in PathParser::new(path) {
+            let (access, index) = access?;
+            current = access.element(current, index)?;
         }
-
         Ok(current)
     }
 
@@ -170,125 +133,293 @@ impl GetPath for dyn Reflect {
         &'r mut self,
         path: &'p str,
     ) -> Result<&'r mut dyn Reflect, ReflectPathError<'p>> {
-        let mut index = 0;
         let mut current: &mut dyn Reflect = self;
-        while let Some(token) = next_token(path, &mut index) {
-            let current_index = index;
-            match token {
-                Token::Dot => {
-                    if let Some(Token::Ident(value)) = next_token(path, &mut index) {
-                        current = read_field_mut(current, value, current_index)?;
-                    } else {
-                        return Err(ReflectPathError::ExpectedIdent {
-                            index: current_index,
-                        });
-                    }
-                }
-                Token::OpenBracket => {
-                    if let Some(Token::Ident(value)) = next_token(path, &mut index) {
-                        match current.reflect_mut() {
-                            ReflectMut::List(reflect_list) => {
-                                let list_index = value.parse::<usize>()?;
-                                let list_item = reflect_list.get_mut(list_index).ok_or(
-                                    ReflectPathError::InvalidListIndex {
-                                        index: current_index,
-                                        list_index,
-                                    },
-                                )?;
-                                current = list_item;
-                            }
-                            _ => {
-                                return Err(ReflectPathError::ExpectedStruct {
-                                    index: current_index,
-                                })
-                            }
-                        }
-                    } else {
-                        return Err(ReflectPathError::ExpectedIdent {
-                            index: current_index,
-                        });
-                    }
+        for access in PathParser::new(path) {
+            let (access, index) = access?;
+            current = access.element_mut(current, index)?;
+        }
+        Ok(current)
+    }
+}
 
-                    if let Some(Token::CloseBracket) = next_token(path, &mut index) {
-                    } else {
-                        return Err(ReflectPathError::ExpectedToken {
-                            index: current_index,
-                            token: "]",
-                        });
-                    }
-                }
-                Token::CloseBracket => {
-                    return Err(ReflectPathError::UnexpectedToken {
-                        index: current_index,
-                        token: "]",
-                    })
-                }
-                Token::Ident(value) => {
-                    current = read_field_mut(current, value, current_index)?;
-                }
-            }
+/// A path string which has been parsed ahead of time.
+///
+/// Accessing a value through a [`ParsedPath`] skips the parsing done by
+/// [`GetPath`] on every call, which makes it suited to paths which are accessed
+/// repeatedly, like animation targets. See [`GetPath`] for the path syntax.
+///
+/// ```
+/// # use bevy_reflect::{ParsedPath, Reflect};
+/// #[derive(Reflect)]
+/// struct Player {
+///     position: (f32, f32),
+/// }
+///
+/// let path = ParsedPath::parse("position.1").unwrap();
+/// let mut player = Player { position: (0.0, 0.0) };
+/// *path.get_element_mut::<f32>(&mut player).unwrap() += 1.0;
+/// assert_eq!(player.position.1, 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParsedPath(Box<[(OwnedAccess, usize)]>);
+
+impl ParsedPath {
+    /// Parses a path string.
+    ///
+    /// Errors are reported at the index in `path` where they occurred.
+    pub fn parse(path: &str) -> Result<Self, ReflectPathError<'_>> {
+        let accesses = PathParser::new(path)
+            .map(|access| access.map(|(access, index)| (access.to_owned(), index)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(accesses.into_boxed_slice()))
+    }
+
+    /// Returns a reference to the value specified by this path in `root`.
+    pub fn element<'r, 'p>(
+        &'p self,
+        root: &'r dyn Reflect,
+    ) -> Result<&'r dyn Reflect, ReflectPathError<'p>> {
+        let mut current = root;
+        for (access, index) in self.0.iter() {
+            current = access.as_access().element(current, *index)?;
         }
+        Ok(current)
+    }
 
+    /// Returns a mutable reference to the value specified by this path in `root`.
+    pub fn element_mut<'r, 'p>(
+        &'p self,
+        root: &'r mut dyn Reflect,
+    ) -> Result<&'r mut dyn Reflect, ReflectPathError<'p>> {
+        let mut current = root;
+        for (access, index) in self.0.iter() {
+            current = access.as_access().element_mut(current, *index)?;
+        }
         Ok(current)
     }
+
+    /// Returns a statically typed reference to the value specified by this
+    /// path in `root`.
+    pub fn get_element<'r, 'p, T: Reflect>(
+        &'p self,
+        root: &'r dyn Reflect,
+    ) -> Result<&'r T, ReflectPathError<'p>> {
+        self.element(root).and_then(|p| {
+            p.downcast_ref::<T>()
+                .ok_or(ReflectPathError::InvalidDowncast)
+        })
+    }
+
+    /// Returns a statically typed mutable reference to the value specified by
+    /// this path in `root`.
+    pub fn get_element_mut<'r, 'p, T: Reflect>(
+        &'p self,
+        root: &'r mut dyn Reflect,
+    ) -> Result<&'r mut T, ReflectPathError<'p>> {
+        self.element_mut(root).and_then(|p| {
+            p.downcast_mut::<T>()
+                .ok_or(ReflectPathError::InvalidDowncast)
+        })
+    }
 }
 
-fn read_field<'r, 'p>(
-    current: &'r dyn Reflect,
-    field: &'p str,
-    current_index: usize,
-) -> Result<&'r dyn Reflect, ReflectPathError<'p>> {
-    match current.reflect_ref() {
-        ReflectRef::Struct(reflect_struct) => {
-            Ok(reflect_struct
-                .field(field)
-                .ok_or(ReflectPathError::InvalidField {
-                    index: current_index,
-                    field,
-                })?)
+impl fmt::Display for ParsedPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (access, _)) in self.0.iter().enumerate() {
+            let dot = if i == 0 { "" } else { "." };
+            match access {
+                OwnedAccess::Field(field) => write!(f, "{}{}", dot, field)?,
+                OwnedAccess::TupleIndex(index) => write!(f, "{}{}", dot, index)?,
+                OwnedAccess::ListIndex(index) => write!(f, "[{}]", index)?,
+                OwnedAccess::MapKey(key) => write!(f, "[\"{}\"]", key)?,
+            }
         }
-        ReflectRef::TupleStruct(reflect_struct) => {
-            let tuple_index = field.parse::<usize>()?;
-            Ok(reflect_struct.field(tuple_index).ok_or(
-                ReflectPathError::InvalidTupleStructIndex {
-                    index: current_index,
-                    tuple_struct_index: tuple_index,
-                },
-            )?)
+        Ok(())
+    }
+}
+
+/// A single step of a path, borrowed from the path string.
+#[derive(Debug, Clone, Copy)]
+enum Access<'a> {
+    Field(&'a str),
+    TupleIndex(usize),
+    ListIndex(usize),
+    MapKey(&'a str),
+}
+
+/// An owned [`Access`], stored in a [`ParsedPath`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum OwnedAccess {
+    Field(String),
+    TupleIndex(usize),
+    ListIndex(usize),
+    MapKey(String),
+}
+
+impl OwnedAccess {
+    fn as_access(&self) -> Access<'_> {
+        match self {
+            OwnedAccess::Field(field) => Access::Field(field),
+            OwnedAccess::TupleIndex(index) => Access::TupleIndex(*index),
+            OwnedAccess::ListIndex(index) => Access::ListIndex(*index),
+            OwnedAccess::MapKey(key) => Access::MapKey(key),
         }
-        _ => Err(ReflectPathError::ExpectedStruct {
-            index: current_index,
-        }),
     }
 }
 
-fn read_field_mut<'r, 'p>(
-    current: &'r mut dyn Reflect,
-    field: &'p str,
-    current_index: usize,
-) -> Result<&'r mut dyn Reflect, ReflectPathError<'p>> {
-    match current.reflect_mut() {
-        ReflectMut::Struct(reflect_struct) => {
-            Ok(reflect_struct
-                .field_mut(field)
-                .ok_or(ReflectPathError::InvalidField {
-                    index: current_index,
-                    field,
-                })?)
+impl<'a> Access<'a> {
+    fn from_ident(ident: &'a str) -> Self {
+        match ident.parse::<usize>() {
+            Ok(index) => Access::TupleIndex(index),
+            Err(_) => Access::Field(ident),
         }
-        ReflectMut::TupleStruct(reflect_struct) => {
-            let tuple_index = field.parse::<usize>()?;
-            Ok(reflect_struct.field_mut(tuple_index).ok_or(
-                ReflectPathError::InvalidTupleStructIndex {
-                    index: current_index,
-                    tuple_struct_index: tuple_index,
-                },
-            )?)
+    }
+
+    fn to_owned(self) -> OwnedAccess {
+        match self {
+            Access::Field(field) => OwnedAccess::Field(field.to_string()),
+            Access::TupleIndex(index) => OwnedAccess::TupleIndex(index),
+            Access::ListIndex(index) => OwnedAccess::ListIndex(index),
+            Access::MapKey(key) => OwnedAccess::MapKey(key.to_string()),
         }
-        _ => Err(ReflectPathError::ExpectedStruct {
-            index: current_index,
-        }),
     }
+
+    fn element<'r>(
+        self,
+        current: &'r dyn Reflect,
+        index: usize,
+    ) -> Result<&'r dyn Reflect, ReflectPathError<'a>> {
+        match (self, current.reflect_ref()) {
+            (Access::Field(field), ReflectRef::Struct(reflect_struct)) => reflect_struct
+                .field(field)
+                .ok_or(ReflectPathError::InvalidField { index, field }),
+            (Access::Field(field), ReflectRef::Enum(reflect_enum)) => reflect_enum
+                .field(field)
+                .ok_or(ReflectPathError::InvalidField { index, field }),
+            (Access::Field(_), _) => Err(ReflectPathError::ExpectedStruct { index }),
+            (Access::TupleIndex(tuple_index), ReflectRef::TupleStruct(reflect_struct)) => {
+                reflect_struct
+                    .field(tuple_index)
+                    .ok_or(ReflectPathError::InvalidTupleStructIndex {
+                        index,
+                        tuple_struct_index: tuple_index,
+                    })
+            }
+            (Access::TupleIndex(tuple_index), ReflectRef::Tuple(reflect_tuple)) => reflect_tuple
+                .field(tuple_index)
+                .ok_or(ReflectPathError::InvalidTupleIndex { index, tuple_index }),
+            (Access::TupleIndex(tuple_index), ReflectRef::Enum(reflect_enum)) => reflect_enum
+                .field_at(tuple_index)
+                .ok_or(ReflectPathError::InvalidTupleIndex { index, tuple_index }),
+            (Access::TupleIndex(_), _) => Err(ReflectPathError::ExpectedTuple { index }),
+            (Access::ListIndex(list_index), ReflectRef::List(reflect_list)) => reflect_list
+                .get(list_index)
+                .ok_or(ReflectPathError::InvalidListIndex { index, list_index }),
+            (Access::ListIndex(list_index), ReflectRef::Array(reflect_array)) => reflect_array
+                .get(list_index)
+                .ok_or(ReflectPathError::InvalidListIndex { index, list_index }),
+            (Access::ListIndex(key), ReflectRef::Map(reflect_map)) => {
+                get_integer_key(reflect_map, key).ok_or_else(|| ReflectPathError::InvalidMapKey {
+                    index,
+                    key: key.to_string(),
+                })
+            }
+            (Access::ListIndex(_), _) => Err(ReflectPathError::ExpectedList { index }),
+            (Access::MapKey(key), ReflectRef::Map(reflect_map)) => reflect_map
+                .get(&key.to_string())
+                .ok_or_else(|| ReflectPathError::InvalidMapKey {
+                    index,
+                    key: key.to_string(),
+                }),
+            (Access::MapKey(_), _) => Err(ReflectPathError::ExpectedMap { index }),
+        }
+    }
+
+    fn element_mut<'r>(
+        self,
+        current: &'r mut dyn Reflect,
+        index: usize,
+    ) -> Result<&'r mut dyn Reflect, ReflectPathError<'a>> {
+        match (self, current.reflect_mut()) {
+            (Access::Field(field), ReflectMut::Struct(reflect_struct)) => reflect_struct
+                .field_mut(field)
+                .ok_or(ReflectPathError::InvalidField { index, field }),
+            (Access::Field(field), ReflectMut::Enum(reflect_enum)) => reflect_enum
+                .field_mut(field)
+                .ok_or(ReflectPathError::InvalidField { index, field }),
+            (Access::Field(_), _) => Err(ReflectPathError::ExpectedStruct { index }),
+            (Access::TupleIndex(tuple_index), ReflectMut::TupleStruct(reflect_struct)) => {
+                reflect_struct.field_mut(tuple_index).ok_or(
+                    ReflectPathError::InvalidTupleStructIndex {
+                        index,
+                        tuple_struct_index: tuple_index,
+                    },
+                )
+            }
+            (Access::TupleIndex(tuple_index), ReflectMut::Tuple(reflect_tuple)) => reflect_tuple
+                .field_mut(tuple_index)
+                .ok_or(ReflectPathError::InvalidTupleIndex { index, tuple_index }),
+            (Access::TupleIndex(tuple_index), ReflectMut::Enum(reflect_enum)) => reflect_enum
+                .field_at_mut(tuple_index)
+                .ok_or(ReflectPathError::InvalidTupleIndex { index, tuple_index }),
+            (Access::TupleIndex(_), _) => Err(ReflectPathError::ExpectedTuple { index }),
+            (Access::ListIndex(list_index), ReflectMut::List(reflect_list)) => reflect_list
+                .get_mut(list_index)
+                .ok_or(ReflectPathError::InvalidListIndex { index, list_index }),
+            (Access::ListIndex(list_index), ReflectMut::Array(reflect_array)) => reflect_array
+                .get_mut(list_index)
+                .ok_or(ReflectPathError::InvalidListIndex { index, list_index }),
+            (Access::ListIndex(key), ReflectMut::Map(reflect_map)) => {
+                get_integer_key_mut(reflect_map, key).ok_or_else(|| {
+                    ReflectPathError::InvalidMapKey {
+                        index,
+                        key: key.to_string(),
+                    }
+                })
+            }
+            (Access::ListIndex(_), _) => Err(ReflectPathError::ExpectedList { index }),
+            (Access::MapKey(key), ReflectMut::Map(reflect_map)) => reflect_map
+                .get_mut(&key.to_string())
+                .ok_or_else(|| ReflectPathError::InvalidMapKey {
+                    index,
+                    key: key.to_string(),
+                }),
+            (Access::MapKey(_), _) => Err(ReflectPathError::ExpectedMap { index }),
+        }
+    }
+}
+
+/// Calls `$body` with `$key` converted to each integer type it fits in.
+macro_rules! for_integer_keys {
+    ($key:expr, |$int:ident| $body:expr) => {{
+        let $int: usize = $key;
+        $body;
+        for_integer_keys!(
+            $key, $int, $body, u64, u32, u16, u8, u128, isize, i64, i32, i16, i8, i128
+        )
+    }};
+    ($key:expr, $int:ident, $body:expr, $($ty:ty),*) => {{
+        $(
+            if let Ok($int) = <$ty>::try_from($key) {
+                $body;
+            }
+        )*
+    }};
+}
+
+/// Looks up an unquoted map index, whose key type isn't known from the path.
+fn get_integer_key(map: &dyn Map, key: usize) -> Option<&dyn Reflect> {
+    for_integer_keys!(key, |key| if let Some(value) = map.get(&key) {
+        return Some(value);
+    });
+    None
+}
+
+fn get_integer_key_mut(map: &mut dyn Map, key: usize) -> Option<&mut dyn Reflect> {
+    for_integer_keys!(key, |key| if map.get(&key).is_some() {
+        return map.get_mut(&key);
+    });
+    None
 }
 
 enum Token<'a> {
@@ -296,43 +427,94 @@ enum Token<'a> {
     OpenBracket,
     CloseBracket,
     Ident(&'a str),
+    Quoted(&'a str),
+}
+
+/// Splits a path string into [`Access`]es, along with the index in the path
+/// where each of them starts.
+struct PathParser<'a> {
+    path: &'a str,
+    index: usize,
 }
 
-fn next_token<'a>(path: &'a str, index: &mut usize) -> Option<Token<'a>> {
-    if *index >= path.len() {
-        return None;
+impl<'a> PathParser<'a> {
+    fn new(path: &'a str) -> Self {
+        Self { path, index: 0 }
     }
 
-    match path[*index..].chars().next().unwrap() {
-        '.' => {
-            *index += 1;
-            return Some(Token::Dot);
-        }
-        '[' => {
-            *index += 1;
-            return Some(Token::OpenBracket);
-        }
-        ']' => {
-            *index += 1;
-            return Some(Token::CloseBracket);
-        }
-        _ => {}
+    fn next_token(&mut self) -> Result<Option<Token<'a>>, ReflectPathError<'a>> {
+        let rest = &self.path[self.index..];
+        let token = match rest.chars().next() {
+            None => return Ok(None),
+            Some('.') => Token::Dot,
+            Some('[') => Token::OpenBracket,
+            Some(']') => Token::CloseBracket,
+            Some('"') => {
+                let len = rest[1..].find('"').ok_or(ReflectPathError::ExpectedToken {
+                    index: self.path.len(),
+                    token: "\"",
+                })?;
+                self.index += len + 2;
+                return Ok(Some(Token::Quoted(&rest[1..len + 1])));
+            }
+            Some(_) => {
+                let len = rest.find(['.', '[', ']', '"']).unwrap_or(rest.len());
+                self.index += len;
+                return Ok(Some(Token::Ident(&rest[..len])));
+            }
+        };
+        self.index += 1;
+        Ok(Some(token))
     }
 
-    // we can assume we are parsing an ident now
-    for (char_index, character) in path[*index..].chars().enumerate() {
-        match character {
-            '.' | '[' | ']' => {
-                let ident = Token::Ident(&path[*index..*index + char_index]);
-                *index += char_index;
-                return Some(ident);
+    fn next_access(&mut self) -> Result<Option<(Access<'a>, usize)>, ReflectPathError<'a>> {
+        let start = self.index;
+        let token = match self.next_token()? {
+            Some(token) => token,
+            None => return Ok(None),
+        };
+        let index = self.index;
+        match token {
+            Token::Dot => match self.next_token()? {
+                Some(Token::Ident(ident)) => Ok(Some((Access::from_ident(ident), index))),
+                _ => Err(ReflectPathError::ExpectedIdent { index }),
+            },
+            Token::OpenBracket => {
+                let access = match self.next_token()? {
+                    Some(Token::Ident(ident)) => {
+                        let list_index = ident
+                            .parse::<usize>()
+                            .map_err(|error| ReflectPathError::IndexParseError { index, error })?;
+                        Access::ListIndex(list_index)
+                    }
+                    Some(Token::Quoted(key)) => Access::MapKey(key),
+                    _ => return Err(ReflectPathError::ExpectedIdent { index }),
+                };
+                match self.next_token()? {
+                    Some(Token::CloseBracket) => Ok(Some((access, index))),
+                    _ => Err(ReflectPathError::ExpectedToken { index, token: "]" }),
+                }
             }
-            _ => {}
+            Token::Ident(ident) if start == 0 => Ok(Some((Access::from_ident(ident), start))),
+            Token::Ident(ident) => Err(ReflectPathError::UnexpectedToken {
+                index: start,
+                token: ident,
+            }),
+            Token::CloseBracket => Err(ReflectPathError::UnexpectedToken { index, token: "]" }),
+            Token::Quoted(_) => Err(ReflectPathError::UnexpectedToken {
+                index: start,
+                token: "\"",
+            }),
         }
     }
-    let ident = Token::Ident(&path[*index..]);
-    *index = path.len();
-    Some(ident)
+}
+
+impl<'a> Iterator for PathParser<'a> {
+    type Item = Result<(Access<'a>, usize), ReflectPathError<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_access().transpose()
+    }
 }
 
 #[cfg(test)]
@@ -341,6 +523,7 @@ mod tests {
     use super::GetPath;
     use crate as bevy_reflect;
     use crate::*;
+    use bevy_utils::HashMap;
     #[test]
     fn reflect_path() {
         #[derive(Reflect)]
@@ -412,7 +595,147 @@ mod tests {
 
         assert!(matches!(
             a.path("y[badindex]"),
-            Err(ReflectPathError::IndexParseError(_))
+            Err(ReflectPathError::IndexParseError { index: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn reflect_path_maps_tuples_and_enums() {
+        #[derive(Reflect)]
+        struct A {
+            names: HashMap<String, B>,
+            ids: HashMap<u32, usize>,
+            pair: (usize, [f32; 2]),
+            e: E,
+        }
+
+        #[derive(Reflect, FromReflect, Clone)]
+        struct B {
+            value: usize,
+        }
+
+        #[derive(Reflect)]
+        enum E {
+            Struct { x: usize },
+        }
+
+        let mut names = HashMap::default();
+        names.insert("first".to_string(), B { value: 1 });
+        let mut ids = HashMap::default();
+        ids.insert(7u32, 70usize);
+        let mut a = A {
+            names,
+            ids,
+            pair: (5, [1.0, 2.0]),
+            e: E::Struct { x: 3 },
+        };
+
+        assert_eq!(*a.get_path::<usize>("names[\"first\"].value").unwrap(), 1);
+        assert_eq!(*a.get_path::<usize>("ids[7]").unwrap(), 70);
+        assert_eq!(*a.get_path::<usize>("pair.0").unwrap(), 5);
+        assert_eq!(*a.get_path::<f32>("pair.1[1]").unwrap(), 2.0);
+        assert_eq!(*a.get_path::<usize>("e.x").unwrap(), 3);
+
+        *a.get_path_mut::<usize>("names[\"first\"].value").unwrap() = 10;
+        *a.get_path_mut::<usize>("ids[7]").unwrap() = 71;
+        *a.get_path_mut::<usize>("e.x").unwrap() = 4;
+        assert_eq!(a.names["first"].value, 10);
+        assert_eq!(a.ids[&7], 71);
+        assert!(matches!(a.e, E::Struct { x: 4 }));
+
+        assert_eq!(
+            a.path("names[\"second\"]").err().unwrap(),
+            ReflectPathError::InvalidMapKey {
+                index: 6,
+                key: "second".to_string()
+            }
+        );
+        assert_eq!(
+            a.path("ids[8]").err().unwrap(),
+            ReflectPathError::InvalidMapKey {
+                index: 4,
+                key: "8".to_string()
+            }
+        );
+        assert_eq!(
+            a.path("pair.2").err().unwrap(),
+            ReflectPathError::InvalidTupleIndex {
+                index: 5,
+                tuple_index: 2
+            }
+        );
+        assert_eq!(
+            a.path("pair[\"0\"]").err().unwrap(),
+            ReflectPathError::ExpectedMap { index: 5 }
+        );
+        assert_eq!(
+            a.path("names.0").err().unwrap(),
+            ReflectPathError::ExpectedTuple { index: 6 }
+        );
+    }
+
+    #[test]
+    fn parsed_path() {
+        #[derive(Reflect)]
+        struct A {
+            x: Vec<(usize, HashMap<String, f32>)>,
+        }
+
+        let mut map = HashMap::default();
+        map.insert("key".to_string(), 1.0);
+        let mut a = A { x: vec![(0, map)] };
+
+        let path = ParsedPath::parse("x[0].1[\"key\"]").unwrap();
+        assert_eq!(*path.get_element::<f32>(&a).unwrap(), 1.0);
+        *path.get_element_mut::<f32>(&mut a).unwrap() = 2.0;
+        assert_eq!(a.x[0].1["key"], 2.0);
+
+        assert_eq!(path.to_string(), "x[0].1[\"key\"]");
+        assert_eq!(ParsedPath::parse(&path.to_string()).unwrap(), path);
+
+        let missing = ParsedPath::parse("x[1]").unwrap();
+        assert_eq!(
+            missing.element(&a).err().unwrap(),
+            ReflectPathError::InvalidListIndex {
+                index: 2,
+                list_index: 1
+            }
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            ParsedPath::parse("x[\"key]").err().unwrap(),
+            ReflectPathError::ExpectedToken {
+                index: 7,
+                token: "\""
+            }
+        );
+        assert_eq!(
+            ParsedPath::parse("x[0").err().unwrap(),
+            ReflectPathError::ExpectedToken {
+                index: 2,
+                token: "]"
+            }
+        );
+        assert_eq!(
+            ParsedPath::parse("x[0]y").err().unwrap(),
+            ReflectPathError::UnexpectedToken {
+                index: 4,
+                token: "y"
+            }
+        );
+        assert_eq!(
+            ParsedPath::parse("x]").err().unwrap(),
+            ReflectPathError::UnexpectedToken {
+                index: 2,
+                token: "]"
+            }
+        );
+        assert!(matches!(
+            ParsedPath::parse("x[-1]"),
+            Err(ReflectPathError::IndexParseError { index: 2, .. })
         ));
     }
 }
@@ -1,4 +1,4 @@
-use crate::{serde::Serializable, Array, List, Map, Struct, Tuple, TupleStruct};
+use crate::{serde::Serializable, Array, Enum, List, Map, Struct, Tuple, TupleStruct};
 use std::{any::Any, fmt::Debug};
 
 pub use bevy_utils::AHasher as ReflectHasher;
@@ -16,6 +16,7 @@ pub enum ReflectRef<'a> {
     List(&'a dyn List),
     Array(&'a dyn Array),
     Map(&'a dyn Map),
+    Enum(&'a dyn Enum),
     Value(&'a dyn Reflect),
 }
 
@@ -32,16 +33,17 @@ pub enum ReflectMut<'a> {
     List(&'a mut dyn List),
     Array(&'a mut dyn Array),
     Map(&'a mut dyn Map),
+    Enum(&'a mut dyn Enum),
     Value(&'a mut dyn Reflect),
 }
 
 /// A reflected Rust type.
 ///
 /// Methods for working with particular kinds of Rust type are available using the [`List`], [`Map`],
-/// [`Struct`], [`TupleStruct`], and [`Tuple`] subtraits.
+/// [`Struct`], [`TupleStruct`], [`Tuple`], and [`Enum`] subtraits.
 ///
-/// When using `#[derive(Reflect)]` with a struct or tuple struct, the suitable subtrait for that
-/// type (`Struct` or `TupleStruct`) is derived automatically.
+/// When using `#[derive(Reflect)]` with a struct, tuple struct or enum, the suitable subtrait for
+/// that type (`Struct`, `TupleStruct` or `Enum`) is derived automatically.
 ///
 /// # Safety
 /// Implementors _must_ ensure that [`Reflect::any`] and [`Reflect::any_mut`] both return the `self`
@@ -81,6 +83,10 @@ pub unsafe trait Reflect: Any + Send + Sync {
     /// - If `T` is a [`Map`], then for each key in `value`, the associated
     ///   value is applied to the value associated with the same key in `self`.
     ///   Keys which are not present in both maps are ignored.
+    /// - If `T` is an [`Enum`], then the variant of `self` is updated to match
+    ///   the variant of `value`. The corresponding fields of that variant are
+    ///   applied from `value` onto `self`. Fields which are not present in both
+    ///   values are ignored.
     /// - If `T` is none of these, then `value` is downcast to `T`, cloned, and
     ///   assigned to `self`.
     ///
@@ -0,0 +1,478 @@
+use crate::{
+    serde::SerializationData, ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap,
+    DynamicStruct, DynamicTuple, DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, MapInfo,
+    NamedField, Reflect, ReflectDeserialize, StructInfo, TupleInfo, TupleStructInfo, TypeInfo,
+    TypeRegistry, UnnamedField, VariantInfo,
+};
+use serde::de::{self, DeserializeSeed, Error, MapAccess, SeqAccess, Visitor};
+use std::{any::TypeId, slice::Iter};
+
+/// Deserializes a reflected value written by
+/// [`CompactReflectSerializer`](crate::serde::CompactReflectSerializer).
+///
+/// The [`TypeRegistry`] must contain the same registrations, registered in the
+/// same order, as the one used for serialization.
+pub struct CompactReflectDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactReflectDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        CompactReflectDeserializer { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(
+            2,
+            CompactReflectVisitor {
+                registry: self.registry,
+            },
+        )
+    }
+}
+
+struct CompactReflectVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for CompactReflectVisitor<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("numeric type id followed by a reflect value")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let numeric_id: u32 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let registration = self
+            .registry
+            .get_with_numeric_id(numeric_id)
+            .ok_or_else(|| {
+                Error::custom(format_args!(
+                    "no registration found for type id {}",
+                    numeric_id
+                ))
+            })?;
+        seq.next_element_seed(TypedValueSeed {
+            type_info: registration.type_info(),
+            registry: self.registry,
+        })?
+        .ok_or_else(|| Error::invalid_length(1, &self))
+    }
+}
+
+/// Deserializes a value whose type is known ahead of time.
+struct TypedValueSeed<'a> {
+    type_info: &'a TypeInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> TypedValueSeed<'a> {
+    fn of<E: Error>(type_id: TypeId, registry: &'a TypeRegistry) -> Result<Self, E> {
+        let registration = registry.get(type_id).ok_or_else(|| {
+            Error::custom(format_args!("no registration found for type {:?}", type_id))
+        })?;
+        Ok(TypedValueSeed {
+            type_info: registration.type_info(),
+            registry,
+        })
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for TypedValueSeed<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let registry = self.registry;
+        match self.type_info {
+            TypeInfo::Struct(info) => {
+                let serialization_data = get_serialization_data(registry, info.type_id());
+                let len =
+                    info.field_len() - serialization_data.map_or(0, |data| data.skipped_len());
+                let value = deserializer.deserialize_tuple(
+                    len,
+                    StructVisitor {
+                        info,
+                        serialization_data,
+                        registry,
+                    },
+                )?;
+                Ok(Box::new(value))
+            }
+            TypeInfo::TupleStruct(info) => {
+                let serialization_data = get_serialization_data(registry, info.type_id());
+                let len =
+                    info.field_len() - serialization_data.map_or(0, |data| data.skipped_len());
+                let value = deserializer.deserialize_tuple(
+                    len,
+                    TupleStructVisitor {
+                        info,
+                        serialization_data,
+                        registry,
+                    },
+                )?;
+                Ok(Box::new(value))
+            }
+            TypeInfo::Tuple(info) => {
+                let value = deserializer
+                    .deserialize_tuple(info.field_len(), TupleVisitor { info, registry })?;
+                Ok(Box::new(value))
+            }
+            TypeInfo::List(info) => {
+                let value = deserializer.deserialize_seq(ListVisitor { info, registry })?;
+                Ok(Box::new(value))
+            }
+            TypeInfo::Array(info) => {
+                let value = deserializer
+                    .deserialize_tuple(info.capacity(), ArrayVisitor { info, registry })?;
+                Ok(Box::new(value))
+            }
+            TypeInfo::Map(info) => {
+                let value = deserializer.deserialize_map(MapVisitor { info, registry })?;
+                Ok(Box::new(value))
+            }
+            TypeInfo::Enum(info) => {
+                let value = deserializer.deserialize_tuple(2, EnumVisitor { info, registry })?;
+                Ok(Box::new(value))
+            }
+            TypeInfo::Value(info) => {
+                let reflect_deserialize = registry
+                    .get_type_data::<ReflectDeserialize>(info.type_id())
+                    .ok_or_else(|| {
+                        Error::custom(format_args!(
+                            "type `{}` does not support ReflectValue deserialization",
+                            info.type_name()
+                        ))
+                    })?;
+                reflect_deserialize.deserialize(deserializer)
+            }
+        }
+    }
+}
+
+fn get_serialization_data(registry: &TypeRegistry, type_id: TypeId) -> Option<&SerializationData> {
+    registry.get_type_data::<SerializationData>(type_id)
+}
+
+fn next_named_fields<'de, V: SeqAccess<'de>>(
+    seq: &mut V,
+    fields: Iter<'_, NamedField>,
+    serialization_data: Option<&SerializationData>,
+    registry: &TypeRegistry,
+) -> Result<DynamicStruct, V::Error> {
+    let mut dynamic_struct = DynamicStruct::default();
+    for (index, field) in fields.enumerate() {
+        if let Some(data) = serialization_data.filter(|data| data.is_field_skipped(index)) {
+            if let Some(value) = data.generate_default(index) {
+                dynamic_struct.insert_boxed(field.name(), value);
+            }
+            continue;
+        }
+        let value = seq
+            .next_element_seed(TypedValueSeed::of(field.type_id(), registry)?)?
+            .ok_or_else(|| Error::invalid_length(index, &"all named fields"))?;
+        dynamic_struct.insert_boxed(field.name(), value);
+    }
+    Ok(dynamic_struct)
+}
+
+fn next_unnamed_fields<'de, V: SeqAccess<'de>>(
+    seq: &mut V,
+    fields: Iter<'_, UnnamedField>,
+    registry: &TypeRegistry,
+) -> Result<DynamicTuple, V::Error> {
+    let mut tuple = DynamicTuple::default();
+    for field in fields {
+        let value = seq
+            .next_element_seed(TypedValueSeed::of(field.type_id(), registry)?)?
+            .ok_or_else(|| Error::invalid_length(field.index(), &"all unnamed fields"))?;
+        tuple.insert_boxed(value);
+    }
+    Ok(tuple)
+}
+
+struct StructVisitor<'a> {
+    info: &'a StructInfo,
+    serialization_data: Option<&'a SerializationData>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
+    type Value = DynamicStruct;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("struct fields")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut dynamic_struct = next_named_fields(
+            &mut seq,
+            self.info.iter(),
+            self.serialization_data,
+            self.registry,
+        )?;
+        dynamic_struct.set_name(self.info.type_name().to_string());
+        Ok(dynamic_struct)
+    }
+}
+
+struct TupleStructVisitor<'a> {
+    info: &'a TupleStructInfo,
+    serialization_data: Option<&'a SerializationData>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
+    type Value = DynamicTupleStruct;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("tuple struct fields")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut tuple_struct = DynamicTupleStruct::default();
+        tuple_struct.set_name(self.info.type_name().to_string());
+        for field in self.info.iter() {
+            let value = match self.serialization_data {
+                // Skipped fields are restored from their defaults so that the
+                // remaining fields keep their indices.
+                Some(data) if data.is_field_skipped(field.index()) => {
+                    data.generate_default(field.index()).ok_or_else(|| {
+                        Error::custom(format_args!(
+                            "skipped field {} of {} has no default value",
+                            field.index(),
+                            self.info.type_name()
+                        ))
+                    })?
+                }
+                _ => seq
+                    .next_element_seed(TypedValueSeed::of(field.type_id(), self.registry)?)?
+                    .ok_or_else(|| Error::invalid_length(field.index(), &self))?,
+            };
+            tuple_struct.insert_boxed(value);
+        }
+        Ok(tuple_struct)
+    }
+}
+
+struct TupleVisitor<'a> {
+    info: &'a TupleInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for TupleVisitor<'a> {
+    type Value = DynamicTuple;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("tuple fields")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut tuple = next_unnamed_fields(&mut seq, self.info.iter(), self.registry)?;
+        tuple.set_name(self.info.type_name().to_string());
+        Ok(tuple)
+    }
+}
+
+struct ListVisitor<'a> {
+    info: &'a ListInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
+    type Value = DynamicList;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("list items")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut list = DynamicList::default();
+        list.set_name(self.info.type_name().to_string());
+        while let Some(value) =
+            seq.next_element_seed(TypedValueSeed::of(self.info.item_type_id(), self.registry)?)?
+        {
+            list.push_box(value);
+        }
+        Ok(list)
+    }
+}
+
+struct ArrayVisitor<'a> {
+    info: &'a ArrayInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
+    type Value = DynamicArray;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "array of {} items", self.info.capacity())
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(self.info.capacity());
+        for index in 0..self.info.capacity() {
+            let value = seq
+                .next_element_seed(TypedValueSeed::of(self.info.item_type_id(), self.registry)?)?
+                .ok_or_else(|| Error::invalid_length(index, &self))?;
+            values.push(value);
+        }
+        let mut array = DynamicArray::new(values.into_boxed_slice());
+        array.set_name(self.info.type_name().to_string());
+        Ok(array)
+    }
+}
+
+struct MapVisitor<'a> {
+    info: &'a MapInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
+    type Value = DynamicMap;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("map entries")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut dynamic_map = DynamicMap::default();
+        dynamic_map.set_name(self.info.type_name().to_string());
+        while let Some(key) =
+            map.next_key_seed(TypedValueSeed::of(self.info.key_type_id(), self.registry)?)?
+        {
+            let value = map.next_value_seed(TypedValueSeed::of(
+                self.info.value_type_id(),
+                self.registry,
+            )?)?;
+            dynamic_map.insert_boxed(key, value);
+        }
+        Ok(dynamic_map)
+    }
+}
+
+struct EnumVisitor<'a> {
+    info: &'a EnumInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
+    type Value = DynamicEnum;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("variant index followed by variant fields")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let variant_index: u32 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let variant = self
+            .info
+            .variant_at(variant_index as usize)
+            .ok_or_else(|| {
+                Error::invalid_value(
+                    de::Unexpected::Unsigned(variant_index as u64),
+                    &"a valid variant index",
+                )
+            })?;
+        let value = seq
+            .next_element_seed(VariantSeed {
+                variant,
+                registry: self.registry,
+            })?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        Ok(DynamicEnum::new(
+            self.info.type_name(),
+            variant_index as usize,
+            variant.name(),
+            value,
+        ))
+    }
+}
+
+struct VariantSeed<'a> {
+    variant: &'a VariantInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for VariantSeed<'a> {
+    type Value = DynamicVariant;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let len = match self.variant {
+            VariantInfo::Struct(variant) => variant.field_len(),
+            VariantInfo::Tuple(variant) => variant.field_len(),
+            VariantInfo::Unit(_) => 0,
+        };
+        deserializer.deserialize_tuple(len, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for VariantSeed<'a> {
+    type Value = DynamicVariant;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "fields of variant `{}`", self.variant.name())
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        Ok(match self.variant {
+            VariantInfo::Struct(variant) => DynamicVariant::Struct(next_named_fields(
+                &mut seq,
+                variant.iter(),
+                None,
+                self.registry,
+            )?),
+            VariantInfo::Tuple(variant) => DynamicVariant::Tuple(next_unnamed_fields(
+                &mut seq,
+                variant.iter(),
+                self.registry,
+            )?),
+            VariantInfo::Unit(_) => DynamicVariant::Unit,
+        })
+    }
+}
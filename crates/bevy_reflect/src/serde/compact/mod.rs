@@ -0,0 +1,240 @@
+//! A compact, non-self-describing format for reflected values.
+//!
+//! Unlike [`ReflectSerializer`](crate::serde::ReflectSerializer), which writes
+//! type and field names alongside every value, the compact format writes a
+//! single numeric type ID assigned by the [`TypeRegistry`](crate::TypeRegistry)
+//! and then relies on the registered [`TypeInfo`](crate::TypeInfo) to write
+//! fields positionally. This makes it suitable for binary formats such as
+//! `bincode` or `postcard`, but both sides must register the same types in the
+//! same order.
+
+mod de;
+mod ser;
+
+pub use de::*;
+pub use ser::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{
+        serde::ReflectSerializer, DynamicStruct, DynamicTupleStruct, FromReflect, Reflect,
+        TypeRegistry,
+    };
+    use bevy_utils::HashMap;
+    use bincode::Options;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Position(f32, f32);
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    enum State {
+        Idle,
+        Moving { speed: f32, direction: Position },
+        Carrying(u32, String),
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        health: f32,
+        position: Position,
+        inventory: Vec<u32>,
+        stats: HashMap<String, u32>,
+        flags: [bool; 3],
+        pair: (u8, i64),
+        state: State,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<Position>();
+        registry.register::<State>();
+        registry.register::<String>();
+        registry.register::<f32>();
+        registry.register::<u32>();
+        registry.register::<u8>();
+        registry.register::<i64>();
+        registry.register::<bool>();
+        registry.register::<Vec<u32>>();
+        registry.register::<HashMap<String, u32>>();
+        registry.register::<[bool; 3]>();
+        registry.register::<(u8, i64)>();
+        registry
+    }
+
+    fn players() -> Vec<Player> {
+        let mut stats = HashMap::default();
+        stats.insert("strength".to_string(), 10);
+        stats.insert("agility".to_string(), 7);
+
+        [
+            State::Idle,
+            State::Moving {
+                speed: 2.5,
+                direction: Position(0.0, -1.0),
+            },
+            State::Carrying(3, "crate".to_string()),
+        ]
+        .into_iter()
+        .map(|state| Player {
+            name: "Alice".to_string(),
+            health: 87.5,
+            position: Position(1.0, 2.0),
+            inventory: vec![4, 8, 15],
+            stats: stats.clone(),
+            flags: [true, false, true],
+            pair: (16, -23),
+            state,
+        })
+        .collect()
+    }
+
+    #[test]
+    fn numeric_type_ids() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<u32>();
+        registry.register::<String>();
+        registry.register::<u32>();
+
+        let u32_id = registry.get_numeric_id(std::any::TypeId::of::<u32>());
+        let string_id = registry.get_numeric_id(std::any::TypeId::of::<String>());
+        assert_eq!(u32_id, Some(0));
+        assert_eq!(string_id, Some(1));
+        assert_eq!(
+            registry.get_with_numeric_id(1).unwrap().type_id(),
+            std::any::TypeId::of::<String>()
+        );
+        assert!(registry.get_with_numeric_id(2).is_none());
+    }
+
+    #[test]
+    fn bincode_roundtrip() {
+        let registry = registry();
+        for player in players() {
+            let bytes = bincode::DefaultOptions::new()
+                .serialize(&CompactReflectSerializer::new(&player, &registry))
+                .unwrap();
+
+            let value = bincode::DefaultOptions::new()
+                .deserialize_seed(CompactReflectDeserializer::new(&registry), &bytes)
+                .unwrap();
+            assert_eq!(Player::from_reflect(&*value), Some(player));
+        }
+    }
+
+    #[test]
+    fn postcard_roundtrip() {
+        let registry = registry();
+        for player in players() {
+            let bytes =
+                postcard::to_allocvec(&CompactReflectSerializer::new(&player, &registry)).unwrap();
+
+            let mut deserializer = postcard::Deserializer::from_bytes(&bytes);
+            let value = CompactReflectDeserializer::new(&registry)
+                .deserialize(&mut deserializer)
+                .unwrap();
+            assert_eq!(Player::from_reflect(&*value), Some(player));
+        }
+    }
+
+    #[test]
+    fn dynamic_struct_fields_are_written_in_declaration_order() {
+        #[derive(Reflect)]
+        struct Point {
+            x: f32,
+            y: f32,
+        }
+
+        let mut registry = registry();
+        registry.register::<Point>();
+
+        let mut dynamic = DynamicStruct::default();
+        dynamic.set_name(std::any::type_name::<Point>().to_string());
+        dynamic.insert("y", 4.0f32);
+        dynamic.insert("x", 3.0f32);
+
+        let point = Point { x: 3.0, y: 4.0 };
+        let expected =
+            postcard::to_allocvec(&CompactReflectSerializer::new(&point, &registry)).unwrap();
+        let actual =
+            postcard::to_allocvec(&CompactReflectSerializer::new(&dynamic, &registry)).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn skipped_fields() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Cached {
+            value: u32,
+            #[reflect(skip_serializing, default)]
+            cache: Vec<u32>,
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Wrapper(#[reflect(skip_serializing)] String, Cached);
+
+        let mut registry = registry();
+        registry.register::<Cached>();
+        registry.register::<Wrapper>();
+
+        let wrapper = Wrapper(
+            "runtime".to_string(),
+            Cached {
+                value: 4,
+                cache: vec![8, 15],
+            },
+        );
+        let bytes =
+            postcard::to_allocvec(&CompactReflectSerializer::new(&wrapper, &registry)).unwrap();
+
+        let mut deserializer = postcard::Deserializer::from_bytes(&bytes);
+        let value = CompactReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let expected = Wrapper(
+            String::new(),
+            Cached {
+                value: 4,
+                cache: Vec::new(),
+            },
+        );
+        assert_eq!(Wrapper::from_reflect(&*value), Some(expected));
+    }
+
+    #[test]
+    fn dynamic_tuple_struct_with_missing_fields_fails() {
+        let registry = registry();
+        let mut dynamic = DynamicTupleStruct::default();
+        dynamic.set_name(std::any::type_name::<Position>().to_string());
+        dynamic.insert(1.0f32);
+
+        let result = postcard::to_allocvec(&CompactReflectSerializer::new(&dynamic, &registry));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn compact_is_smaller_than_reflect_serializer() {
+        let registry = registry();
+        let player = &players()[1];
+
+        let compact =
+            bincode::serialize(&CompactReflectSerializer::new(player, &registry)).unwrap();
+        let full = bincode::serialize(&ReflectSerializer::new(player, &registry)).unwrap();
+        assert!(compact.len() * 4 < full.len());
+    }
+
+    #[test]
+    fn unregistered_type_fails() {
+        #[derive(Reflect)]
+        struct Unregistered(u32);
+
+        let registry = registry();
+        let result =
+            bincode::serialize(&CompactReflectSerializer::new(&Unregistered(1), &registry));
+        assert!(result.is_err());
+    }
+}
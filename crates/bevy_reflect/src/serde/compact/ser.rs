@@ -0,0 +1,288 @@
+use crate::{
+    serde::ser::{get_serializable, get_serialization_data},
+    serde::SerializationData,
+    Array, Enum, List, Map, Reflect, ReflectRef, Struct, Tuple, TupleStruct, TypeInfo,
+    TypeRegistry, VariantInfo,
+};
+use serde::{
+    ser::{Error, SerializeMap, SerializeSeq, SerializeTuple},
+    Serialize,
+};
+
+/// Serializes a reflected value in the compact format read by
+/// [`CompactReflectDeserializer`](crate::serde::CompactReflectDeserializer).
+///
+/// The value is written as its registry-assigned numeric type ID followed by
+/// its fields in declaration order, without any field or type names.
+pub struct CompactReflectSerializer<'a> {
+    pub value: &'a dyn Reflect,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactReflectSerializer<'a> {
+    pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
+        CompactReflectSerializer { value, registry }
+    }
+}
+
+impl<'a> Serialize for CompactReflectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let type_id = self
+            .registry
+            .get_with_name(self.value.type_name())
+            .map(|registration| registration.type_id())
+            .ok_or_else(|| unregistered::<S::Error>(self.value.type_name()))?;
+        let numeric_id = self.registry.get_numeric_id(type_id).unwrap();
+
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&numeric_id)?;
+        state.serialize_element(&CompactValueSerializer {
+            value: self.value,
+            registry: self.registry,
+        })?;
+        state.end()
+    }
+}
+
+fn unregistered<E: Error>(type_name: &str) -> E {
+    Error::custom(format_args!(
+        "no registration found for type `{}`",
+        type_name
+    ))
+}
+
+fn get_type_info<'r, E: Error>(
+    registry: &'r TypeRegistry,
+    type_name: &str,
+) -> Result<&'r TypeInfo, E> {
+    registry
+        .get_type_info_with_name(type_name)
+        .ok_or_else(|| unregistered(type_name))
+}
+
+struct CompactValueSerializer<'a> {
+    value: &'a dyn Reflect,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for CompactValueSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let registry = self.registry;
+        match self.value.reflect_ref() {
+            ReflectRef::Struct(value) => serialize_struct(value, registry, serializer),
+            ReflectRef::TupleStruct(value) => serialize_tuple_struct(value, registry, serializer),
+            ReflectRef::Tuple(value) => serialize_tuple(value, registry, serializer),
+            ReflectRef::List(value) => serialize_list(value, registry, serializer),
+            ReflectRef::Array(value) => serialize_array(value, registry, serializer),
+            ReflectRef::Map(value) => serialize_map(value, registry, serializer),
+            ReflectRef::Enum(value) => serialize_enum(value, registry, serializer),
+            ReflectRef::Value(value) => get_serializable::<S::Error>(value)?
+                .borrow()
+                .serialize(serializer),
+        }
+    }
+}
+
+fn compact<'a>(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> CompactValueSerializer<'a> {
+    CompactValueSerializer { value, registry }
+}
+
+fn serialize_struct<S: serde::Serializer>(
+    struct_value: &dyn Struct,
+    registry: &TypeRegistry,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let info = match get_type_info::<S::Error>(registry, struct_value.type_name())? {
+        TypeInfo::Struct(info) => info,
+        _ => {
+            return Err(Error::custom(format_args!(
+                "type `{}` is not registered as a struct",
+                struct_value.type_name()
+            )))
+        }
+    };
+
+    // Fields are written in declaration order so that dynamic structs with
+    // differently ordered fields produce the same output.
+    let serialization_data = get_serialization_data(registry, struct_value.type_name());
+    let skipped_len = serialization_data.map_or(0, SerializationData::skipped_len);
+    let mut state = serializer.serialize_tuple(info.field_len() - skipped_len)?;
+    for (index, field) in info.iter().enumerate() {
+        if serialization_data.map_or(false, |data| data.is_field_skipped(index)) {
+            continue;
+        }
+        let value = struct_value.field(field.name()).ok_or_else(|| {
+            S::Error::custom(format_args!(
+                "missing field `{}` of type `{}`",
+                field.name(),
+                struct_value.type_name()
+            ))
+        })?;
+        state.serialize_element(&compact(value, registry))?;
+    }
+    state.end()
+}
+
+fn serialize_tuple_struct<S: serde::Serializer>(
+    tuple_struct: &dyn TupleStruct,
+    registry: &TypeRegistry,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let info = match get_type_info::<S::Error>(registry, tuple_struct.type_name())? {
+        TypeInfo::TupleStruct(info) => info,
+        _ => {
+            return Err(Error::custom(format_args!(
+                "type `{}` is not registered as a tuple struct",
+                tuple_struct.type_name()
+            )))
+        }
+    };
+
+    let serialization_data = get_serialization_data(registry, tuple_struct.type_name());
+    let skipped_len = serialization_data.map_or(0, SerializationData::skipped_len);
+    let mut state = serializer.serialize_tuple(info.field_len() - skipped_len)?;
+    for index in 0..info.field_len() {
+        if serialization_data.map_or(false, |data| data.is_field_skipped(index)) {
+            continue;
+        }
+        let value = tuple_struct.field(index).ok_or_else(|| {
+            S::Error::custom(format_args!(
+                "missing field `{}` of type `{}`",
+                index,
+                tuple_struct.type_name()
+            ))
+        })?;
+        state.serialize_element(&compact(value, registry))?;
+    }
+    state.end()
+}
+
+fn serialize_tuple<S: serde::Serializer>(
+    tuple: &dyn Tuple,
+    registry: &TypeRegistry,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_tuple(tuple.field_len())?;
+    for value in tuple.iter_fields() {
+        state.serialize_element(&compact(value, registry))?;
+    }
+    state.end()
+}
+
+fn serialize_list<S: serde::Serializer>(
+    list: &dyn List,
+    registry: &TypeRegistry,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_seq(Some(list.len()))?;
+    for value in list.iter() {
+        state.serialize_element(&compact(value, registry))?;
+    }
+    state.end()
+}
+
+fn serialize_array<S: serde::Serializer>(
+    array: &dyn Array,
+    registry: &TypeRegistry,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_tuple(array.len())?;
+    for value in array.iter() {
+        state.serialize_element(&compact(value, registry))?;
+    }
+    state.end()
+}
+
+fn serialize_map<S: serde::Serializer>(
+    map: &dyn Map,
+    registry: &TypeRegistry,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_map(Some(map.len()))?;
+    for (key, value) in map.iter() {
+        state.serialize_entry(&compact(key, registry), &compact(value, registry))?;
+    }
+    state.end()
+}
+
+fn serialize_enum<S: serde::Serializer>(
+    enum_value: &dyn Enum,
+    registry: &TypeRegistry,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let info = match get_type_info::<S::Error>(registry, enum_value.type_name())? {
+        TypeInfo::Enum(info) => info,
+        _ => {
+            return Err(Error::custom(format_args!(
+                "type `{}` is not registered as an enum",
+                enum_value.type_name()
+            )))
+        }
+    };
+    let variant_index = info.index_of(enum_value.variant_name()).ok_or_else(|| {
+        S::Error::custom(format_args!(
+            "unknown variant `{}`",
+            enum_value.variant_path()
+        ))
+    })?;
+
+    let mut state = serializer.serialize_tuple(2)?;
+    state.serialize_element(&(variant_index as u32))?;
+    state.serialize_element(&VariantSerializer {
+        enum_value,
+        variant: info.variant_at(variant_index).unwrap(),
+        registry,
+    })?;
+    state.end()
+}
+
+struct VariantSerializer<'a> {
+    enum_value: &'a dyn Enum,
+    variant: &'a VariantInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for VariantSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let enum_value = self.enum_value;
+        let missing_field = |field: &dyn std::fmt::Display| {
+            S::Error::custom(format_args!(
+                "missing field `{}` of variant `{}`",
+                field,
+                enum_value.variant_path()
+            ))
+        };
+        match self.variant {
+            VariantInfo::Struct(variant) => {
+                let mut state = serializer.serialize_tuple(variant.field_len())?;
+                for field in variant.iter() {
+                    let value = enum_value
+                        .field(field.name())
+                        .ok_or_else(|| missing_field(&field.name()))?;
+                    state.serialize_element(&compact(value, self.registry))?;
+                }
+                state.end()
+            }
+            VariantInfo::Tuple(variant) => {
+                let mut state = serializer.serialize_tuple(variant.field_len())?;
+                for field in variant.iter() {
+                    let value = enum_value
+                        .field_at(field.index())
+                        .ok_or_else(|| missing_field(&field.index()))?;
+                    state.serialize_element(&compact(value, self.registry))?;
+                }
+                state.end()
+            }
+            VariantInfo::Unit(_) => serializer.serialize_tuple(0)?.end(),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
-    serde::type_fields, DynamicArray, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
-    DynamicTupleStruct, Reflect, ReflectDeserialize, TypeRegistry,
+    serde::{type_fields, SerializationData},
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, DynamicVariant, Reflect, ReflectDeserialize, Struct, TypeInfo,
+    TypeRegistration, TypeRegistry,
 };
 use erased_serde::Deserializer;
 use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
@@ -162,6 +164,7 @@ impl<'a, 'de> Visitor<'de> for ReflectVisitor<'a> {
                         .ok_or_else(|| de::Error::missing_field(type_fields::TYPE))?;
                     let mut dynamic_struct = map.next_value_seed(StructDeserializer {
                         registry: self.registry,
+                        registration: self.registry.get_with_name(&type_name),
                     })?;
                     dynamic_struct.set_name(type_name);
                     return Ok(Box::new(dynamic_struct));
@@ -172,6 +175,7 @@ impl<'a, 'de> Visitor<'de> for ReflectVisitor<'a> {
                         .ok_or_else(|| de::Error::missing_field(type_fields::TYPE))?;
                     let mut tuple_struct = map.next_value_seed(TupleStructDeserializer {
                         registry: self.registry,
+                        registration: self.registry.get_with_name(&type_name),
                     })?;
                     tuple_struct.set_name(type_name);
                     return Ok(Box::new(tuple_struct));
@@ -203,6 +207,17 @@ impl<'a, 'de> Visitor<'de> for ReflectVisitor<'a> {
                     })?;
                     return Ok(Box::new(array));
                 }
+                type_fields::ENUM => {
+                    let type_name = type_name
+                        .take()
+                        .ok_or_else(|| de::Error::missing_field(type_fields::TYPE))?;
+                    let mut dynamic_enum = map.next_value_seed(EnumDeserializer {
+                        registry: self.registry,
+                        registration: self.registry.get_with_name(&type_name),
+                    })?;
+                    dynamic_enum.set_name(type_name);
+                    return Ok(Box::new(dynamic_enum));
+                }
                 type_fields::VALUE => {
                     let type_name = type_name
                         .take()
@@ -345,7 +360,7 @@ impl<'a, 'de> DeserializeSeed<'de> for MapDeserializer<'a> {
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_map(MapVisitor {
+        deserializer.deserialize_any(MapVisitor {
             registry: self.registry,
         })
     }
@@ -362,6 +377,21 @@ impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
         formatter.write_str("map value")
     }
 
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut dynamic_map = DynamicMap::default();
+        while let Some((key, value)) = seq.next_element_seed(MapEntryDeserializer {
+            registry: self.registry,
+        })? {
+            dynamic_map.insert_boxed(key, value);
+        }
+
+        Ok(dynamic_map)
+    }
+
+    /// Maps used to be written as serde maps, which are still accepted.
     fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
     where
         V: MapAccess<'de>,
@@ -380,8 +410,49 @@ impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
     }
 }
 
+struct MapEntryDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for MapEntryDeserializer<'a> {
+    type Value = (Box<dyn Reflect>, Box<dyn Reflect>);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for MapEntryDeserializer<'a> {
+    type Value = (Box<dyn Reflect>, Box<dyn Reflect>);
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("map entry")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let key = seq
+            .next_element_seed(ReflectDeserializer {
+                registry: self.registry,
+            })?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let value = seq
+            .next_element_seed(ReflectDeserializer {
+                registry: self.registry,
+            })?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok((key, value))
+    }
+}
+
 struct StructDeserializer<'a> {
     registry: &'a TypeRegistry,
+    registration: Option<&'a TypeRegistration>,
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for StructDeserializer<'a> {
@@ -393,12 +464,14 @@ impl<'a, 'de> DeserializeSeed<'de> for StructDeserializer<'a> {
     {
         deserializer.deserialize_map(StructVisitor {
             registry: self.registry,
+            registration: self.registration,
         })
     }
 }
 
 struct StructVisitor<'a> {
     registry: &'a TypeRegistry,
+    registration: Option<&'a TypeRegistration>,
 }
 
 impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
@@ -412,12 +485,35 @@ impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
     where
         V: MapAccess<'de>,
     {
+        // Aliases and defaults are only known for registered structs.
+        let serialization = self.registration.and_then(|registration| {
+            match (
+                registration.data::<SerializationData>(),
+                registration.type_info(),
+            ) {
+                (Some(data), TypeInfo::Struct(info)) => Some((data, info)),
+                _ => None,
+            }
+        });
+
         let mut dynamic_struct = DynamicStruct::default();
         while let Some(key) = map.next_key::<String>()? {
             let value = map.next_value_seed(ReflectDeserializer {
                 registry: self.registry,
             })?;
-            dynamic_struct.insert_boxed(&key, value);
+            let name = serialization
+                .and_then(|(data, info)| info.field_at(data.index_of_alias(&key)?))
+                .map_or(key.as_str(), |field| field.name());
+            dynamic_struct.insert_boxed(name, value);
+        }
+
+        if let Some((data, info)) = serialization {
+            for index in data.iter_defaults() {
+                let name = info.field_at(index).unwrap().name();
+                if dynamic_struct.field(name).is_none() {
+                    dynamic_struct.insert_boxed(name, data.generate_default(index).unwrap());
+                }
+            }
         }
 
         Ok(dynamic_struct)
@@ -426,6 +522,7 @@ impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
 
 struct TupleStructDeserializer<'a> {
     registry: &'a TypeRegistry,
+    registration: Option<&'a TypeRegistration>,
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for TupleStructDeserializer<'a> {
@@ -437,12 +534,14 @@ impl<'a, 'de> DeserializeSeed<'de> for TupleStructDeserializer<'a> {
     {
         deserializer.deserialize_seq(TupleStructVisitor {
             registry: self.registry,
+            registration: self.registration,
         })
     }
 }
 
 struct TupleStructVisitor<'a> {
     registry: &'a TypeRegistry,
+    registration: Option<&'a TypeRegistration>,
 }
 
 impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
@@ -456,10 +555,37 @@ impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
     where
         V: SeqAccess<'de>,
     {
-        let mut tuple_struct = DynamicTupleStruct::default();
+        let mut values = Vec::new();
         while let Some(value) = seq.next_element_seed(ReflectDeserializer {
             registry: self.registry,
         })? {
+            values.push(value);
+        }
+
+        let serialization_data = self
+            .registration
+            .and_then(|registration| registration.data::<SerializationData>());
+        let skipped_len = serialization_data.map_or(0, SerializationData::skipped_len);
+
+        // Skipped fields are restored from their defaults so that the
+        // remaining fields keep their indices.
+        let mut tuple_struct = DynamicTupleStruct::default();
+        let mut values = values.into_iter();
+        for index in 0..values.len() + skipped_len {
+            let value = match serialization_data {
+                Some(data) if data.is_field_skipped(index) => {
+                    data.generate_default(index).ok_or_else(|| {
+                        de::Error::custom(format_args!(
+                            "skipped field {} of {} has no default value",
+                            index,
+                            self.registration.unwrap().name()
+                        ))
+                    })?
+                }
+                _ => values
+                    .next()
+                    .ok_or_else(|| de::Error::invalid_length(index, &self))?,
+            };
             tuple_struct.insert_boxed(value);
         }
         Ok(tuple_struct)
@@ -507,3 +633,97 @@ impl<'a, 'de> Visitor<'de> for TupleVisitor<'a> {
         Ok(tuple)
     }
 }
+
+struct EnumDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    registration: Option<&'a TypeRegistration>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EnumDeserializer<'a> {
+    type Value = DynamicEnum;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(EnumVisitor {
+            registry: self.registry,
+            registration: self.registration,
+        })
+    }
+}
+
+struct EnumVisitor<'a> {
+    registry: &'a TypeRegistry,
+    registration: Option<&'a TypeRegistration>,
+}
+
+impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
+    type Value = DynamicEnum;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("enum value")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut variant_name: Option<String> = None;
+        let mut variant = DynamicVariant::Unit;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                type_fields::VARIANT => {
+                    variant_name = Some(map.next_value()?);
+                }
+                type_fields::STRUCT => {
+                    let dynamic_struct = map.next_value_seed(StructDeserializer {
+                        registry: self.registry,
+                        registration: None,
+                    })?;
+                    variant = DynamicVariant::Struct(dynamic_struct);
+                }
+                type_fields::TUPLE => {
+                    let tuple = map.next_value_seed(TupleDeserializer {
+                        registry: self.registry,
+                    })?;
+                    variant = DynamicVariant::Tuple(tuple);
+                }
+                _ => {
+                    return Err(de::Error::unknown_field(
+                        key.as_str(),
+                        &[
+                            type_fields::VARIANT,
+                            type_fields::STRUCT,
+                            type_fields::TUPLE,
+                        ],
+                    ))
+                }
+            }
+        }
+
+        let variant_name =
+            variant_name.ok_or_else(|| de::Error::missing_field(type_fields::VARIANT))?;
+        // The variant index is only known for registered enums. Unregistered
+        // ones get an index of 0, they are still applied by variant name.
+        let variant_index = match self.registration.map(TypeRegistration::type_info) {
+            Some(TypeInfo::Enum(info)) => info.index_of(&variant_name).ok_or_else(|| {
+                de::Error::custom(format_args!(
+                    "No variant named {} in enum {}",
+                    variant_name,
+                    info.type_name()
+                ))
+            })?,
+            Some(info) => {
+                return Err(de::Error::custom(format_args!(
+                    "{} is not an enum",
+                    info.type_name()
+                )))
+            }
+            None => 0,
+        };
+        let mut dynamic_enum = DynamicEnum::default();
+        dynamic_enum.set_variant(variant_index, variant_name, variant);
+        Ok(dynamic_enum)
+    }
+}
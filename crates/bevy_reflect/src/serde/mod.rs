@@ -1,8 +1,14 @@
+mod compact;
 mod de;
+mod schema;
 mod ser;
+mod type_data;
 
+pub use compact::*;
 pub use de::*;
+pub use schema::*;
 pub use ser::*;
+pub use type_data::*;
 
 pub(crate) mod type_fields {
     pub const TYPE: &str = "type";
@@ -12,5 +18,7 @@ pub(crate) mod type_fields {
     pub const TUPLE: &str = "tuple";
     pub const LIST: &str = "list";
     pub const ARRAY: &str = "array";
+    pub const ENUM: &str = "enum";
+    pub const VARIANT: &str = "variant";
     pub const VALUE: &str = "value";
 }
@@ -0,0 +1,719 @@
+use crate::{
+    serde::{type_fields, SerializationData},
+    NamedField, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField, ValueInfo, VariantInfo,
+};
+use serde::{
+    ser::{SerializeMap, SerializeSeq},
+    Serialize,
+};
+use std::borrow::Cow;
+
+/// The JSON Schema dialect of the generated documents.
+const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Serializes a [JSON Schema] describing the values produced by
+/// [`ReflectSerializer`] for the types in a [`TypeRegistry`].
+///
+/// Every registered type is described in `$defs` under its type name, and the
+/// root schema accepts a value of any of them. Fields whose type isn't
+/// registered are only checked for their `type` entry.
+///
+/// Maps are described as arrays of `[key, value]` entries, as `ReflectSerializer`
+/// writes them.
+///
+/// ```
+/// # use bevy_reflect::{serde::TypeRegistrySchema, Reflect, TypeRegistry};
+/// #[derive(Reflect)]
+/// struct Player {
+///     name: String,
+///     health: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+/// let schema = ron::to_string(&TypeRegistrySchema::new(&registry)).unwrap();
+/// assert!(schema.contains("\"health\""));
+/// ```
+///
+/// [JSON Schema]: https://json-schema.org
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+pub struct TypeRegistrySchema<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> TypeRegistrySchema<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        TypeRegistrySchema { registry }
+    }
+}
+
+impl<'a> Serialize for TypeRegistrySchema<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        // Sorted so the document doesn't change between runs.
+        let mut registrations = self.registry.iter().collect::<Vec<_>>();
+        registrations.sort_by_key(|registration| registration.name());
+
+        let definitions = registrations
+            .iter()
+            .map(|registration| {
+                (
+                    registration.name(),
+                    type_schema(registration, self.registry),
+                )
+            })
+            .collect();
+        let any_of = registrations
+            .iter()
+            .map(|registration| definition_ref(registration.name()))
+            .collect();
+
+        Schema::Object(vec![
+            ("$schema", Schema::string(DIALECT)),
+            ("$defs", Schema::Object(definitions)),
+            ("anyOf", Schema::Array(any_of)),
+        ])
+        .serialize(serializer)
+    }
+}
+
+/// A JSON value making up a schema.
+enum Schema {
+    Bool(bool),
+    Int(i64),
+    String(Cow<'static, str>),
+    Array(Vec<Schema>),
+    Object(Vec<(&'static str, Schema)>),
+}
+
+impl Schema {
+    fn string(value: impl Into<Cow<'static, str>>) -> Self {
+        Schema::String(value.into())
+    }
+
+    fn strings(values: impl IntoIterator<Item = &'static str>) -> Self {
+        Schema::Array(values.into_iter().map(Schema::string).collect())
+    }
+}
+
+impl Serialize for Schema {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Schema::Bool(value) => serializer.serialize_bool(*value),
+            Schema::Int(value) => serializer.serialize_i64(*value),
+            Schema::String(value) => serializer.serialize_str(value),
+            Schema::Array(values) => {
+                let mut state = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    state.serialize_element(value)?;
+                }
+                state.end()
+            }
+            Schema::Object(entries) => {
+                let mut state = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    state.serialize_entry(key, value)?;
+                }
+                state.end()
+            }
+        }
+    }
+}
+
+/// Describes a value of a registered type, as written by `ReflectSerializer`:
+/// a map of its type name and its kind-specific representation.
+fn type_schema(registration: &TypeRegistration, registry: &TypeRegistry) -> Schema {
+    let serialization_data = registration.data::<SerializationData>();
+    let (kind, value) = match registration.type_info() {
+        TypeInfo::Struct(info) => (
+            type_fields::STRUCT,
+            struct_schema(info.iter(), serialization_data, registry),
+        ),
+        TypeInfo::TupleStruct(info) => (
+            type_fields::TUPLE_STRUCT,
+            tuple_schema(info.iter(), serialization_data, registry),
+        ),
+        TypeInfo::Tuple(info) => (
+            type_fields::TUPLE,
+            tuple_schema(info.iter(), None, registry),
+        ),
+        TypeInfo::List(info) => (
+            type_fields::LIST,
+            Schema::Object(vec![
+                ("type", Schema::string("array")),
+                ("items", value_ref(info.item_type_name(), registry)),
+            ]),
+        ),
+        TypeInfo::Array(info) => (
+            type_fields::ARRAY,
+            Schema::Object(vec![
+                ("type", Schema::string("array")),
+                ("items", value_ref(info.item_type_name(), registry)),
+                ("minItems", Schema::Int(info.capacity() as i64)),
+                ("maxItems", Schema::Int(info.capacity() as i64)),
+            ]),
+        ),
+        TypeInfo::Map(info) => (
+            type_fields::MAP,
+            Schema::Object(vec![
+                ("type", Schema::string("array")),
+                (
+                    "items",
+                    Schema::Object(vec![
+                        ("type", Schema::string("array")),
+                        ("minItems", Schema::Int(2)),
+                        (
+                            "prefixItems",
+                            Schema::Array(vec![
+                                value_ref(info.key_type_name(), registry),
+                                value_ref(info.value_type_name(), registry),
+                            ]),
+                        ),
+                        ("items", Schema::Bool(false)),
+                    ]),
+                ),
+            ]),
+        ),
+        TypeInfo::Enum(info) => (
+            type_fields::ENUM,
+            Schema::Object(vec![(
+                "oneOf",
+                Schema::Array(
+                    info.iter()
+                        .map(|variant| variant_schema(variant, registry))
+                        .collect(),
+                ),
+            )]),
+        ),
+        TypeInfo::Value(info) => (type_fields::VALUE, primitive_schema(info)),
+    };
+
+    Schema::Object(vec![
+        (
+            "title",
+            Schema::string(registration.short_name().to_string()),
+        ),
+        ("type", Schema::string("object")),
+        (
+            "properties",
+            Schema::Object(vec![
+                (type_fields::TYPE, const_string(registration.name())),
+                (kind, value),
+            ]),
+        ),
+        ("required", Schema::strings([type_fields::TYPE, kind])),
+        ("additionalProperties", Schema::Bool(false)),
+    ])
+}
+
+/// Describes the fields of a struct or struct variant.
+///
+/// Skipped fields are left out, and fields with a default or an alias don't
+/// need to be present under their own name.
+fn struct_schema<'a>(
+    fields: impl Iterator<Item = &'a NamedField>,
+    serialization_data: Option<&SerializationData>,
+    registry: &TypeRegistry,
+) -> Schema {
+    let fields = fields.collect::<Vec<_>>();
+    let mut properties = Vec::new();
+    let mut required = Vec::new();
+    let mut all_of = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        if serialization_data.map_or(false, |data| data.is_field_skipped(index)) {
+            continue;
+        }
+        properties.push((field.name(), value_ref(field.type_name(), registry)));
+
+        if serialization_data.map_or(false, |data| data.has_default(index)) {
+            continue;
+        }
+        let mut aliases = serialization_data
+            .map(|data| {
+                data.iter_aliases()
+                    .filter(|(_, alias_index)| *alias_index == index)
+                    .map(|(alias, _)| alias)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if aliases.is_empty() {
+            required.push(field.name());
+        } else {
+            aliases.sort_unstable();
+            let names = std::iter::once(field.name()).chain(aliases);
+            all_of.push(Schema::Object(vec![(
+                "anyOf",
+                Schema::Array(
+                    names
+                        .map(|name| Schema::Object(vec![("required", Schema::strings([name]))]))
+                        .collect(),
+                ),
+            )]));
+        }
+    }
+
+    if let Some(data) = serialization_data {
+        let mut aliases = data.iter_aliases().collect::<Vec<_>>();
+        aliases.sort_unstable();
+        for (alias, index) in aliases {
+            if let Some(field) = fields.get(index) {
+                properties.push((alias, value_ref(field.type_name(), registry)));
+            }
+        }
+    }
+
+    let mut schema = vec![
+        ("type", Schema::string("object")),
+        ("properties", Schema::Object(properties)),
+        ("required", Schema::strings(required)),
+        ("additionalProperties", Schema::Bool(false)),
+    ];
+    if !all_of.is_empty() {
+        schema.push(("allOf", Schema::Array(all_of)));
+    }
+    Schema::Object(schema)
+}
+
+/// Describes the fields of a tuple, tuple struct or tuple variant.
+fn tuple_schema<'a>(
+    fields: impl Iterator<Item = &'a UnnamedField>,
+    serialization_data: Option<&SerializationData>,
+    registry: &TypeRegistry,
+) -> Schema {
+    let items = fields
+        .filter(|field| {
+            !serialization_data.map_or(false, |data| data.is_field_skipped(field.index()))
+        })
+        .map(|field| value_ref(field.type_name(), registry))
+        .collect::<Vec<_>>();
+    Schema::Object(vec![
+        ("type", Schema::string("array")),
+        ("minItems", Schema::Int(items.len() as i64)),
+        ("prefixItems", Schema::Array(items)),
+        ("items", Schema::Bool(false)),
+    ])
+}
+
+/// Describes an enum variant, as written by `EnumValueSerializer`.
+fn variant_schema(variant: &VariantInfo, registry: &TypeRegistry) -> Schema {
+    let mut properties = vec![(type_fields::VARIANT, const_string(variant.name()))];
+    let mut required = vec![type_fields::VARIANT];
+    match variant {
+        VariantInfo::Struct(info) => {
+            properties.push((
+                type_fields::STRUCT,
+                struct_schema(info.iter(), None, registry),
+            ));
+            required.push(type_fields::STRUCT);
+        }
+        VariantInfo::Tuple(info) => {
+            properties.push((
+                type_fields::TUPLE,
+                tuple_schema(info.iter(), None, registry),
+            ));
+            required.push(type_fields::TUPLE);
+        }
+        VariantInfo::Unit(_) => {}
+    }
+
+    Schema::Object(vec![
+        ("title", Schema::string(variant.name())),
+        ("type", Schema::string("object")),
+        ("properties", Schema::Object(properties)),
+        ("required", Schema::strings(required)),
+        ("additionalProperties", Schema::Bool(false)),
+    ])
+}
+
+/// Describes the serde representation of a value type.
+///
+/// Only primitives are known, anything else is accepted as is.
+fn primitive_schema(info: &ValueInfo) -> Schema {
+    macro_rules! integer {
+        ($($ty:ty),*) => {
+            $(
+                if info.is::<$ty>() {
+                    return Schema::Object(vec![
+                        ("type", Schema::string("integer")),
+                        ("minimum", Schema::Int(<$ty>::MIN as i64)),
+                        ("maximum", Schema::Int(<$ty>::MAX as i64)),
+                    ]);
+                }
+            )*
+        };
+    }
+    integer!(u8, u16, u32, i8, i16, i32, i64);
+
+    if info.is::<u64>() || info.is::<u128>() || info.is::<usize>() {
+        Schema::Object(vec![
+            ("type", Schema::string("integer")),
+            ("minimum", Schema::Int(0)),
+        ])
+    } else if info.is::<i128>() || info.is::<isize>() {
+        Schema::Object(vec![("type", Schema::string("integer"))])
+    } else if info.is::<f32>() || info.is::<f64>() {
+        Schema::Object(vec![("type", Schema::string("number"))])
+    } else if info.is::<bool>() {
+        Schema::Object(vec![("type", Schema::string("boolean"))])
+    } else if info.is::<String>() || info.is::<Cow<'static, str>>() {
+        Schema::Object(vec![("type", Schema::string("string"))])
+    } else if info.is::<char>() {
+        Schema::Object(vec![
+            ("type", Schema::string("string")),
+            ("minLength", Schema::Int(1)),
+            ("maxLength", Schema::Int(1)),
+        ])
+    } else {
+        Schema::Object(Vec::new())
+    }
+}
+
+/// Describes a nested value of the given type.
+fn value_ref(type_name: &'static str, registry: &TypeRegistry) -> Schema {
+    if registry.get_with_name(type_name).is_some() {
+        definition_ref(type_name)
+    } else {
+        Schema::Object(vec![
+            ("type", Schema::string("object")),
+            (
+                "properties",
+                Schema::Object(vec![(type_fields::TYPE, const_string(type_name))]),
+            ),
+            ("required", Schema::strings([type_fields::TYPE])),
+        ])
+    }
+}
+
+fn definition_ref(type_name: &str) -> Schema {
+    let mut reference = String::from("#/$defs/");
+    for byte in type_name.bytes() {
+        match byte {
+            b'~' => reference.push_str("~0"),
+            b'/' => reference.push_str("~1"),
+            // Characters allowed in a URI fragment.
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'.'
+            | b'_'
+            | b'!'
+            | b'$'
+            | b'&'
+            | b'\''
+            | b'('
+            | b')'
+            | b'*'
+            | b'+'
+            | b','
+            | b';'
+            | b'='
+            | b':'
+            | b'@'
+            | b'?' => reference.push(byte as char),
+            _ => reference.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    Schema::Object(vec![("$ref", Schema::string(reference))])
+}
+
+fn const_string(value: &'static str) -> Schema {
+    Schema::Object(vec![("const", Schema::string(value))])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{definition_ref, TypeRegistrySchema};
+    use crate as bevy_reflect;
+    use crate::{serde::ReflectSerializer, Reflect, TypeRegistry};
+    use bevy_utils::HashMap;
+    use serde_json::{json, Value};
+
+    fn schema(registry: &TypeRegistry) -> Value {
+        serde_json::to_value(TypeRegistrySchema::new(registry)).unwrap()
+    }
+
+    /// Checks `value` against `schema`, for the keywords used by `TypeRegistrySchema`.
+    fn validate(value: &Value, schema: &Value, root: &Value) -> bool {
+        let schema = match schema {
+            Value::Bool(valid) => return *valid,
+            Value::Object(schema) => schema,
+            _ => panic!("Invalid schema {}", schema),
+        };
+        let len = |expected: &Value| expected.as_u64().unwrap() as usize;
+        schema
+            .iter()
+            .all(|(keyword, expected)| match keyword.as_str() {
+                "$schema" | "$defs" | "title" => true,
+                "$ref" => {
+                    let (_, definition) = root["$defs"]
+                        .as_object()
+                        .unwrap()
+                        .iter()
+                        .find(|(name, _)| {
+                            serde_json::to_value(definition_ref(name)).unwrap()["$ref"] == *expected
+                        })
+                        .unwrap();
+                    validate(value, definition, root)
+                }
+                "type" => match expected.as_str().unwrap() {
+                    "object" => value.is_object(),
+                    "array" => value.is_array(),
+                    "string" => value.is_string(),
+                    "integer" => value.is_i64() || value.is_u64(),
+                    "number" => value.is_number(),
+                    "boolean" => value.is_boolean(),
+                    other => panic!("Unknown type {}", other),
+                },
+                "const" => value == expected,
+                "properties" => expected.as_object().unwrap().iter().all(|(name, schema)| {
+                    value
+                        .get(name)
+                        .map_or(true, |field| validate(field, schema, root))
+                }),
+                "required" => expected
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .all(|name| value.get(name.as_str().unwrap()).is_some()),
+                "additionalProperties" => value.as_object().map_or(true, |object| {
+                    object
+                        .iter()
+                        .filter(|(name, _)| schema["properties"].get(name).is_none())
+                        .all(|(_, field)| validate(field, expected, root))
+                }),
+                "anyOf" => expected
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .any(|schema| validate(value, schema, root)),
+                "oneOf" => {
+                    expected
+                        .as_array()
+                        .unwrap()
+                        .iter()
+                        .filter(|schema| validate(value, schema, root))
+                        .count()
+                        == 1
+                }
+                "allOf" => expected
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .all(|schema| validate(value, schema, root)),
+                "prefixItems" => value.as_array().map_or(true, |items| {
+                    items
+                        .iter()
+                        .zip(expected.as_array().unwrap())
+                        .all(|(item, schema)| validate(item, schema, root))
+                }),
+                "items" => value.as_array().map_or(true, |items| {
+                    let prefix_len = schema
+                        .get("prefixItems")
+                        .map_or(0, |prefix| prefix.as_array().unwrap().len());
+                    items
+                        .iter()
+                        .skip(prefix_len)
+                        .all(|item| validate(item, expected, root))
+                }),
+                "minItems" => value
+                    .as_array()
+                    .map_or(true, |items| items.len() >= len(expected)),
+                "maxItems" => value
+                    .as_array()
+                    .map_or(true, |items| items.len() <= len(expected)),
+                "minLength" => value
+                    .as_str()
+                    .map_or(true, |string| string.chars().count() >= len(expected)),
+                "maxLength" => value
+                    .as_str()
+                    .map_or(true, |string| string.chars().count() <= len(expected)),
+                "minimum" => value
+                    .as_f64()
+                    .map_or(true, |number| number >= expected.as_f64().unwrap()),
+                "maximum" => value
+                    .as_f64()
+                    .map_or(true, |number| number <= expected.as_f64().unwrap()),
+                other => panic!("Unsupported keyword {}", other),
+            })
+    }
+
+    #[test]
+    fn struct_schema() {
+        #[derive(Reflect)]
+        struct Foo {
+            a: u8,
+            #[reflect(skip_serializing)]
+            b: usize,
+            #[reflect(default)]
+            c: Vec<f32>,
+            #[reflect(alias = "old_d")]
+            d: Bar,
+        }
+
+        #[derive(Reflect)]
+        struct Bar(String, (bool, char));
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+        registry.register::<Bar>();
+        registry.register::<u8>();
+        registry.register::<Vec<f32>>();
+
+        let schema = schema(&registry);
+        let foo_name = std::any::type_name::<Foo>();
+        let bar_name = std::any::type_name::<Bar>();
+        let bar_ref = format!("#/$defs/{}", bar_name);
+        let foo = &schema["$defs"][foo_name];
+
+        assert_eq!(foo["properties"]["type"], json!({ "const": foo_name }));
+        assert_eq!(foo["required"], json!(["type", "struct"]));
+        let fields = &foo["properties"]["struct"];
+        assert_eq!(fields["properties"]["a"], json!({ "$ref": "#/$defs/u8" }));
+        assert!(fields["properties"].get("b").is_none());
+        assert_eq!(fields["properties"]["d"], json!({ "$ref": bar_ref }));
+        assert_eq!(fields["properties"]["old_d"], json!({ "$ref": bar_ref }));
+        assert_eq!(fields["required"], json!(["a"]));
+        assert_eq!(
+            fields["allOf"],
+            json!([{ "anyOf": [{ "required": ["d"] }, { "required": ["old_d"] }] }])
+        );
+
+        let bar = &schema["$defs"][bar_name]["properties"]["tuple_struct"];
+        assert_eq!(bar["minItems"], json!(2));
+        assert_eq!(
+            bar["prefixItems"][0],
+            json!({
+                "type": "object",
+                "properties": { "type": { "const": "alloc::string::String" } },
+                "required": ["type"],
+            })
+        );
+
+        let u8_value = &schema["$defs"]["u8"]["properties"]["value"];
+        assert_eq!(
+            u8_value,
+            &json!({ "type": "integer", "minimum": 0, "maximum": 255 })
+        );
+        let list = &schema["$defs"][std::any::type_name::<Vec<f32>>()]["properties"]["list"];
+        assert_eq!(list["type"], json!("array"));
+
+        assert_eq!(schema["anyOf"].as_array().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn enum_schema() {
+        #[derive(Reflect)]
+        enum Foo {
+            A,
+            B(u8),
+            C { value: u8 },
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+        registry.register::<u8>();
+
+        let schema = schema(&registry);
+        let variants =
+            &schema["$defs"][std::any::type_name::<Foo>()]["properties"]["enum"]["oneOf"];
+        assert_eq!(
+            variants[0]["properties"],
+            json!({ "variant": { "const": "A" } })
+        );
+        assert_eq!(variants[1]["required"], json!(["variant", "tuple"]));
+        assert_eq!(
+            variants[1]["properties"]["tuple"]["prefixItems"],
+            json!([{ "$ref": "#/$defs/u8" }])
+        );
+        assert_eq!(variants[2]["required"], json!(["variant", "struct"]));
+        assert_eq!(
+            variants[2]["properties"]["struct"]["required"],
+            json!(["value"])
+        );
+    }
+
+    #[test]
+    fn serialized_values_match_schema() {
+        #[derive(Reflect)]
+        struct Player {
+            name: String,
+            health: u8,
+            #[reflect(skip_serializing)]
+            cache: Vec<u32>,
+            path: Vec<f32>,
+            scores: HashMap<String, u32>,
+            position: Position,
+            state: State,
+        }
+
+        #[derive(Reflect)]
+        struct Position(f32, f32);
+
+        #[derive(Reflect)]
+        enum State {
+            Idle,
+            Moving(f32),
+            Attacking { target: u32 },
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<Position>();
+        registry.register::<State>();
+        registry.register::<String>();
+        registry.register::<u8>();
+        registry.register::<u32>();
+        registry.register::<f32>();
+        registry.register::<Vec<f32>>();
+        registry.register::<HashMap<String, u32>>();
+        let schema = schema(&registry);
+
+        for state in [
+            State::Idle,
+            State::Moving(2.0),
+            State::Attacking { target: 3 },
+        ] {
+            let mut scores = HashMap::default();
+            scores.insert("wins".to_string(), 3);
+            scores.insert("losses".to_string(), 1);
+            let player = Player {
+                name: "player".to_string(),
+                health: 100,
+                cache: vec![1, 2],
+                path: vec![0.0, 1.5],
+                scores,
+                position: Position(1.0, 2.0),
+                state,
+            };
+
+            let mut value =
+                serde_json::to_value(ReflectSerializer::new(&player, &registry)).unwrap();
+            assert!(validate(&value, &schema, &schema), "{}", value);
+
+            value["struct"]["health"]["value"] = json!(300);
+            assert!(!validate(&value, &schema, &schema));
+        }
+    }
+
+    #[test]
+    fn definition_refs_are_escaped() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<[u8; 2]>();
+
+        let schema = schema(&registry);
+        assert_eq!(
+            schema["anyOf"],
+            json!([{ "$ref": "#/$defs/%5Bu8;%202%5D" }])
+        );
+        assert_eq!(
+            schema["$defs"]["[u8; 2]"]["properties"]["array"]["maxItems"],
+            json!(2)
+        );
+    }
+}
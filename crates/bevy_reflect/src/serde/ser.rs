@@ -1,6 +1,7 @@
 use crate::{
-    serde::type_fields, Array, List, Map, Reflect, ReflectRef, Struct, Tuple, TupleStruct,
-    TypeRegistry,
+    serde::{type_fields, SerializationData},
+    Array, Enum, List, Map, Reflect, ReflectRef, Struct, Tuple, TupleStruct, TypeInfo,
+    TypeRegistry, VariantType,
 };
 use serde::{
     ser::{SerializeMap, SerializeSeq},
@@ -22,7 +23,9 @@ impl<'a> Serializable<'a> {
     }
 }
 
-fn get_serializable<E: serde::ser::Error>(reflect_value: &dyn Reflect) -> Result<Serializable, E> {
+pub(super) fn get_serializable<E: serde::ser::Error>(
+    reflect_value: &dyn Reflect,
+) -> Result<Serializable, E> {
     reflect_value.serializable().ok_or_else(|| {
         serde::ser::Error::custom(format_args!(
             "Type '{}' does not support ReflectValue serialization",
@@ -31,6 +34,16 @@ fn get_serializable<E: serde::ser::Error>(reflect_value: &dyn Reflect) -> Result
     })
 }
 
+/// Returns the [`SerializationData`] registered for the given type, if any.
+pub(super) fn get_serialization_data<'a>(
+    registry: &'a TypeRegistry,
+    type_name: &str,
+) -> Option<&'a SerializationData> {
+    registry
+        .get_with_name(type_name)
+        .and_then(|registration| registration.data::<SerializationData>())
+}
+
 pub struct ReflectSerializer<'a> {
     pub value: &'a dyn Reflect,
     pub registry: &'a TypeRegistry,
@@ -78,6 +91,11 @@ impl<'a> Serialize for ReflectSerializer<'a> {
                 registry: self.registry,
             }
             .serialize(serializer),
+            ReflectRef::Enum(value) => EnumSerializer {
+                enum_value: value,
+                registry: self.registry,
+            }
+            .serialize(serializer),
             ReflectRef::Value(value) => ReflectValueSerializer {
                 registry: self.registry,
                 value,
@@ -141,10 +159,36 @@ impl<'a> Serialize for StructValueSerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_map(Some(self.struct_value.field_len()))?;
-        for (index, value) in self.struct_value.iter_fields().enumerate() {
-            let key = self.struct_value.name_at(index).unwrap();
-            state.serialize_entry(key, &ReflectSerializer::new(value, self.registry))?;
+        // Skipped fields are looked up by name, since dynamic structs may have
+        // their fields in any order.
+        let serialization = self
+            .registry
+            .get_with_name(self.struct_value.type_name())
+            .and_then(|registration| {
+                match (
+                    registration.data::<SerializationData>(),
+                    registration.type_info(),
+                ) {
+                    (Some(data), TypeInfo::Struct(info)) => Some((data, info)),
+                    _ => None,
+                }
+            });
+        let fields: Vec<_> = self
+            .struct_value
+            .iter_fields()
+            .enumerate()
+            .map(|(index, value)| (self.struct_value.name_at(index).unwrap(), value))
+            .filter(|(name, _)| {
+                !serialization.map_or(false, |(data, info)| {
+                    info.index_of(name)
+                        .map_or(false, |index| data.is_field_skipped(index))
+                })
+            })
+            .collect();
+
+        let mut state = serializer.serialize_map(Some(fields.len()))?;
+        for (name, value) in fields {
+            state.serialize_entry(name, &ReflectSerializer::new(value, self.registry))?;
         }
         state.end()
     }
@@ -184,14 +228,126 @@ impl<'a> Serialize for TupleStructValueSerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_seq(Some(self.tuple_struct.field_len()))?;
-        for value in self.tuple_struct.iter_fields() {
+        let serialization_data =
+            get_serialization_data(self.registry, self.tuple_struct.type_name());
+        let fields: Vec<_> = self
+            .tuple_struct
+            .iter_fields()
+            .enumerate()
+            .filter(|(index, _)| {
+                !serialization_data.map_or(false, |data| data.is_field_skipped(*index))
+            })
+            .map(|(_, value)| value)
+            .collect();
+
+        let mut state = serializer.serialize_seq(Some(fields.len()))?;
+        for value in fields {
             state.serialize_element(&ReflectSerializer::new(value, self.registry))?;
         }
         state.end()
     }
 }
 
+pub struct EnumSerializer<'a> {
+    pub enum_value: &'a dyn Enum,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for EnumSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(2))?;
+
+        state.serialize_entry(type_fields::TYPE, self.enum_value.type_name())?;
+        state.serialize_entry(
+            type_fields::ENUM,
+            &EnumValueSerializer {
+                enum_value: self.enum_value,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+pub struct EnumValueSerializer<'a> {
+    pub enum_value: &'a dyn Enum,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for EnumValueSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant_type = self.enum_value.variant_type();
+        let len = if variant_type == VariantType::Unit {
+            1
+        } else {
+            2
+        };
+        let mut state = serializer.serialize_map(Some(len))?;
+        state.serialize_entry(type_fields::VARIANT, self.enum_value.variant_name())?;
+        match variant_type {
+            VariantType::Struct => state.serialize_entry(
+                type_fields::STRUCT,
+                &StructVariantSerializer {
+                    enum_value: self.enum_value,
+                    registry: self.registry,
+                },
+            )?,
+            VariantType::Tuple => state.serialize_entry(
+                type_fields::TUPLE,
+                &TupleVariantSerializer {
+                    enum_value: self.enum_value,
+                    registry: self.registry,
+                },
+            )?,
+            VariantType::Unit => {}
+        }
+        state.end()
+    }
+}
+
+pub struct StructVariantSerializer<'a> {
+    pub enum_value: &'a dyn Enum,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for StructVariantSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.enum_value.field_len()))?;
+        for field in self.enum_value.iter_fields() {
+            let key = field.name().unwrap();
+            state.serialize_entry(key, &ReflectSerializer::new(field.value(), self.registry))?;
+        }
+        state.end()
+    }
+}
+
+pub struct TupleVariantSerializer<'a> {
+    pub enum_value: &'a dyn Enum,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for TupleVariantSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.enum_value.field_len()))?;
+        for field in self.enum_value.iter_fields() {
+            state.serialize_element(&ReflectSerializer::new(field.value(), self.registry))?;
+        }
+        state.end()
+    }
+}
+
 pub struct TupleSerializer<'a> {
     pub tuple: &'a dyn Tuple,
     pub registry: &'a TypeRegistry,
@@ -268,12 +424,14 @@ impl<'a> Serialize for MapValueSerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_map(Some(self.map.len()))?;
+        // Entries are written as `(key, value)` pairs rather than as a serde map, since the
+        // keys are reflected values which formats like JSON can't use as map keys.
+        let mut state = serializer.serialize_seq(Some(self.map.len()))?;
         for (key, value) in self.map.iter() {
-            state.serialize_entry(
-                &ReflectSerializer::new(key, self.registry),
-                &ReflectSerializer::new(value, self.registry),
-            )?;
+            state.serialize_element(&(
+                ReflectSerializer::new(key, self.registry),
+                ReflectSerializer::new(value, self.registry),
+            ))?;
         }
         state.end()
     }
@@ -0,0 +1,67 @@
+use crate::Reflect;
+use bevy_utils::{HashMap, HashSet};
+
+/// Per-field serialization settings of a struct or tuple struct.
+///
+/// This is registered as type data by `#[derive(Reflect)]` when any field uses
+/// `#[reflect(skip_serializing)]`, `#[reflect(default)]` or
+/// `#[reflect(alias = "...")]`. Fields are identified by their reflected index.
+#[derive(Clone, Default)]
+pub struct SerializationData {
+    skipped_fields: HashSet<usize>,
+    defaults: HashMap<usize, fn() -> Box<dyn Reflect>>,
+    aliases: HashMap<&'static str, usize>,
+}
+
+impl SerializationData {
+    /// Leaves the field at `index` out of serialized data.
+    pub fn skip_field(&mut self, index: usize) {
+        self.skipped_fields.insert(index);
+    }
+
+    /// Sets the function producing the value of the field at `index` when it
+    /// is missing from serialized data.
+    pub fn set_default(&mut self, index: usize, default: fn() -> Box<dyn Reflect>) {
+        self.defaults.insert(index, default);
+    }
+
+    /// Accepts `alias` as the name of the field at `index` when deserializing.
+    pub fn add_alias(&mut self, alias: &'static str, index: usize) {
+        self.aliases.insert(alias, index);
+    }
+
+    /// Returns true if the field at `index` is left out of serialized data.
+    pub fn is_field_skipped(&self, index: usize) -> bool {
+        self.skipped_fields.contains(&index)
+    }
+
+    /// Returns the number of fields left out of serialized data.
+    pub fn skipped_len(&self) -> usize {
+        self.skipped_fields.len()
+    }
+
+    /// Generates the default value of the field at `index`, if it has one.
+    pub fn generate_default(&self, index: usize) -> Option<Box<dyn Reflect>> {
+        self.defaults.get(&index).map(|default| default())
+    }
+
+    /// Returns true if the field at `index` has a default value.
+    pub fn has_default(&self, index: usize) -> bool {
+        self.defaults.contains_key(&index)
+    }
+
+    /// Returns an iterator over the indices of the fields with a default value.
+    pub fn iter_defaults(&self) -> impl Iterator<Item = usize> + '_ {
+        self.defaults.keys().copied()
+    }
+
+    /// Returns the index of the field with the given alias.
+    pub fn index_of_alias(&self, alias: &str) -> Option<usize> {
+        self.aliases.get(alias).copied()
+    }
+
+    /// Returns an iterator over the aliases and the indices of their fields.
+    pub fn iter_aliases(&self) -> impl Iterator<Item = (&'static str, usize)> + '_ {
+        self.aliases.iter().map(|(alias, index)| (*alias, *index))
+    }
+}
@@ -1,9 +1,10 @@
 use crate::{
     FromReflect, FromType, GetTypeRegistration, Reflect, ReflectDeserialize, ReflectMut,
-    ReflectRef, TypeRegistration,
+    ReflectRef, TypeInfo, TypeRegistration, Typed, UnnamedField,
 };
 use serde::Deserialize;
-use std::any::Any;
+use std::any::{Any, TypeId};
+use std::slice::Iter;
 
 /// A reflected Rust tuple.
 ///
@@ -42,6 +43,61 @@ pub trait Tuple: Reflect {
     fn clone_dynamic(&self) -> DynamicTuple;
 }
 
+/// A container for compile-time tuple info.
+#[derive(Debug, Clone)]
+pub struct TupleInfo {
+    type_name: &'static str,
+    type_id: TypeId,
+    fields: Box<[UnnamedField]>,
+}
+
+impl TupleInfo {
+    /// Create a new [`TupleInfo`].
+    ///
+    /// # Arguments
+    ///
+    /// * `fields`: The fields of this tuple in the order they are defined
+    pub fn new<T: Reflect>(fields: &[UnnamedField]) -> Self {
+        Self {
+            type_name: std::any::type_name::<T>(),
+            type_id: TypeId::of::<T>(),
+            fields: fields.to_vec().into_boxed_slice(),
+        }
+    }
+
+    /// Get the field at the given index.
+    pub fn field_at(&self, index: usize) -> Option<&UnnamedField> {
+        self.fields.get(index)
+    }
+
+    /// Iterate over the fields of this tuple.
+    pub fn iter(&self) -> Iter<'_, UnnamedField> {
+        self.fields.iter()
+    }
+
+    /// The total number of fields in this tuple.
+    pub fn field_len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// The [type name] of the tuple.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The [`TypeId`] of the tuple.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Check if the given type matches the tuple type.
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id
+    }
+}
+
 /// An iterator over the field values of a tuple.
 pub struct TupleFieldIter<'a> {
     pub(crate) tuple: &'a dyn Tuple,
@@ -405,6 +461,14 @@ macro_rules! impl_reflect_tuple {
             }
         }
 
+        impl<$($name: Reflect),*> Typed for ($($name,)*) {
+            fn type_info() -> TypeInfo {
+                TypeInfo::Tuple(TupleInfo::new::<Self>(&[
+                    $(UnnamedField::new::<$name>($index),)*
+                ]))
+            }
+        }
+
         impl<$($name: Reflect + for<'de> Deserialize<'de>),*> GetTypeRegistration for ($($name,)*) {
             fn get_type_registration() -> TypeRegistration {
                 let mut registration = TypeRegistration::of::<($($name,)*)>();
@@ -0,0 +1,134 @@
+use crate::{
+    ArrayInfo, EnumInfo, ListInfo, MapInfo, Reflect, StructInfo, TupleInfo, TupleStructInfo,
+};
+use std::any::{Any, TypeId};
+
+/// A static accessor to compile-time type information.
+///
+/// This trait is automatically implemented by the `#[derive(Reflect)]` macro
+/// and allows type information to be processed without an instance of that type.
+///
+/// # Example
+///
+/// ```
+/// use bevy_reflect::{Reflect, TypeInfo, Typed};
+///
+/// #[derive(Reflect)]
+/// struct Foo {
+///     bar: usize,
+/// }
+///
+/// # fn main() {
+/// if let TypeInfo::Struct(info) = Foo::type_info() {
+///     let field = info.field("bar").unwrap();
+///     assert!(field.is::<usize>());
+/// }
+/// # }
+/// ```
+pub trait Typed: Reflect {
+    /// Returns the compile-time [info] for the underlying type.
+    ///
+    /// [info]: TypeInfo
+    fn type_info() -> TypeInfo;
+}
+
+/// Compile-time type information for various reflected types.
+///
+/// Generally, for any given type, this value can be retrieved one of two ways:
+///
+/// 1. [`Typed::type_info`]
+/// 2. [`TypeRegistration::type_info`] (or [`TypeRegistry::get_type_info`])
+///
+/// Each returns a structure describing the type, the names and types of its
+/// fields, or the types of its elements.
+///
+/// [`TypeRegistration::type_info`]: crate::TypeRegistration::type_info
+/// [`TypeRegistry::get_type_info`]: crate::TypeRegistry::get_type_info
+#[derive(Debug, Clone)]
+pub enum TypeInfo {
+    Struct(StructInfo),
+    TupleStruct(TupleStructInfo),
+    Tuple(TupleInfo),
+    List(ListInfo),
+    Array(ArrayInfo),
+    Map(MapInfo),
+    Enum(EnumInfo),
+    Value(ValueInfo),
+}
+
+impl TypeInfo {
+    /// The [`TypeId`] of the underlying type.
+    pub fn type_id(&self) -> TypeId {
+        match self {
+            Self::Struct(info) => info.type_id(),
+            Self::TupleStruct(info) => info.type_id(),
+            Self::Tuple(info) => info.type_id(),
+            Self::List(info) => info.type_id(),
+            Self::Array(info) => info.type_id(),
+            Self::Map(info) => info.type_id(),
+            Self::Enum(info) => info.type_id(),
+            Self::Value(info) => info.type_id(),
+        }
+    }
+
+    /// The [name] of the underlying type.
+    ///
+    /// [name]: std::any::type_name
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Struct(info) => info.type_name(),
+            Self::TupleStruct(info) => info.type_name(),
+            Self::Tuple(info) => info.type_name(),
+            Self::List(info) => info.type_name(),
+            Self::Array(info) => info.type_name(),
+            Self::Map(info) => info.type_name(),
+            Self::Enum(info) => info.type_name(),
+            Self::Value(info) => info.type_name(),
+        }
+    }
+
+    /// Check if the given type matches the underlying type.
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id()
+    }
+}
+
+/// A container for compile-time info related to general value types, including primitives.
+///
+/// This typically represents a type which cannot be broken down any further. This is often
+/// due to technical reasons (or by definition), but it can also be a purposeful choice.
+///
+/// For example, [`i32`] cannot be broken down any further, so it is represented by a [`ValueInfo`].
+/// And while [`String`] itself is a struct, its fields are private, so we don't really treat
+/// it _as_ a struct. It therefore makes more sense to represent it as a [`ValueInfo`].
+#[derive(Debug, Clone)]
+pub struct ValueInfo {
+    type_name: &'static str,
+    type_id: TypeId,
+}
+
+impl ValueInfo {
+    pub fn new<T: Reflect + ?Sized>() -> Self {
+        Self {
+            type_name: std::any::type_name::<T>(),
+            type_id: TypeId::of::<T>(),
+        }
+    }
+
+    /// The [type name] of the value.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The [`TypeId`] of the value.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Check if the given type matches the value type.
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Reflect;
+use crate::{Reflect, TypeInfo, Typed};
 use bevy_utils::{HashMap, HashSet};
 use downcast_rs::{impl_downcast, Downcast};
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
@@ -12,6 +12,8 @@ pub struct TypeRegistry {
     short_name_to_id: HashMap<String, TypeId>,
     full_name_to_id: HashMap<String, TypeId>,
     ambiguous_names: HashSet<String>,
+    numeric_ids: HashMap<TypeId, u32>,
+    numeric_id_to_type: Vec<TypeId>,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -59,6 +61,11 @@ impl TypeRegistry {
         }
         self.full_name_to_id
             .insert(registration.name.to_string(), registration.type_id);
+        if !self.numeric_ids.contains_key(&registration.type_id) {
+            self.numeric_ids
+                .insert(registration.type_id, self.numeric_id_to_type.len() as u32);
+            self.numeric_id_to_type.push(registration.type_id);
+        }
         self.registrations
             .insert(registration.type_id, registration);
     }
@@ -129,6 +136,28 @@ impl TypeRegistry {
             .and_then(|id| self.registrations.get_mut(id))
     }
 
+    /// Returns the numeric ID assigned to the type with the given [`TypeId`].
+    ///
+    /// IDs are assigned in registration order, so they only match between
+    /// registries that register the same types in the same order.
+    ///
+    /// If the specified type has not been registered, returns `None`.
+    ///
+    /// [`TypeId`]: std::any::TypeId
+    pub fn get_numeric_id(&self, type_id: TypeId) -> Option<u32> {
+        self.numeric_ids.get(&type_id).copied()
+    }
+
+    /// Returns a reference to the [`TypeRegistration`] of the type with the
+    /// given numeric ID.
+    ///
+    /// If no type has been assigned the ID, returns `None`.
+    pub fn get_with_numeric_id(&self, id: u32) -> Option<&TypeRegistration> {
+        self.numeric_id_to_type
+            .get(id as usize)
+            .and_then(|type_id| self.get(*type_id))
+    }
+
     /// Returns a reference to the [`TypeData`] of type `T` associated with the given `TypeId`.
     ///
     /// The returned value may be used to downcast [`Reflect`] trait objects to
@@ -152,6 +181,22 @@ impl TypeRegistry {
             .and_then(|registration| registration.data_mut::<T>())
     }
 
+    /// Returns the [`TypeInfo`] associated with the given `TypeId`.
+    ///
+    /// If the specified type has not been registered, returns `None`.
+    pub fn get_type_info(&self, type_id: TypeId) -> Option<&TypeInfo> {
+        self.get(type_id)
+            .map(|registration| registration.type_info())
+    }
+
+    /// Returns the [`TypeInfo`] of the type with the given name.
+    ///
+    /// If no type with the given name has been registered, returns `None`.
+    pub fn get_type_info_with_name(&self, type_name: &str) -> Option<&TypeInfo> {
+        self.get_with_name(type_name)
+            .map(|registration| registration.type_info())
+    }
+
     /// Returns an iterator over the [`TypeRegistration`]s of the registered
     /// types.
     pub fn iter(&self) -> impl Iterator<Item = &TypeRegistration> {
@@ -179,7 +224,7 @@ impl TypeRegistryArc {
 
 /// A record of data about a type.
 ///
-/// This contains the [`TypeId`], [name], and [short name] of the type.
+/// This contains the [`TypeId`], [name], [short name] and [`TypeInfo`] of the type.
 ///
 /// For each trait specified by the [`#[reflect(_)]`][0] attribute of
 /// [`#[derive(Reflect)]`][1] on the registered type, this record also contains
@@ -196,6 +241,7 @@ pub struct TypeRegistration {
     short_name: String,
     name: &'static str,
     data: HashMap<TypeId, Box<dyn TypeData>>,
+    type_info: TypeInfo,
 }
 
 impl TypeRegistration {
@@ -235,7 +281,7 @@ impl TypeRegistration {
     }
 
     /// Creates type registration information for `T`.
-    pub fn of<T: Reflect>() -> Self {
+    pub fn of<T: Reflect + Typed>() -> Self {
         let ty = TypeId::of::<T>();
         let type_name = std::any::type_name::<T>();
         Self {
@@ -243,9 +289,15 @@ impl TypeRegistration {
             data: HashMap::default(),
             name: type_name,
             short_name: Self::get_short_name(type_name),
+            type_info: T::type_info(),
         }
     }
 
+    /// Returns the compile-time [`TypeInfo`] of the type.
+    pub fn type_info(&self) -> &TypeInfo {
+        &self.type_info
+    }
+
     /// Returns the [short name] of the type.
     ///
     /// [short name]: TypeRegistration::get_short_name
@@ -314,6 +366,7 @@ impl Clone for TypeRegistration {
             name: self.name,
             short_name: self.short_name.clone(),
             type_id: self.type_id,
+            type_info: self.type_info.clone(),
         }
     }
 }
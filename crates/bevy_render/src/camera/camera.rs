@@ -39,6 +39,7 @@ pub struct Camera {
 }
 
 #[derive(Debug, Clone, Reflect, PartialEq, Eq, Hash)]
+#[reflect_value(PartialEq, Hash)]
 pub enum RenderTarget {
     /// Window to which the camera's view is rendered.
     Window(WindowId),
@@ -218,9 +218,12 @@ impl ShaderReflection {
 pub struct ShaderLoader;
 
 impl AssetLoader for ShaderLoader {
+    type Settings = ();
+
     fn load<'a>(
         &'a self,
         bytes: &'a [u8],
+        _settings: &'a Self::Settings,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
         Box::pin(async move {
@@ -9,9 +9,12 @@ use wgpu::{Extent3d, TextureDimension, TextureFormat};
 pub struct HdrTextureLoader;
 
 impl AssetLoader for HdrTextureLoader {
+    type Settings = ();
+
     fn load<'a>(
         &'a self,
         bytes: &'a [u8],
+        _settings: &'a Self::Settings,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
@@ -1,7 +1,9 @@
 use anyhow::Result;
 use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
 use bevy_ecs::prelude::{FromWorld, World};
+use bevy_reflect::Reflect;
 use bevy_utils::BoxedFuture;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::{
@@ -17,6 +19,47 @@ pub struct ImageTextureLoader {
     supported_compressed_formats: CompressedImageFormats,
 }
 
+/// The settings of the [`ImageTextureLoader`], usually read from the `.meta` file of an image.
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImageTextureLoaderSettings {
+    /// Whether the image stores sRGB colors, rather than linear data such as normals.
+    pub is_srgb: bool,
+    pub sampler: ImageSampler,
+}
+
+impl Default for ImageTextureLoaderSettings {
+    fn default() -> Self {
+        Self {
+            is_srgb: true,
+            sampler: ImageSampler::Default,
+        }
+    }
+}
+
+/// The filtering used to sample a loaded image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub enum ImageSampler {
+    /// Keeps the sampler of the loaded image.
+    Default,
+    Linear,
+    Nearest,
+}
+
+impl ImageSampler {
+    /// Sets the filters of `descriptor` to the ones of this sampler.
+    pub fn apply(&self, descriptor: &mut wgpu::SamplerDescriptor<'static>) {
+        let filter = match self {
+            ImageSampler::Default => return,
+            ImageSampler::Linear => wgpu::FilterMode::Linear,
+            ImageSampler::Nearest => wgpu::FilterMode::Nearest,
+        };
+        descriptor.mag_filter = filter;
+        descriptor.min_filter = filter;
+        descriptor.mipmap_filter = filter;
+    }
+}
+
 const FILE_EXTENSIONS: &[&str] = &[
     #[cfg(feature = "basis-universal")]
     "basis",
@@ -37,25 +80,29 @@ const FILE_EXTENSIONS: &[&str] = &[
 ];
 
 impl AssetLoader for ImageTextureLoader {
+    type Settings = ImageTextureLoaderSettings;
+
     fn load<'a>(
         &'a self,
         bytes: &'a [u8],
+        settings: &'a Self::Settings,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
             // use the file extension for the image type
             let ext = load_context.path().extension().unwrap().to_str().unwrap();
 
-            let dyn_img = Image::from_buffer(
+            let mut dyn_img = Image::from_buffer(
                 bytes,
                 ImageType::Extension(ext),
                 self.supported_compressed_formats,
-                true,
+                settings.is_srgb,
             )
             .map_err(|err| FileTextureError {
                 error: err,
                 path: format!("{}", load_context.path().display()),
             })?;
+            settings.sampler.apply(&mut dyn_img.sampler_descriptor);
 
             load_context.set_default_asset(LoadedAsset::new(dyn_img));
             Ok(())
@@ -100,6 +147,24 @@ impl std::fmt::Display for FileTextureError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn image_sampler() {
+        let mut descriptor = wgpu::SamplerDescriptor {
+            mag_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        };
+        ImageSampler::Default.apply(&mut descriptor);
+        assert_eq!(descriptor.mag_filter, wgpu::FilterMode::Linear);
+        assert_eq!(descriptor.min_filter, wgpu::FilterMode::Nearest);
+
+        ImageSampler::Linear.apply(&mut descriptor);
+        assert_eq!(descriptor.min_filter, wgpu::FilterMode::Linear);
+        assert_eq!(descriptor.mipmap_filter, wgpu::FilterMode::Linear);
+
+        ImageSampler::Nearest.apply(&mut descriptor);
+        assert_eq!(descriptor.mag_filter, wgpu::FilterMode::Nearest);
+    }
+
     #[test]
     fn test_supported_file_extensions() {
         for ext in FILE_EXTENSIONS {
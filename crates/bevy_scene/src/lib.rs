@@ -18,8 +18,8 @@ pub mod prelude {
     };
 }
 
-use bevy_app::prelude::*;
-use bevy_asset::AddAsset;
+use bevy_app::{prelude::*, PluginDependency};
+use bevy_asset::{AddAsset, AssetPlugin};
 use bevy_ecs::{schedule::ExclusiveSystemDescriptorCoercion, system::IntoExclusiveSystem};
 
 #[derive(Default)]
@@ -36,4 +36,8 @@ impl Plugin for ScenePlugin {
                 scene_spawner_system.exclusive_system().at_end(),
             );
     }
+
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        vec![PluginDependency::of::<AssetPlugin>()]
+    }
 }
@@ -21,9 +21,12 @@ impl FromWorld for SceneLoader {
 }
 
 impl AssetLoader for SceneLoader {
+    type Settings = ();
+
     fn load<'a>(
         &'a self,
         bytes: &'a [u8],
+        _settings: &'a Self::Settings,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
@@ -249,3 +249,64 @@ impl<'a, 'de> Visitor<'de> for ComponentSeqVisitor<'a> {
         Ok(dynamic_properties)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{serde::SceneDeserializer, DynamicScene};
+    use bevy_ecs::{entity::EntityMap, prelude::*, reflect::ReflectComponent};
+    use bevy_reflect::{Reflect, TypeRegistryArc};
+    use serde::de::DeserializeSeed;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health {
+        current: u32,
+        #[reflect(skip_serializing, default = "max_health")]
+        max: u32,
+    }
+
+    fn max_health() -> u32 {
+        100
+    }
+
+    #[test]
+    fn skipped_fields_round_trip() {
+        let registry = TypeRegistryArc::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Health>();
+            registry.register::<u32>();
+        }
+
+        let mut world = World::new();
+        world.insert_resource(registry.clone());
+        world.spawn().insert(Health { current: 7, max: 3 });
+
+        let scene = DynamicScene::from_world(&world, &registry);
+        let ron = scene.serialize_ron(&registry).unwrap();
+        assert!(ron.contains("current"));
+        assert!(!ron.contains("max"));
+
+        let mut deserializer = ron::de::Deserializer::from_str(&ron).unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        let mut world = World::new();
+        world.insert_resource(registry);
+        scene
+            .write_to_world(&mut world, &mut EntityMap::default())
+            .unwrap();
+
+        let healths = world.query::<&Health>().iter(&world).collect::<Vec<_>>();
+        assert_eq!(
+            healths,
+            vec![&Health {
+                current: 7,
+                max: 100
+            }]
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use event_listener::Event;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+#[derive(Debug, Default)]
+struct CancellationState {
+    cancelled: AtomicBool,
+    event: Event,
+}
+
+/// A token for cooperatively cancelling a long-running task.
+///
+/// Clones of a token share their state, so the owner of a task can keep one
+/// clone and move another into the task. The task is expected to check
+/// [`is_cancelled`](CancellationToken::is_cancelled) at convenient points, or to
+/// await [`cancelled`](CancellationToken::cancelled), and to stop early once it
+/// has been cancelled.
+///
+/// Unlike dropping a [`Task`](crate::Task), this lets the task clean up and
+/// return a partial result.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<CancellationState>);
+
+impl CancellationToken {
+    /// Creates a new token which hasn't been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the token and wakes up every task awaiting
+    /// [`cancelled`](CancellationToken::cancelled).
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::Release);
+        self.0.event.notify(usize::MAX);
+    }
+
+    /// Returns true if [`cancel`](CancellationToken::cancel) was called on this
+    /// token or any of its clones.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Waits until the token is cancelled.
+    pub async fn cancelled(&self) {
+        loop {
+            if self.is_cancelled() {
+                return;
+            }
+            let listener = self.0.event.listen();
+            // The token may have been cancelled before the listener was registered.
+            if self.is_cancelled() {
+                return;
+            }
+            listener.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future;
+
+    #[test]
+    fn cancel_is_shared_between_clones() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+        token.cancel();
+        assert!(clone.is_cancelled());
+        future::block_on(clone.cancelled());
+    }
+
+    #[test]
+    fn cancelled_wakes_up_waiting_thread() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        let waiter = std::thread::spawn(move || future::block_on(clone.cancelled()));
+        token.cancel();
+        waiter.join().unwrap();
+    }
+}
@@ -5,7 +5,16 @@ mod slice;
 pub use slice::{ParallelSlice, ParallelSliceMut};
 
 mod task;
-pub use task::Task;
+pub use task::{Task, TaskPriority};
+
+mod cancellation;
+pub use cancellation::CancellationToken;
+
+mod progress;
+pub use progress::TaskProgress;
+
+mod main_thread_executor;
+pub use main_thread_executor::MainThreadExecutor;
 
 #[cfg(not(target_arch = "wasm32"))]
 mod task_pool;
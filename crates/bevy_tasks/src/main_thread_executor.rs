@@ -0,0 +1,189 @@
+use std::{
+    future::Future,
+    mem::ManuallyDrop,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    thread::{self, ThreadId},
+};
+
+use async_task::Runnable;
+
+use crate::Task;
+
+#[derive(Debug)]
+struct MainThreadQueue {
+    thread: ThreadId,
+    sender: async_channel::Sender<Runnable>,
+    receiver: async_channel::Receiver<Runnable>,
+}
+
+/// An executor for tasks which have to run on the main thread, like tasks
+/// touching `!Send` platform handles.
+///
+/// The executor belongs to the thread it was created on, which has to call
+/// [`tick`](MainThreadExecutor::tick) regularly to run its tasks. Clones of the
+/// executor share its tasks, so any thread holding one can spawn tasks with
+/// [`spawn_on_main`](MainThreadExecutor::spawn_on_main).
+///
+/// In a Bevy app, the executor is inserted as a resource by `CorePlugin` and
+/// ticked at the start of every `App::update`.
+#[derive(Debug, Clone)]
+pub struct MainThreadExecutor(Arc<MainThreadQueue>);
+
+impl MainThreadExecutor {
+    /// Creates an executor belonging to the current thread.
+    pub fn new() -> Self {
+        let (sender, receiver) = async_channel::unbounded();
+        Self(Arc::new(MainThreadQueue {
+            thread: thread::current().id(),
+            sender,
+            receiver,
+        }))
+    }
+
+    /// Returns true if called from the thread the executor belongs to.
+    pub fn is_main_thread(&self) -> bool {
+        thread::current().id() == self.0.thread
+    }
+
+    /// Spawns a task on the thread the executor belongs to, from any thread.
+    ///
+    /// The future is created by calling `f` on that thread, so it doesn't need
+    /// to be `Send`. The returned [`Task`] can be awaited or polled from any
+    /// thread.
+    pub fn spawn_on_main<F, Fut, T>(&self, f: F) -> Task<T>
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = T> + 'static,
+        T: Send + 'static,
+    {
+        let sender = self.0.sender.clone();
+        let schedule = move |runnable| {
+            // The channel can't be closed while this sender exists.
+            let _ = sender.try_send(runnable);
+        };
+        let future = ThreadBound {
+            thread: self.0.thread,
+            future: ManuallyDrop::new(async move { f().await }),
+        };
+
+        // SAFETY: A `!Send` future must only be polled and dropped on the thread it was spawned on.
+        // `ThreadBound` stands in for that thread: the runnable is only run by `tick`, which
+        // asserts that it runs on `thread`, and `ThreadBound` leaks the future instead of dropping
+        // it on another thread.
+        let (runnable, task) = unsafe { async_task::spawn_unchecked(future, schedule) };
+        runnable.schedule();
+        Task::new(task)
+    }
+
+    /// Runs the tasks which are ready to make progress.
+    ///
+    /// Tasks woken up while ticking run during the next tick.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not called from the thread the executor belongs to.
+    pub fn tick(&self) {
+        assert!(
+            self.is_main_thread(),
+            "MainThreadExecutor::tick must be called from the thread that created the executor"
+        );
+        for _ in 0..self.0.receiver.len() {
+            match self.0.receiver.try_recv() {
+                Ok(runnable) => {
+                    runnable.run();
+                }
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl Default for MainThreadExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A future which may only be polled and dropped on the given thread.
+struct ThreadBound<F> {
+    thread: ThreadId,
+    future: ManuallyDrop<F>,
+}
+
+impl<F: Future> Future for ThreadBound<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        debug_assert_eq!(thread::current().id(), self.thread);
+        // SAFETY: The future is never moved out of `self`.
+        unsafe { self.map_unchecked_mut(|this| &mut *this.future) }.poll(cx)
+    }
+}
+
+impl<F> Drop for ThreadBound<F> {
+    fn drop(&mut self) {
+        if thread::current().id() == self.thread {
+            // SAFETY: The future is never used again.
+            unsafe { ManuallyDrop::drop(&mut self.future) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future;
+    use std::{cell::Cell, rc::Rc};
+
+    #[test]
+    fn spawn_on_main_from_other_thread() {
+        let executor = MainThreadExecutor::new();
+        let main_thread = thread::current().id();
+
+        let spawner = executor.clone();
+        let mut task = thread::spawn(move || {
+            spawner.spawn_on_main(|| async {
+                // `Rc` isn't `Send`, so this future has to stay on the main thread.
+                let value = Rc::new(Cell::new(20));
+                future::yield_now().await;
+                value.set(value.get() + 1);
+                (value.get() * 2, thread::current().id())
+            })
+        })
+        .join()
+        .unwrap();
+
+        assert!(!task.is_finished());
+        executor.tick();
+        assert!(!task.is_finished());
+        executor.tick();
+        assert_eq!(task.poll_once(), Some((42, main_thread)));
+    }
+
+    #[test]
+    fn dropped_task_is_cancelled() {
+        let executor = MainThreadExecutor::new();
+        let ran = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let task = {
+            let ran = ran.clone();
+            executor.spawn_on_main(move || async move {
+                ran.store(true, std::sync::atomic::Ordering::Relaxed);
+            })
+        };
+        drop(task);
+        executor.tick();
+        assert!(!ran.load(std::sync::atomic::Ordering::Relaxed));
+    }
+
+    #[test]
+    #[should_panic]
+    fn tick_from_other_thread_panics() {
+        let executor = MainThreadExecutor::new();
+        let result = thread::spawn(move || executor.tick()).join();
+        if let Err(panic) = result {
+            std::panic::resume_unwind(panic);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+#[derive(Debug, Default)]
+struct ProgressState {
+    completed: AtomicU64,
+    total: AtomicU64,
+}
+
+/// Reports how much of its work a long-running task has completed.
+///
+/// Clones of a [`TaskProgress`] share their state, so a task can advance its
+/// clone while another thread reads the progress, e.g. to display a progress
+/// bar.
+#[derive(Debug, Clone, Default)]
+pub struct TaskProgress(Arc<ProgressState>);
+
+impl TaskProgress {
+    /// Creates a new progress with nothing completed out of `total` steps.
+    pub fn new(total: u64) -> Self {
+        let progress = Self::default();
+        progress.set_total(total);
+        progress
+    }
+
+    /// Marks `steps` more steps as completed.
+    pub fn advance(&self, steps: u64) {
+        self.0.completed.fetch_add(steps, Ordering::Relaxed);
+    }
+
+    /// Sets the number of completed steps.
+    pub fn set_completed(&self, completed: u64) {
+        self.0.completed.store(completed, Ordering::Relaxed);
+    }
+
+    /// Sets the total number of steps, for tasks which discover their work as
+    /// they go.
+    pub fn set_total(&self, total: u64) {
+        self.0.total.store(total, Ordering::Relaxed);
+    }
+
+    /// Returns the number of completed steps.
+    pub fn completed(&self) -> u64 {
+        self.0.completed.load(Ordering::Relaxed)
+    }
+
+    /// Returns the total number of steps.
+    pub fn total(&self) -> u64 {
+        self.0.total.load(Ordering::Relaxed)
+    }
+
+    /// Returns the completed fraction of the work, between `0.0` and `1.0`.
+    ///
+    /// Returns `0.0` if the total is zero.
+    pub fn fraction(&self) -> f32 {
+        let total = self.total();
+        if total == 0 {
+            0.0
+        } else {
+            (self.completed() as f64 / total as f64).min(1.0) as f32
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TaskProgress;
+
+    #[test]
+    fn progress_is_shared_between_clones() {
+        let progress = TaskProgress::new(4);
+        let clone = progress.clone();
+        assert_eq!(progress.fraction(), 0.0);
+
+        clone.advance(1);
+        assert_eq!(progress.completed(), 1);
+        assert_eq!(progress.fraction(), 0.25);
+
+        clone.set_total(2);
+        clone.advance(2);
+        assert_eq!(progress.fraction(), 1.0);
+
+        assert_eq!(TaskProgress::default().fraction(), 0.0);
+    }
+}
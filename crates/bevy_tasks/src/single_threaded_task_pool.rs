@@ -4,6 +4,8 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+use crate::TaskPriority;
+
 /// Used to create a TaskPool
 #[derive(Debug, Default, Clone)]
 pub struct TaskPoolBuilder {}
@@ -106,6 +108,19 @@ impl TaskPool {
         FakeTask
     }
 
+    /// Spawns a static future onto the JS event loop. Priorities are ignored on the single
+    /// threaded task pool, so this is exactly the same as [`TaskPool::spawn`].
+    pub fn spawn_with_priority<T>(
+        &self,
+        _priority: TaskPriority,
+        future: impl Future<Output = T> + 'static,
+    ) -> FakeTask
+    where
+        T: 'static,
+    {
+        self.spawn(future)
+    }
+
     /// Spawns a static future on the JS event loop. This is exactly the same as [`TaskSpool::spawn`].
     pub fn spawn_local<T>(&self, future: impl Future<Output = T> + 'static) -> FakeTask
     where
@@ -145,7 +160,7 @@ impl<'scope, T: Send + 'scope> Scope<'scope, T> {
         self.spawn_local(f);
     }
 
-    /// Spawns a scoped future onto the thread-local executor. The scope *must* outlive
+    /// Spawns a scoped future onto the thread the scope is run on. The scope *must* outlive
     /// the provided future. The results of the future will be returned as a part of
     /// [`TaskPool::scope`]'s return value.
     ///
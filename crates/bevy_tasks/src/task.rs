@@ -1,9 +1,31 @@
+use futures_lite::future;
 use std::{
     future::Future,
     pin::Pin,
     task::{Context, Poll},
 };
 
+/// The priority of a task spawned on a [`TaskPool`](crate::TaskPool).
+///
+/// Threads of the pool run higher priority tasks first whenever several tasks
+/// are ready to make progress. Tasks aren't preempted, so a running low priority
+/// task still runs until it yields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TaskPriority {
+    /// For background work which should only run when nothing else is ready.
+    Low,
+    /// The priority of tasks spawned without an explicit priority.
+    Normal,
+    /// For work which should run before any other ready task.
+    High,
+}
+
+impl Default for TaskPriority {
+    fn default() -> Self {
+        TaskPriority::Normal
+    }
+}
+
 /// Wraps `async_executor::Task`, a spawned future.
 ///
 /// Tasks are also futures themselves and yield the output of the spawned future.
@@ -40,6 +62,26 @@ impl<T> Task<T> {
     pub async fn cancel(self) -> Option<T> {
         self.0.cancel().await
     }
+
+    /// Returns true if the task has completed or was canceled, in which case
+    /// [`poll_once`](Task::poll_once) won't return [`None`].
+    pub fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+
+    /// Polls the task once without blocking, returning its output if it has
+    /// completed.
+    ///
+    /// This allows checking on a task every frame from a system, without
+    /// awaiting it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the output was already returned, or if the task panicked or
+    /// was canceled.
+    pub fn poll_once(&mut self) -> Option<T> {
+        future::block_on(future::poll_once(&mut self.0))
+    }
 }
 
 impl<T> Future for Task<T> {
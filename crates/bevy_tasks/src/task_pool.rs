@@ -8,7 +8,7 @@ use std::{
 
 use futures_lite::{future, pin};
 
-use crate::Task;
+use crate::{Task, TaskPriority};
 
 /// Used to create a [`TaskPool`]
 #[derive(Debug, Default, Clone)]
@@ -60,6 +60,60 @@ impl TaskPoolBuilder {
     }
 }
 
+/// One executor per [`TaskPriority`].
+#[derive(Debug)]
+struct Executors {
+    high: async_executor::Executor<'static>,
+    normal: async_executor::Executor<'static>,
+    low: async_executor::Executor<'static>,
+}
+
+impl Executors {
+    fn new() -> Self {
+        Self {
+            high: async_executor::Executor::new(),
+            normal: async_executor::Executor::new(),
+            low: async_executor::Executor::new(),
+        }
+    }
+
+    fn get(&self, priority: TaskPriority) -> &async_executor::Executor<'static> {
+        match priority {
+            TaskPriority::High => &self.high,
+            TaskPriority::Normal => &self.normal,
+            TaskPriority::Low => &self.low,
+        }
+    }
+
+    /// Runs a single ready task, if any, preferring higher priorities.
+    fn try_tick(&self) -> bool {
+        self.high.try_tick() || self.normal.try_tick() || self.low.try_tick()
+    }
+
+    /// Waits for a task to be ready and runs it, preferring higher priorities.
+    async fn tick(&self) {
+        future::or(
+            self.high.tick(),
+            future::or(self.normal.tick(), self.low.tick()),
+        )
+        .await;
+    }
+
+    /// Runs tasks until `shutdown` completes.
+    async fn run<T>(&self, shutdown: impl Future<Output = T>) -> T {
+        let run_forever = async {
+            loop {
+                for _ in 0..200 {
+                    self.tick().await;
+                }
+                // Give `shutdown` a chance to complete while tasks keep being ready.
+                future::yield_now().await;
+            }
+        };
+        future::or(shutdown, run_forever).await
+    }
+}
+
 #[derive(Debug)]
 struct TaskPoolInner {
     threads: Vec<JoinHandle<()>>,
@@ -84,12 +138,12 @@ impl Drop for TaskPoolInner {
 /// the pool on threads owned by the pool.
 #[derive(Debug, Clone)]
 pub struct TaskPool {
-    /// The executor for the pool
+    /// The executors for the pool, one per [`TaskPriority`]
     ///
-    /// This has to be separate from TaskPoolInner because we have to create an Arc<Executor> to
+    /// This has to be separate from TaskPoolInner because we have to create an Arc<Executors> to
     /// pass into the worker threads, and we must create the worker threads before we can create
     /// the Vec<Task<T>> contained within TaskPoolInner
-    executor: Arc<async_executor::Executor<'static>>,
+    executors: Arc<Executors>,
 
     /// Inner state of the pool
     inner: Arc<TaskPoolInner>,
@@ -112,13 +166,13 @@ impl TaskPool {
     ) -> Self {
         let (shutdown_tx, shutdown_rx) = async_channel::unbounded::<()>();
 
-        let executor = Arc::new(async_executor::Executor::new());
+        let executors = Arc::new(Executors::new());
 
         let num_threads = num_threads.unwrap_or_else(num_cpus::get);
 
         let threads = (0..num_threads)
             .map(|i| {
-                let ex = Arc::clone(&executor);
+                let ex = Arc::clone(&executors);
                 let shutdown_rx = shutdown_rx.clone();
 
                 // miri does not support setting thread names
@@ -154,7 +208,7 @@ impl TaskPool {
             .collect();
 
         Self {
-            executor,
+            executors,
             inner: Arc::new(TaskPoolInner {
                 threads,
                 shutdown_tx,
@@ -182,7 +236,7 @@ impl TaskPool {
             // before this function returns. However, rust has no way of knowing
             // this so we must convert to 'static here to appease the compiler as it is unable to
             // validate safety.
-            let executor: &async_executor::Executor = &*self.executor;
+            let executor: &async_executor::Executor = self.executors.get(TaskPriority::Normal);
             let executor: &'scope async_executor::Executor = unsafe { mem::transmute(executor) };
             let local_executor: &'scope async_executor::LocalExecutor =
                 unsafe { mem::transmute(local_executor) };
@@ -230,7 +284,7 @@ impl TaskPool {
                         break result;
                     };
 
-                    self.executor.try_tick();
+                    self.executors.try_tick();
                     local_executor.try_tick();
                 }
             }
@@ -241,12 +295,28 @@ impl TaskPool {
     /// cancelled and "detached" allowing it to continue running without having to be polled by the
     /// end-user.
     ///
-    /// If the provided future is non-`Send`, [`TaskPool::spawn_local`] should be used instead.
+    /// The task is spawned with [`TaskPriority::Normal`]. If the provided future is non-`Send`,
+    /// [`TaskPool::spawn_local`] should be used instead.
     pub fn spawn<T>(&self, future: impl Future<Output = T> + Send + 'static) -> Task<T>
     where
         T: Send + 'static,
     {
-        Task::new(self.executor.spawn(future))
+        self.spawn_with_priority(TaskPriority::Normal, future)
+    }
+
+    /// Spawns a static future onto the thread pool with the given [`TaskPriority`]. Threads of
+    /// the pool run ready tasks of higher priorities first.
+    ///
+    /// See [`TaskPool::spawn`] for more details.
+    pub fn spawn_with_priority<T>(
+        &self,
+        priority: TaskPriority,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        Task::new(self.executors.get(priority).spawn(future))
     }
 
     /// Spawns a static future on the thread-local async executor for the current thread. The task
@@ -283,8 +353,8 @@ impl<'scope, T: Send + 'scope> Scope<'scope, T> {
     /// the provided future. The results of the future will be returned as a part of
     /// [`TaskPool::scope`]'s return value.
     ///
-    /// If the provided future is non-`Send`, [`Scope::spawn_local`] should be used
-    /// instead.
+    /// If the provided future is non-`Send`, or has to run on the thread calling
+    /// [`TaskPool::scope`], [`Scope::spawn_local`] should be used instead.
     ///
     /// For more information, see [`TaskPool::scope`].
     pub fn spawn<Fut: Future<Output = T> + 'scope + Send>(&mut self, f: Fut) {
@@ -292,10 +362,11 @@ impl<'scope, T: Send + 'scope> Scope<'scope, T> {
         self.spawned.push(task);
     }
 
-    /// Spawns a scoped future onto the thread-local executor. The scope *must* outlive
-    /// the provided future. The results of the future will be returned as a part of
-    /// [`TaskPool::scope`]'s return value.  Users should generally prefer to use
-    /// [`Scope::spawn`] instead, unless the provided future is not `Send`.
+    /// Spawns a scoped future onto the thread the scope is run on, i.e. the thread that called
+    /// [`TaskPool::scope`]. The scope *must* outlive the provided future. The results of the
+    /// future will be returned as a part of [`TaskPool::scope`]'s return value.  Users should
+    /// generally prefer to use [`Scope::spawn`] instead, unless the provided future is not `Send`
+    /// or touches data tied to the calling thread.
     ///
     /// For more information, see [`TaskPool::scope`].
     pub fn spawn_local<Fut: Future<Output = T> + 'scope>(&mut self, f: Fut) {
@@ -308,9 +379,10 @@ impl<'scope, T: Send + 'scope> Scope<'scope, T> {
 #[allow(clippy::blacklisted_name)]
 mod tests {
     use super::*;
+    use crate::{CancellationToken, TaskProgress};
     use std::sync::{
         atomic::{AtomicBool, AtomicI32, Ordering},
-        Barrier,
+        mpsc, Barrier, Mutex,
     };
 
     #[test]
@@ -389,6 +461,95 @@ mod tests {
         assert_eq!(non_local_count.load(Ordering::Relaxed), 50);
     }
 
+    #[test]
+    fn test_priorities() {
+        let pool = TaskPoolBuilder::new().num_threads(1).build();
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        // Keep the only thread busy until every other task has been spawned.
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let blocker = pool.spawn(async move {
+            started_tx.send(()).unwrap();
+            release_rx.recv().unwrap();
+        });
+        started_rx.recv().unwrap();
+
+        let tasks = [
+            TaskPriority::Low,
+            TaskPriority::Normal,
+            TaskPriority::High,
+            TaskPriority::Low,
+            TaskPriority::High,
+        ]
+        .into_iter()
+        .map(|priority| {
+            let order = order.clone();
+            pool.spawn_with_priority(priority, async move {
+                order.lock().unwrap().push(priority);
+            })
+        })
+        .collect::<Vec<_>>();
+
+        release_tx.send(()).unwrap();
+        future::block_on(blocker);
+        for task in tasks {
+            future::block_on(task);
+        }
+
+        assert_eq!(
+            *order.lock().unwrap(),
+            vec![
+                TaskPriority::High,
+                TaskPriority::High,
+                TaskPriority::Normal,
+                TaskPriority::Low,
+                TaskPriority::Low,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_poll_once() {
+        let pool = TaskPool::new();
+        let (tx, rx) = async_channel::bounded(1);
+        let mut task = pool.spawn(async move { rx.recv().await.unwrap() * 2 });
+
+        assert!(!task.is_finished());
+        assert_eq!(task.poll_once(), None);
+
+        future::block_on(tx.send(21)).unwrap();
+        while !task.is_finished() {
+            std::thread::yield_now();
+        }
+        assert_eq!(task.poll_once(), Some(42));
+    }
+
+    #[test]
+    fn test_cancellation_token() {
+        let pool = TaskPool::new();
+        let token = CancellationToken::new();
+        let progress = TaskProgress::new(u64::MAX);
+
+        let task = {
+            let token = token.clone();
+            let progress = progress.clone();
+            pool.spawn(async move {
+                while !token.is_cancelled() {
+                    progress.advance(1);
+                    future::yield_now().await;
+                }
+                progress.completed()
+            })
+        };
+
+        while progress.completed() == 0 {
+            std::thread::yield_now();
+        }
+        token.cancel();
+        assert!(future::block_on(task) > 0);
+    }
+
     #[test]
     fn test_thread_locality() {
         let pool = Arc::new(TaskPool::new());
@@ -7,9 +7,12 @@ use bevy_utils::BoxedFuture;
 pub struct FontLoader;
 
 impl AssetLoader for FontLoader {
+    type Settings = ();
+
     fn load<'a>(
         &'a self,
         bytes: &'a [u8],
+        _settings: &'a Self::Settings,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
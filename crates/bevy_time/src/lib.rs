@@ -3,20 +3,23 @@ mod stopwatch;
 #[allow(clippy::module_inception)]
 mod time;
 mod timer;
+mod timers;
 
 pub use fixed_timestep::*;
 pub use stopwatch::*;
 pub use time::*;
 pub use timer::*;
+pub use timers::*;
 
 pub mod prelude {
     //! The Bevy Time Prelude.
     #[doc(hidden)]
-    pub use crate::{Time, Timer};
+    pub use crate::{Time, TimeUpdateStrategy, Timer, TimerFinished, TimerMode, Timers};
 }
 
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
+use bevy_utils::{Duration, Instant};
 
 /// Adds time functionality to Apps.
 #[derive(Default)]
@@ -30,17 +33,136 @@ pub struct TimeSystem;
 impl Plugin for TimePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Time>()
+            .init_resource::<TimeUpdateStrategy>()
             .init_resource::<FixedTimesteps>()
             .register_type::<Timer>()
+            .register_type::<TimerMode>()
+            .register_type::<Timers>()
+            .add_event::<TimerFinished>()
             // time system is added as an "exclusive system" to ensure it runs before other systems
             // in CoreStage::First
             .add_system_to_stage(
                 CoreStage::First,
                 time_system.exclusive_system().at_start().label(TimeSystem),
-            );
+            )
+            .add_system_to_stage(CoreStage::First, tick_timers.after(TimeSystem));
     }
 }
 
-fn time_system(mut time: ResMut<Time>) {
-    time.update();
+/// Configures how the [`Time`] resource is advanced by the [`TimePlugin`] each update.
+///
+/// By default, [`Time`] is updated from the system clock. The manual strategies make the
+/// progression of time deterministic, which is mostly useful for tests and headless runs where
+/// each call to [`App::update`] should advance time by a known amount.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_time::prelude::*;
+/// # use bevy_utils::Duration;
+/// let mut app = App::new();
+/// app.add_plugin(bevy_time::TimePlugin)
+///     .insert_resource(TimeUpdateStrategy::from_framerate(60.0));
+///
+/// // Run one second worth of frames at 60 fps.
+/// app.update_frames(61);
+///
+/// let time = app.world.resource::<Time>();
+/// assert_eq!(time.delta(), Duration::from_secs_f64(1.0 / 60.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUpdateStrategy {
+    /// [`Time`] is updated using [`Instant::now`] every update.
+    Automatic,
+    /// [`Time`] is updated to the given [`Instant`] every update.
+    ///
+    /// Change the value of this resource to move time forward.
+    ManualInstant(Instant),
+    /// [`Time`] is advanced by the given [`Duration`] every update, starting from
+    /// [`Time::startup`].
+    ManualDuration(Duration),
+}
+
+impl Default for TimeUpdateStrategy {
+    fn default() -> Self {
+        TimeUpdateStrategy::Automatic
+    }
+}
+
+impl TimeUpdateStrategy {
+    /// Creates a [`TimeUpdateStrategy::ManualDuration`] that advances [`Time`] by exactly one
+    /// frame at the given number of frames per second on every update.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frames_per_second` is not strictly positive and finite.
+    pub fn from_framerate(frames_per_second: f64) -> Self {
+        assert!(
+            frames_per_second > 0.0 && frames_per_second.is_finite(),
+            "the framerate must be strictly positive and finite, got {}",
+            frames_per_second
+        );
+        TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(1.0 / frames_per_second))
+    }
+}
+
+fn time_system(mut time: ResMut<Time>, update_strategy: Res<TimeUpdateStrategy>) {
+    match *update_strategy {
+        TimeUpdateStrategy::Automatic => time.update(),
+        TimeUpdateStrategy::ManualInstant(instant) => time.update_with_instant(instant),
+        TimeUpdateStrategy::ManualDuration(duration) => {
+            let last_update = time.last_update().unwrap_or_else(|| time.startup());
+            time.update_with_instant(last_update + duration);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Time, TimePlugin, TimeUpdateStrategy};
+    use bevy_app::App;
+    use bevy_utils::Duration;
+
+    #[test]
+    fn manual_duration_strategy() {
+        let mut app = App::new();
+        app.add_plugin(TimePlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                10,
+            )));
+
+        // The first update has no previous update to compute a delta from.
+        app.update();
+        let time = app.world.resource::<Time>();
+        assert_eq!(time.delta(), Duration::ZERO);
+        assert_eq!(time.time_since_startup(), Duration::from_millis(10));
+
+        app.update_frames(3);
+        let time = app.world.resource::<Time>();
+        assert_eq!(time.delta(), Duration::from_millis(10));
+        assert_eq!(time.time_since_startup(), Duration::from_millis(40));
+    }
+
+    #[test]
+    fn manual_instant_strategy() {
+        let mut app = App::new();
+        app.add_plugin(TimePlugin);
+        let startup = app.world.resource::<Time>().startup();
+
+        app.insert_resource(TimeUpdateStrategy::ManualInstant(startup));
+        app.update();
+        let target = startup + Duration::from_secs(2);
+        app.insert_resource(TimeUpdateStrategy::ManualInstant(target));
+        app.update();
+
+        let time = app.world.resource::<Time>();
+        assert_eq!(time.last_update(), Some(target));
+        assert_eq!(time.delta(), Duration::from_secs(2));
+
+        // Updating again without moving the instant forward leaves no delta.
+        app.update();
+        let time = app.world.resource::<Time>();
+        assert_eq!(time.delta(), Duration::ZERO);
+    }
 }
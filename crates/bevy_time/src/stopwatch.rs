@@ -1,5 +1,5 @@
 use bevy_reflect::prelude::*;
-use bevy_reflect::Reflect;
+use bevy_reflect::{FromReflect, Reflect};
 use bevy_utils::Duration;
 
 /// A Stopwatch is a struct that track elapsed time when started.
@@ -23,7 +23,7 @@ use bevy_utils::Duration;
 /// assert!(stopwatch.paused());
 /// assert_eq!(stopwatch.elapsed_secs(), 0.0);
 /// ```
-#[derive(Clone, Debug, Default, Reflect)]
+#[derive(Clone, Debug, Default, Reflect, FromReflect)]
 #[reflect(Default)]
 pub struct Stopwatch {
     elapsed: Duration,
@@ -1,32 +1,57 @@
 use crate::Stopwatch;
-use bevy_reflect::prelude::*;
+use bevy_reflect::{prelude::*, FromReflect};
 use bevy_utils::Duration;
+use serde::{Deserialize, Serialize};
+
+/// Specifies how a [`Timer`] behaves once it reaches its duration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect, FromReflect, Serialize, Deserialize)]
+#[reflect(PartialEq, Hash, Serialize, Deserialize)]
+pub enum TimerMode {
+    /// The timer finishes once, then stops tracking and stays in the finished state until reset.
+    Once,
+    /// The timer wraps around each time it reaches its duration and never stops.
+    Repeating,
+    /// The timer wraps around until it has finished the given number of times, then behaves
+    /// like a [`TimerMode::Once`] timer that has finished.
+    ///
+    /// A timer repeating zero times never advances.
+    RepeatTimes(u32),
+}
+
+impl Default for TimerMode {
+    fn default() -> Self {
+        TimerMode::Once
+    }
+}
 
 /// Tracks elapsed time. Enters the finished state once `duration` is reached.
 ///
-/// Non repeating timers will stop tracking and stay in the finished state until reset.
-/// Repeating timers will only be in the finished state on each tick `duration` is reached or
-/// exceeded, and can still be reset at any given point.
+/// [`TimerMode::Once`] timers will stop tracking and stay in the finished state until reset.
+/// [`TimerMode::Repeating`] timers will only be in the finished state on each tick `duration` is
+/// reached or exceeded, and can still be reset at any given point.
+/// [`TimerMode::RepeatTimes`] timers behave like repeating timers until they finished the given
+/// number of times, and then stay in the finished state until reset.
 ///
 /// Paused timers will not have elapsed time increased.
-#[derive(Clone, Debug, Default, Reflect)]
+#[derive(Clone, Debug, Default, Reflect, FromReflect)]
 #[reflect(Default)]
 pub struct Timer {
     stopwatch: Stopwatch,
     duration: Duration,
-    repeating: bool,
+    mode: TimerMode,
     finished: bool,
     times_finished_this_tick: u32,
+    times_finished: u32,
 }
 
 impl Timer {
     /// Creates a new timer with a given duration.
     ///
     /// See also [`Timer::from_seconds`](Timer::from_seconds).
-    pub fn new(duration: Duration, repeating: bool) -> Self {
+    pub fn new(duration: Duration, mode: TimerMode) -> Self {
         Self {
             duration,
-            repeating,
+            mode,
             ..Default::default()
         }
     }
@@ -36,12 +61,12 @@ impl Timer {
     /// # Example
     /// ```
     /// # use bevy_time::*;
-    /// let mut timer = Timer::from_seconds(1.0, false);
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Once);
     /// ```
-    pub fn from_seconds(duration: f32, repeating: bool) -> Self {
+    pub fn from_seconds(duration: f32, mode: TimerMode) -> Self {
         Self {
             duration: Duration::from_secs_f32(duration),
-            repeating,
+            mode,
             ..Default::default()
         }
     }
@@ -52,7 +77,7 @@ impl Timer {
     /// ```
     /// # use bevy_time::*;
     /// use std::time::Duration;
-    /// let mut timer = Timer::from_seconds(1.0, false);
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Once);
     /// timer.tick(Duration::from_secs_f32(1.5));
     /// assert!(timer.finished());
     /// timer.tick(Duration::from_secs_f32(0.5));
@@ -69,7 +94,7 @@ impl Timer {
     /// ```
     /// # use bevy_time::*;
     /// use std::time::Duration;
-    /// let mut timer = Timer::from_seconds(1.0, false);
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Once);
     /// timer.tick(Duration::from_secs_f32(1.5));
     /// assert!(timer.just_finished());
     /// timer.tick(Duration::from_secs_f32(0.5));
@@ -81,7 +106,7 @@ impl Timer {
     }
 
     /// Returns the time elapsed on the timer. Guaranteed to be between 0.0 and `duration`.
-    /// Will only equal `duration` when the timer is finished and non repeating.
+    /// Will only equal `duration` when the timer is [`done`](Timer::done).
     ///
     /// See also [`Stopwatch::elapsed`](Stopwatch::elapsed).
     ///
@@ -89,7 +114,7 @@ impl Timer {
     /// ```
     /// # use bevy_time::*;
     /// use std::time::Duration;
-    /// let mut timer = Timer::from_seconds(1.0, false);
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Once);
     /// timer.tick(Duration::from_secs_f32(0.5));
     /// assert_eq!(timer.elapsed(), Duration::from_secs_f32(0.5));
     /// ```
@@ -113,7 +138,7 @@ impl Timer {
     /// ```
     /// # use bevy_time::*;
     /// use std::time::Duration;
-    /// let mut timer = Timer::from_seconds(1.0, false);
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Once);
     /// timer.set_elapsed(Duration::from_secs(2));
     /// assert_eq!(timer.elapsed(), Duration::from_secs(2));
     /// // the timer is not finished even if the elapsed time is greater than the duration.
@@ -130,7 +155,7 @@ impl Timer {
     /// ```
     /// # use bevy_time::*;
     /// use std::time::Duration;
-    /// let timer = Timer::new(Duration::from_secs(1), false);
+    /// let timer = Timer::new(Duration::from_secs(1), TimerMode::Once);
     /// assert_eq!(timer.duration(), Duration::from_secs(1));
     /// ```
     #[inline]
@@ -144,7 +169,7 @@ impl Timer {
     /// ```
     /// # use bevy_time::*;
     /// use std::time::Duration;
-    /// let mut timer = Timer::from_seconds(1.5, false);
+    /// let mut timer = Timer::from_seconds(1.5, TimerMode::Once);
     /// timer.set_duration(Duration::from_secs(1));
     /// assert_eq!(timer.duration(), Duration::from_secs(1));
     /// ```
@@ -153,40 +178,74 @@ impl Timer {
         self.duration = duration;
     }
 
-    /// Returns `true` if the timer is repeating.
+    /// Returns the [`TimerMode`] of the timer.
     ///
     /// # Examples
     /// ```
     /// # use bevy_time::*;
-    /// let mut timer = Timer::from_seconds(1.0, true);
-    /// assert!(timer.repeating());
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Repeating);
+    /// assert_eq!(timer.mode(), TimerMode::Repeating);
     /// ```
     #[inline]
-    pub fn repeating(&self) -> bool {
-        self.repeating
+    pub fn mode(&self) -> TimerMode {
+        self.mode
     }
 
-    /// Sets whether the timer is repeating or not.
+    /// Sets the [`TimerMode`] of the timer, and restarts counting the times it finished.
+    ///
+    /// If the timer was done according to its previous mode but can keep going with the new one,
+    /// its elapsed time is reset. If the timer finished and is done according to the new mode, such
+    /// as a repeating timer switched to [`TimerMode::Once`] on the tick it finished, its elapsed
+    /// time is clamped at its duration.
     ///
     /// # Examples
     /// ```
     /// # use bevy_time::*;
-    /// let mut timer = Timer::from_seconds(1.0, true);
-    /// timer.set_repeating(false);
-    /// assert!(!timer.repeating());
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Repeating);
+    /// timer.set_mode(TimerMode::Once);
+    /// assert_eq!(timer.mode(), TimerMode::Once);
     /// ```
     #[inline]
-    pub fn set_repeating(&mut self, repeating: bool) {
-        if !self.repeating && repeating && self.finished {
+    pub fn set_mode(&mut self, mode: TimerMode) {
+        let was_done = self.done();
+        self.mode = mode;
+        self.times_finished = 0;
+        if was_done && !self.done() {
             self.stopwatch.reset();
             self.finished = self.just_finished();
+        } else if self.finished && self.done() {
+            self.set_elapsed(self.duration());
+        }
+    }
+
+    /// Returns `true` if the timer will not advance anymore until reset, which happens once a
+    /// [`TimerMode::Once`] timer finished, or once a [`TimerMode::RepeatTimes`] timer finished
+    /// the given number of times.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_time::*;
+    /// use std::time::Duration;
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::RepeatTimes(2));
+    /// timer.tick(Duration::from_secs_f32(1.5));
+    /// assert!(!timer.done());
+    /// timer.tick(Duration::from_secs_f32(1.5));
+    /// assert!(timer.done());
+    /// ```
+    #[inline]
+    pub fn done(&self) -> bool {
+        match self.mode {
+            TimerMode::Once => self.finished,
+            TimerMode::Repeating => false,
+            TimerMode::RepeatTimes(times) => self.times_finished >= times,
         }
-        self.repeating = repeating;
     }
 
     /// Advance the timer by `delta` seconds.
-    /// Non repeating timer will clamp at duration.
-    /// Repeating timer will wrap around.
+    /// [`TimerMode::Once`] timers will clamp at duration.
+    /// [`TimerMode::Repeating`] timers will wrap around.
+    /// [`TimerMode::RepeatTimes`] timers will wrap around until their last repetition,
+    /// where they clamp at duration.
     ///
     /// See also [`Stopwatch::tick`](Stopwatch::tick).
     ///
@@ -194,24 +253,24 @@ impl Timer {
     /// ```
     /// # use bevy_time::*;
     /// use std::time::Duration;
-    /// let mut timer = Timer::from_seconds(1.0, false);
-    /// let mut repeating = Timer::from_seconds(1.0, true);
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Once);
+    /// let mut repeating = Timer::from_seconds(1.0, TimerMode::Repeating);
     /// timer.tick(Duration::from_secs_f32(1.5));
     /// repeating.tick(Duration::from_secs_f32(1.5));
     /// assert_eq!(timer.elapsed_secs(), 1.0);
     /// assert_eq!(repeating.elapsed_secs(), 0.5);
     /// ```
     pub fn tick(&mut self, delta: Duration) -> &Self {
-        if self.paused() {
+        if self.done() {
             self.times_finished_this_tick = 0;
-            if self.repeating() {
-                self.finished = false;
-            }
             return self;
         }
 
-        if !self.repeating() && self.finished() {
+        if self.paused() {
             self.times_finished_this_tick = 0;
+            if self.mode != TimerMode::Once {
+                self.finished = false;
+            }
             return self;
         }
 
@@ -219,14 +278,23 @@ impl Timer {
         self.finished = self.elapsed() >= self.duration();
 
         if self.finished() {
-            if self.repeating() {
-                self.times_finished_this_tick =
-                    (self.elapsed().as_nanos() / self.duration().as_nanos()) as u32;
+            let times_finished = (self.elapsed().as_nanos() / self.duration().as_nanos())
+                .min(u32::MAX as u128) as u32;
+            let times_left = match self.mode {
+                TimerMode::Once => 1,
+                TimerMode::Repeating => u32::MAX,
+                TimerMode::RepeatTimes(times) => times - self.times_finished,
+            };
+            self.times_finished_this_tick = times_finished.min(times_left);
+            self.times_finished = self
+                .times_finished
+                .saturating_add(self.times_finished_this_tick);
+
+            if self.done() {
+                self.set_elapsed(self.duration());
+            } else {
                 // Duration does not have a modulo
                 self.set_elapsed(self.elapsed() - self.duration() * self.times_finished_this_tick);
-            } else {
-                self.times_finished_this_tick = 1;
-                self.set_elapsed(self.duration());
             }
         } else {
             self.times_finished_this_tick = 0;
@@ -243,7 +311,7 @@ impl Timer {
     /// ```
     /// # use bevy_time::*;
     /// use std::time::Duration;
-    /// let mut timer = Timer::from_seconds(1.0, false);
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Once);
     /// timer.pause();
     /// timer.tick(Duration::from_secs_f32(0.5));
     /// assert_eq!(timer.elapsed_secs(), 0.0);
@@ -261,7 +329,7 @@ impl Timer {
     /// ```
     /// # use bevy_time::*;
     /// use std::time::Duration;
-    /// let mut timer = Timer::from_seconds(1.0, false);
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Once);
     /// timer.pause();
     /// timer.tick(Duration::from_secs_f32(0.5));
     /// timer.unpause();
@@ -280,7 +348,7 @@ impl Timer {
     /// # Examples
     /// ```
     /// # use bevy_time::*;
-    /// let mut timer = Timer::from_seconds(1.0, false);
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Once);
     /// assert!(!timer.paused());
     /// timer.pause();
     /// assert!(timer.paused());
@@ -300,7 +368,7 @@ impl Timer {
     /// ```
     /// # use bevy_time::*;
     /// use std::time::Duration;
-    /// let mut timer = Timer::from_seconds(1.0, false);
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Once);
     /// timer.tick(Duration::from_secs_f32(1.5));
     /// timer.reset();
     /// assert!(!timer.finished());
@@ -311,6 +379,7 @@ impl Timer {
         self.stopwatch.reset();
         self.finished = false;
         self.times_finished_this_tick = 0;
+        self.times_finished = 0;
     }
 
     /// Returns the percentage of the timer elapsed time (goes from 0.0 to 1.0).
@@ -319,7 +388,7 @@ impl Timer {
     /// ```
     /// # use bevy_time::*;
     /// use std::time::Duration;
-    /// let mut timer = Timer::from_seconds(2.0, false);
+    /// let mut timer = Timer::from_seconds(2.0, TimerMode::Once);
     /// timer.tick(Duration::from_secs_f32(0.5));
     /// assert_eq!(timer.percent(), 0.25);
     /// ```
@@ -334,7 +403,7 @@ impl Timer {
     /// ```
     /// # use bevy_time::*;
     /// use std::time::Duration;
-    /// let mut timer = Timer::from_seconds(2.0, false);
+    /// let mut timer = Timer::from_seconds(2.0, TimerMode::Once);
     /// timer.tick(Duration::from_secs_f32(0.5));
     /// assert_eq!(timer.percent_left(), 0.75);
     /// ```
@@ -346,14 +415,14 @@ impl Timer {
     /// Returns the number of times a repeating timer
     /// finished during the last [`tick`](Timer<T>::tick) call.
     ///
-    /// For non repeating-timers, this method will only ever
+    /// For [`TimerMode::Once`] timers, this method will only ever
     /// return 0 or 1.
     ///
     /// # Examples
     /// ```
     /// # use bevy_time::*;
     /// use std::time::Duration;
-    /// let mut timer = Timer::from_seconds(1.0, true);
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Repeating);
     /// timer.tick(Duration::from_secs_f32(6.0));
     /// assert_eq!(timer.times_finished_this_tick(), 6);
     /// timer.tick(Duration::from_secs_f32(2.0));
@@ -365,6 +434,25 @@ impl Timer {
     pub fn times_finished_this_tick(&self) -> u32 {
         self.times_finished_this_tick
     }
+
+    /// Returns the number of times the timer finished since it was created or last reset.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_time::*;
+    /// use std::time::Duration;
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::RepeatTimes(5));
+    /// timer.tick(Duration::from_secs_f32(3.5));
+    /// assert_eq!(timer.times_finished(), 3);
+    /// timer.tick(Duration::from_secs_f32(10.0));
+    /// assert_eq!(timer.times_finished(), 5);
+    /// timer.reset();
+    /// assert_eq!(timer.times_finished(), 0);
+    /// ```
+    #[inline]
+    pub fn times_finished(&self) -> u32 {
+        self.times_finished
+    }
 }
 
 #[cfg(test)]
@@ -374,7 +462,7 @@ mod tests {
 
     #[test]
     fn non_repeating_timer() {
-        let mut t = Timer::from_seconds(10.0, false);
+        let mut t = Timer::from_seconds(10.0, TimerMode::Once);
         // Tick once, check all attributes
         t.tick(Duration::from_secs_f32(0.25));
         assert_eq!(t.elapsed_secs(), 0.25);
@@ -382,7 +470,7 @@ mod tests {
         assert!(!t.finished());
         assert!(!t.just_finished());
         assert_eq!(t.times_finished_this_tick(), 0);
-        assert!(!t.repeating());
+        assert_eq!(t.mode(), TimerMode::Once);
         assert_eq!(t.percent(), 0.025);
         assert_eq!(t.percent_left(), 0.975);
         // Ticking while paused changes nothing
@@ -393,7 +481,7 @@ mod tests {
         assert!(!t.finished());
         assert!(!t.just_finished());
         assert_eq!(t.times_finished_this_tick(), 0);
-        assert!(!t.repeating());
+        assert_eq!(t.mode(), TimerMode::Once);
         assert_eq!(t.percent(), 0.025);
         assert_eq!(t.percent_left(), 0.975);
         // Tick past the end and make sure elapsed doesn't go past 0.0 and other things update
@@ -417,7 +505,7 @@ mod tests {
 
     #[test]
     fn repeating_timer() {
-        let mut t = Timer::from_seconds(2.0, true);
+        let mut t = Timer::from_seconds(2.0, TimerMode::Repeating);
         // Tick once, check all attributes
         t.tick(Duration::from_secs_f32(0.75));
         assert_eq!(t.elapsed_secs(), 0.75);
@@ -425,7 +513,7 @@ mod tests {
         assert!(!t.finished());
         assert!(!t.just_finished());
         assert_eq!(t.times_finished_this_tick(), 0);
-        assert!(t.repeating());
+        assert_eq!(t.mode(), TimerMode::Repeating);
         assert_eq!(t.percent(), 0.375);
         assert_eq!(t.percent_left(), 0.625);
         // Tick past the end and make sure elapsed wraps
@@ -448,7 +536,7 @@ mod tests {
 
     #[test]
     fn times_finished_repeating() {
-        let mut t = Timer::from_seconds(1.0, true);
+        let mut t = Timer::from_seconds(1.0, TimerMode::Repeating);
         assert_eq!(t.times_finished_this_tick(), 0);
         t.tick(Duration::from_secs_f32(3.5));
         assert_eq!(t.times_finished_this_tick(), 3);
@@ -461,7 +549,7 @@ mod tests {
 
     #[test]
     fn times_finished_this_tick() {
-        let mut t = Timer::from_seconds(1.0, false);
+        let mut t = Timer::from_seconds(1.0, TimerMode::Once);
         assert_eq!(t.times_finished_this_tick(), 0);
         t.tick(Duration::from_secs_f32(1.5));
         assert_eq!(t.times_finished_this_tick(), 1);
@@ -471,7 +559,7 @@ mod tests {
 
     #[test]
     fn times_finished_this_tick_precise() {
-        let mut t = Timer::from_seconds(0.01, true);
+        let mut t = Timer::from_seconds(0.01, TimerMode::Repeating);
         let duration = Duration::from_secs_f64(0.333);
 
         // total duration: 0.333 => 33 times finished
@@ -490,7 +578,7 @@ mod tests {
 
     #[test]
     fn paused() {
-        let mut t = Timer::from_seconds(10.0, false);
+        let mut t = Timer::from_seconds(10.0, TimerMode::Once);
 
         t.tick(Duration::from_secs_f32(10.0));
         assert!(t.just_finished());
@@ -504,7 +592,7 @@ mod tests {
 
     #[test]
     fn paused_repeating() {
-        let mut t = Timer::from_seconds(10.0, true);
+        let mut t = Timer::from_seconds(10.0, TimerMode::Repeating);
 
         t.tick(Duration::from_secs_f32(10.0));
         assert!(t.just_finished());
@@ -515,4 +603,88 @@ mod tests {
         assert!(!t.just_finished());
         assert!(!t.finished());
     }
+
+    #[test]
+    fn repeat_times_timer() {
+        let mut t = Timer::from_seconds(1.0, TimerMode::RepeatTimes(3));
+        // Wraps around like a repeating timer until the last repetition
+        t.tick(Duration::from_secs_f32(1.5));
+        assert_eq!(t.elapsed_secs(), 0.5);
+        assert!(t.finished());
+        assert!(t.just_finished());
+        assert!(!t.done());
+        assert_eq!(t.times_finished(), 1);
+        t.tick(Duration::from_secs_f32(0.25));
+        assert!(!t.finished());
+        assert!(!t.just_finished());
+        // Finishing more times than left clamps both the count and the elapsed time
+        t.tick(Duration::from_secs_f32(5.0));
+        assert_eq!(t.times_finished_this_tick(), 2);
+        assert_eq!(t.times_finished(), 3);
+        assert_eq!(t.elapsed_secs(), 1.0);
+        assert!(t.finished());
+        assert!(t.done());
+        // Continuing to tick when done should only change just_finished
+        t.tick(Duration::from_secs_f32(1.0));
+        assert_eq!(t.elapsed_secs(), 1.0);
+        assert!(t.finished());
+        assert!(!t.just_finished());
+        assert_eq!(t.times_finished(), 3);
+        // Resetting allows the timer to repeat again
+        t.reset();
+        t.tick(Duration::from_secs_f32(1.0));
+        assert!(t.just_finished());
+        assert_eq!(t.times_finished(), 1);
+    }
+
+    #[test]
+    fn set_mode_restarts_done_timer() {
+        let mut t = Timer::from_seconds(1.0, TimerMode::Once);
+        t.tick(Duration::from_secs_f32(1.5));
+        assert!(t.done());
+        t.set_mode(TimerMode::RepeatTimes(2));
+        assert!(!t.done());
+        assert_eq!(t.elapsed_secs(), 0.0);
+        assert_eq!(t.times_finished(), 0);
+        t.tick(Duration::from_secs_f32(1.0));
+        assert!(!t.done());
+        t.tick(Duration::from_secs_f32(1.0));
+        assert!(t.done());
+        t.set_mode(TimerMode::Repeating);
+        assert!(!t.done());
+        t.tick(Duration::from_secs_f32(1.0));
+        assert!(t.just_finished());
+    }
+
+    #[test]
+    fn set_mode_restarts_times_finished() {
+        let mut t = Timer::from_seconds(1.0, TimerMode::Repeating);
+        t.tick(Duration::from_secs_f32(3.5));
+        assert_eq!(t.times_finished(), 3);
+        // The repetitions of the previous mode don't count towards the new one
+        t.set_mode(TimerMode::RepeatTimes(2));
+        assert_eq!(t.times_finished(), 0);
+        assert!(!t.done());
+        assert_eq!(t.elapsed_secs(), 0.5);
+        t.tick(Duration::from_secs_f32(1.0));
+        assert!(!t.done());
+        t.tick(Duration::from_secs_f32(1.0));
+        assert!(t.done());
+        assert_eq!(t.times_finished(), 2);
+    }
+
+    #[test]
+    fn set_mode_once_on_finished_timer() {
+        let mut t = Timer::from_seconds(1.0, TimerMode::Repeating);
+        t.tick(Duration::from_secs_f32(1.5));
+        assert_eq!(t.elapsed_secs(), 0.5);
+        // The timer finished this tick, so it is done as a once timer and stops wrapping
+        t.set_mode(TimerMode::Once);
+        assert!(t.done());
+        assert_eq!(t.elapsed_secs(), 1.0);
+        t.tick(Duration::from_secs_f32(0.5));
+        assert_eq!(t.elapsed_secs(), 1.0);
+        assert!(t.finished());
+        assert!(!t.just_finished());
+    }
 }
@@ -0,0 +1,205 @@
+use crate::{Time, Timer};
+use bevy_ecs::{
+    entity::Entity,
+    event::EventWriter,
+    prelude::Component,
+    reflect::ReflectComponent,
+    system::{Query, Res, ResMut},
+};
+use bevy_reflect::prelude::*;
+use bevy_utils::{Duration, HashMap};
+
+/// A collection of named [`Timer`]s, ticked automatically by the [`TimePlugin`](crate::TimePlugin).
+///
+/// It can be used both as a [`Component`], to attach several timers to an entity, and as a
+/// resource, for timers that are not tied to an entity. Every time one of the timers finishes,
+/// a [`TimerFinished`] event is sent, so that a single system can react to all of them.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::*;
+/// fn setup(mut commands: Commands) {
+///     let mut timers = Timers::default();
+///     timers.insert("attack_cooldown", Timer::from_seconds(1.5, TimerMode::Once));
+///     timers.insert("regenerate", Timer::from_seconds(5.0, TimerMode::Repeating));
+///     commands.spawn().insert(timers);
+/// }
+///
+/// fn on_timer_finished(mut events: EventReader<TimerFinished>) {
+///     for event in events.iter() {
+///         if event.name == "regenerate" {
+///             println!("{:?} regenerates", event.entity);
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(setup);
+/// # bevy_ecs::system::assert_is_system(on_timer_finished);
+/// ```
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct Timers {
+    timers: HashMap<String, Timer>,
+}
+
+impl Timers {
+    /// Inserts a timer with the given name, returning the timer previously registered under that
+    /// name, if any.
+    pub fn insert(&mut self, name: impl Into<String>, timer: Timer) -> Option<Timer> {
+        self.timers.insert(name.into(), timer)
+    }
+
+    /// Removes the timer with the given name, returning it if it existed.
+    pub fn remove(&mut self, name: &str) -> Option<Timer> {
+        self.timers.remove(name)
+    }
+
+    /// Returns a reference to the timer with the given name.
+    pub fn get(&self, name: &str) -> Option<&Timer> {
+        self.timers.get(name)
+    }
+
+    /// Returns a mutable reference to the timer with the given name.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Timer> {
+        self.timers.get_mut(name)
+    }
+
+    /// Returns `true` if a timer with the given name exists.
+    pub fn contains(&self, name: &str) -> bool {
+        self.timers.contains_key(name)
+    }
+
+    /// Returns `true` if the timer with the given name just finished.
+    ///
+    /// Returns `false` if there is no timer with that name.
+    pub fn just_finished(&self, name: &str) -> bool {
+        self.get(name).map_or(false, Timer::just_finished)
+    }
+
+    /// Returns the number of timers in the collection.
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+
+    /// Returns `true` if the collection contains no timers.
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+
+    /// Iterates over the names and timers of the collection.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Timer)> {
+        self.timers
+            .iter()
+            .map(|(name, timer)| (name.as_str(), timer))
+    }
+
+    /// Iterates mutably over the names and timers of the collection.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&str, &mut Timer)> {
+        self.timers
+            .iter_mut()
+            .map(|(name, timer)| (name.as_str(), timer))
+    }
+
+    /// Advances every timer of the collection by `delta`, calling `on_finished` with the name of
+    /// each timer that finished during this tick and the number of times it finished.
+    pub fn tick(&mut self, delta: Duration, mut on_finished: impl FnMut(&str, u32)) {
+        for (name, timer) in self.timers.iter_mut() {
+            let times = timer.tick(delta).times_finished_this_tick();
+            if times > 0 {
+                on_finished(name, times);
+            }
+        }
+    }
+}
+
+/// An event sent when a timer of a [`Timers`] collection finishes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimerFinished {
+    /// The entity owning the [`Timers`] component, or `None` if the timer belongs to the
+    /// [`Timers`] resource.
+    pub entity: Option<Entity>,
+    /// The name of the timer in its [`Timers`] collection.
+    pub name: String,
+    /// The number of times the timer finished during the last tick.
+    ///
+    /// See [`Timer::times_finished_this_tick`].
+    pub times: u32,
+}
+
+/// Ticks the [`Timers`] resource and every [`Timers`] component, sending a [`TimerFinished`]
+/// event for each timer that finished.
+pub fn tick_timers(
+    time: Res<Time>,
+    timers_resource: Option<ResMut<Timers>>,
+    mut timers_query: Query<(Entity, &mut Timers)>,
+    mut timer_finished_events: EventWriter<TimerFinished>,
+) {
+    let delta = time.delta();
+    if let Some(mut timers) = timers_resource {
+        timers.tick(delta, |name, times| {
+            timer_finished_events.send(TimerFinished {
+                entity: None,
+                name: name.to_string(),
+                times,
+            });
+        });
+    }
+    for (entity, mut timers) in timers_query.iter_mut() {
+        timers.tick(delta, |name, times| {
+            timer_finished_events.send(TimerFinished {
+                entity: Some(entity),
+                name: name.to_string(),
+                times,
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TimePlugin, TimeUpdateStrategy, Timer, TimerFinished, TimerMode, Timers};
+    use bevy_app::App;
+    use bevy_ecs::event::Events;
+    use bevy_utils::Duration;
+
+    #[test]
+    fn timers_send_finished_events() {
+        let mut app = App::new();
+        app.add_plugin(TimePlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+
+        let mut timers = Timers::default();
+        timers.insert("once", Timer::from_seconds(2.0, TimerMode::Once));
+        timers.insert("twice", Timer::from_seconds(1.0, TimerMode::RepeatTimes(2)));
+        let entity = app.world.spawn().insert(timers).id();
+
+        let mut global_timers = Timers::default();
+        global_timers.insert("global", Timer::from_seconds(1.0, TimerMode::Repeating));
+        app.insert_resource(global_timers);
+
+        // The first update has no delta, then each update advances time by a second.
+        let mut events = Vec::new();
+        for _ in 0..4 {
+            app.update();
+            events.extend(app.world.resource_mut::<Events<TimerFinished>>().drain());
+        }
+
+        let count = |name: &str| events.iter().filter(|event| event.name == name).count();
+        assert_eq!(count("global"), 3);
+        assert_eq!(count("once"), 1);
+        assert_eq!(count("twice"), 2);
+        assert!(events
+            .iter()
+            .filter(|event| event.name != "global")
+            .all(|event| event.entity == Some(entity) && event.times == 1));
+        assert!(events
+            .iter()
+            .filter(|event| event.name == "global")
+            .all(|event| event.entity.is_none()));
+
+        let timers = app.world.get::<Timers>(entity).unwrap();
+        assert!(timers.get("once").unwrap().done());
+        assert!(timers.get("twice").unwrap().done());
+    }
+}
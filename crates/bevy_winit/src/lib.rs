@@ -610,6 +610,9 @@ pub fn winit_runner_with(mut app: App) {
                         *control_flow = ControlFlow::Exit;
                     }
                 }
+                if *control_flow == ControlFlow::Exit {
+                    app.shutdown();
+                }
                 winit_state.redraw_request_sent = redraw;
             }
             _ => (),
@@ -47,5 +47,8 @@ fn setup(
             transform: Transform::from_scale(Vec3::splat(6.0)),
             ..default()
         })
-        .insert(AnimationTimer(Timer::from_seconds(0.1, true)));
+        .insert(AnimationTimer(Timer::from_seconds(
+            0.1,
+            TimerMode::Repeating,
+        )));
 }
@@ -52,7 +52,10 @@ fn main() {
 fn setup_system(mut commands: Commands) {
     commands
         .spawn()
-        .insert(PrinterTick(bevy::prelude::Timer::from_seconds(1.0, true)))
+        .insert(PrinterTick(bevy::prelude::Timer::from_seconds(
+            1.0,
+            TimerMode::Repeating,
+        )))
         .insert(TextToPrint(
             "I will print until you press space.".to_string(),
         ))
@@ -60,7 +63,10 @@ fn setup_system(mut commands: Commands) {
 
     commands
         .spawn()
-        .insert(PrinterTick(bevy::prelude::Timer::from_seconds(1.0, true)))
+        .insert(PrinterTick(bevy::prelude::Timer::from_seconds(
+            1.0,
+            TimerMode::Repeating,
+        )))
         .insert(TextToPrint("I will always print".to_string()))
         .insert(LevelUnload);
 }
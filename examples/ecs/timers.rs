@@ -23,8 +23,8 @@ pub struct Countdown {
 impl Countdown {
     pub fn new() -> Self {
         Self {
-            percent_trigger: Timer::from_seconds(4.0, true),
-            main_timer: Timer::from_seconds(20.0, false),
+            percent_trigger: Timer::from_seconds(4.0, TimerMode::Repeating),
+            main_timer: Timer::from_seconds(20.0, TimerMode::Once),
         }
     }
 }
@@ -39,7 +39,10 @@ fn setup(mut commands: Commands) {
     // Add an entity to the world with a timer
     commands
         .spawn()
-        .insert(PrintOnCompletionTimer(Timer::from_seconds(5.0, false)));
+        .insert(PrintOnCompletionTimer(Timer::from_seconds(
+            5.0,
+            TimerMode::Once,
+        )));
 }
 
 /// This system ticks all the `Timer` components on entities within the scene
@@ -97,7 +97,7 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>, mut game: ResMu
     game.score = 0;
     game.player.i = BOARD_SIZE_I / 2;
     game.player.j = BOARD_SIZE_J / 2;
-    game.player.move_cooldown = Timer::from_seconds(0.3, false);
+    game.player.move_cooldown = Timer::from_seconds(0.3, TimerMode::Once);
 
     commands.spawn_bundle(PointLightBundle {
         transform: Transform::from_xyz(4.0, 10.0, 4.0),
@@ -41,14 +41,23 @@ pub struct D {
     x: usize,
 }
 
-/// By default, deriving with Reflect assumes the type is a "struct". You can tell reflect to treat
-/// your type as a "value type" by using the `reflect_value` attribute instead of `reflect`. It is
-/// generally a good idea to implement (and reflect) the `PartialEq`, `Serialize`, and `Deserialize`
-/// traits on `reflect_value` types to ensure that these values behave as expected when nested
-/// underneath Reflect-ed structs.
+/// Deriving reflect on an enum will implement the `Reflect` and `Enum` traits. The fields of its
+/// variants need to implement `FromReflect`, so that the enum can be switched to another variant.
+#[derive(Reflect)]
+pub enum E {
+    A,
+    B(usize),
+    C { value: f32 },
+}
+
+/// By default, deriving with Reflect assumes the type is a "struct" or an "enum". You can tell
+/// reflect to treat your type as a "value type" by using the `reflect_value` attribute instead of
+/// `reflect`. It is generally a good idea to implement (and reflect) the `PartialEq`, `Serialize`,
+/// and `Deserialize` traits on `reflect_value` types to ensure that these values behave as expected
+/// when nested underneath Reflect-ed structs.
 #[derive(Reflect, Copy, Clone, PartialEq, Serialize, Deserialize)]
 #[reflect_value(PartialEq, Serialize, Deserialize)]
-pub enum E {
+pub enum F {
     X,
     Y,
 }
@@ -93,6 +102,10 @@ fn setup() {
         // This exposes "map" operations on your type, such as getting / inserting by key.
         // Map is automatically implemented for relevant core types like HashMap<K, V>
         ReflectRef::Map(_) => {}
+        // `Enum` is a trait automatically implemented for enums that derive Reflect. This trait
+        // allows you to get the current variant and interact with its fields, by name for
+        // struct variants or by index for both struct and tuple variants.
+        ReflectRef::Enum(_) => {}
         // `Value` types do not implement any of the other traits above. They are simply a Reflect
         // implementation. Value is implemented for core types like i32, usize, f32, and
         // String.